@@ -0,0 +1,89 @@
+//! Compares vector layer kernels to per-item layer operations.
+//!
+//! Run with and without the `simd` feature to compare SIMD kernels to scalar code:
+//!
+//! ```sh
+//! cargo bench --bench vector_kernels
+//! cargo bench --bench vector_kernels --features simd
+//! ```
+
+use isecad::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const LENGTH: usize = 1 << 20;
+const RUNS: usize = 50;
+
+/// The fastest of several runs, in nanoseconds per item.
+fn measure(mut f: impl FnMut()) -> f64 {
+    let mut best = Duration::MAX;
+
+    for _ in 0..RUNS {
+        let start = Instant::now();
+
+        f();
+
+        best = best.min(start.elapsed());
+    }
+
+    best.as_nanos() as f64 / LENGTH as f64
+}
+
+fn report(name: &str, per_item: f64, kernel: f64) {
+    println!("{:<16} {:>8.3} ns/item {:>8.3} ns/item {:>6.2}×", name, per_item, kernel, per_item / kernel);
+}
+
+fn main() {
+    let mut random = Random::new(1);
+    let a = Layer::from_fn(LENGTH, |_| {
+        V3::new(random.uniform(-0.5, 0.5), random.uniform(-0.5, 0.5), random.uniform(-0.5, 0.5))
+    });
+    let b = Layer::from_fn(LENGTH, |_| {
+        V3::new(random.uniform(-0.5, 0.5), random.uniform(-0.5, 0.5), random.uniform(-0.5, 0.5))
+    });
+    let a4 = Layer::from_fn(LENGTH, |i| a[i].extend(random.f32()));
+    let m3 = (V3::new(1.0, 2.0, 3.0).normalize() * 0.5).to_rotation_m3();
+    let m4 = (V3::new(1.0, 2.0, 3.0).normalize() * 0.5).to_rotation_m4();
+
+    let mut v3 = Layer::<V3>::new(LENGTH);
+    let mut v4 = Layer::<V4>::new(LENGTH);
+    let mut f32s = Layer::<f32>::new(LENGTH);
+
+    println!("{:<16} {:>16} {:>16}", "", "per item", "kernel");
+
+    report(
+        "add V3",
+        measure(|| a.add_layer(black_box(&b), &mut v3)),
+        measure(|| vector_kernels::add_v3(&a, black_box(&b), &mut v3)),
+    );
+    report(
+        "dot V3",
+        measure(|| a.map2(black_box(&b), &mut f32s, V3::dot)),
+        measure(|| vector_kernels::dot_v3(&a, black_box(&b), &mut f32s)),
+    );
+    report(
+        "magnitude V3",
+        measure(|| black_box(&a).to_magnitudes(&mut f32s)),
+        measure(|| vector_kernels::magnitude_v3(black_box(&a), &mut f32s)),
+    );
+    report(
+        "normalize V3",
+        measure(|| black_box(&a).normalize_each(&mut v3)),
+        measure(|| vector_kernels::normalize_v3(black_box(&a), &mut v3)),
+    );
+    report(
+        "M3 × V3",
+        measure(|| black_box(&a).map1(&mut v3, |a_i| a_i * m3)),
+        measure(|| vector_kernels::transform_m3_v3(black_box(&a), m3, &mut v3)),
+    );
+    report(
+        "M4 × V4",
+        measure(|| black_box(&a4).map1(&mut v4, |a_i| a_i * m4)),
+        measure(|| vector_kernels::transform_m4_v4(black_box(&a4), m4, &mut v4)),
+    );
+    report(
+        "normalize V4",
+        measure(|| black_box(&a4).normalize_each(&mut v4)),
+        measure(|| vector_kernels::normalize_v4(black_box(&a4), &mut v4)),
+    );
+}
@@ -0,0 +1,40 @@
+//! Checksums of binary formats.
+
+/// CRC-32 (IEEE 802.3, reflected) of bytes.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !crc32_update(!0, bytes)
+}
+
+/// Continues a CRC-32 computation; `crc` is an inverted intermediate value, starting with `!0`.
+pub fn crc32_update(mut crc: u32, bytes: &[u8]) -> u32 {
+    for &byte in bytes {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+
+    crc
+}
+
+/// Adler-32 of bytes, as used by zlib streams.
+pub fn adler32(bytes: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+
+    let mut a = 1;
+    let mut b = 0;
+
+    // 5552 is the largest number of bytes whose sums can’t overflow `u32` before the reduction.
+    for chunk in bytes.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+
+        a %= MODULUS;
+        b %= MODULUS;
+    }
+
+    b << 16 | a
+}
@@ -0,0 +1,248 @@
+//! Colormaps and palettes.
+//!
+//! Turns scalar layers into RGBA colors for visualization: a [`Colormap`] interpolates between gradient stops, and a
+//! [`Palette`] looks up colors of categories, e.g., of plate or biome IDs. Colors are [`Rgba8`] arrays, so a
+//! `Layer<Rgba8>` can be uploaded as an image as is.
+
+use crate::*;
+
+/// An sRGB color with straight alpha, 8 bits per channel.
+pub type Rgba8 = [u8; 4];
+
+/// Color of NaN items: fully transparent.
+pub const TRANSPARENT: Rgba8 = [0, 0, 0, 0];
+
+/// A color gradient over a scalar range.
+///
+/// Stops are given in units of mapped values; e.g., a temperature map may have stops at -30 °C, 0 °C, and 30 °C. Values
+/// between stops are linearly interpolated with [`interpolations::mix`] per channel, and values beyond the first or the last
+/// stop get its color.
+///
+/// Two stops at the same position make a sharp edge: values below it get the first color, and values at or above it get the
+/// second one.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Colormap {
+    positions: Vec<f32>,
+    colors: Vec<[f32; 4]>,
+}
+
+impl Colormap {
+    /// Creates a colormap from stops, ordered by position.
+    ///
+    /// # Panics
+    ///
+    /// If there are no stops.
+    pub fn new(stops: &[(f32, Rgba8)]) -> Self {
+        assert!(!stops.is_empty(), "colormap without stops");
+        debug_assert!(stops.windows(2).all(|pair| pair[0].0 <= pair[1].0), "colormap stops aren’t ordered");
+
+        Self {
+            positions: stops.iter().map(|&(position, _)| position).collect(),
+            colors: stops.iter().map(|(_, color)| color.map(f32::from)).collect(),
+        }
+    }
+
+    /// Creates a colormap from colors evenly spread between bounds.
+    fn evenly(lower: f32, upper: f32, colors: &[Rgba8]) -> Self {
+        let last = (colors.len() - 1) as f32;
+
+        let stops: Vec<(f32, Rgba8)> = colors
+            .iter()
+            .enumerate()
+            .map(|(k, &color)| (interpolations::mix(k as f32 / last, lower, upper), color))
+            .collect();
+
+        Self::new(&stops)
+    }
+
+    /// Perceptually uniform viridis colormap, from dark purple at `lower` to yellow at `upper`.
+    ///
+    /// Good for most sequential fields, e.g., precipitation; readable in grayscale and by color vision deficient people.
+    pub fn viridis(lower: f32, upper: f32) -> Self {
+        Self::evenly(
+            lower,
+            upper,
+            &[
+                [0x44, 0x01, 0x54, 0xff],
+                [0x48, 0x24, 0x75, 0xff],
+                [0x41, 0x44, 0x87, 0xff],
+                [0x35, 0x5f, 0x8d, 0xff],
+                [0x2a, 0x78, 0x8e, 0xff],
+                [0x21, 0x91, 0x8c, 0xff],
+                [0x22, 0xa8, 0x84, 0xff],
+                [0x44, 0xbf, 0x70, 0xff],
+                [0x7a, 0xd1, 0x51, 0xff],
+                [0xbd, 0xdf, 0x26, 0xff],
+                [0xfd, 0xe7, 0x25, 0xff],
+            ],
+        )
+    }
+
+    /// Diverging blue-white-red colormap, with white at `center`.
+    ///
+    /// Good for fields with a meaningful middle value, e.g., temperature around 0 °C, or anomalies around 0. Each side is
+    /// stretched separately, so the range doesn’t have to be symmetric.
+    pub fn diverging(lower: f32, center: f32, upper: f32) -> Self {
+        let mut below = Self::evenly(
+            lower,
+            center,
+            &[
+                [0x05, 0x30, 0x61, 0xff],
+                [0x21, 0x66, 0xac, 0xff],
+                [0x43, 0x93, 0xc3, 0xff],
+                [0x92, 0xc5, 0xde, 0xff],
+                [0xd1, 0xe5, 0xf0, 0xff],
+                [0xf7, 0xf7, 0xf7, 0xff],
+            ],
+        );
+
+        let above = Self::evenly(
+            center,
+            upper,
+            &[
+                [0xf7, 0xf7, 0xf7, 0xff],
+                [0xfd, 0xdb, 0xc7, 0xff],
+                [0xf4, 0xa5, 0x82, 0xff],
+                [0xd6, 0x60, 0x4d, 0xff],
+                [0xb2, 0x18, 0x2b, 0xff],
+                [0x67, 0x00, 0x1f, 0xff],
+            ],
+        );
+
+        below.append(above);
+
+        below
+    }
+
+    /// Hypsometric tint for elevation: blues from `lower` to `sea_level`, and greens, tans, browns, and white from
+    /// `sea_level` to `upper`.
+    ///
+    /// Water and land are split with a sharp edge at `sea_level`, so coastlines stay visible; items exactly at sea level are
+    /// land.
+    pub fn hypsometric(lower: f32, sea_level: f32, upper: f32) -> Self {
+        let mut below = Self::evenly(
+            lower,
+            sea_level,
+            &[
+                [0x08, 0x1d, 0x58, 0xff],
+                [0x1d, 0x4f, 0x9c, 0xff],
+                [0x3a, 0x86, 0xc8, 0xff],
+                [0x8c, 0xc6, 0xe8, 0xff],
+            ],
+        );
+
+        let above = Self::evenly(
+            sea_level,
+            upper,
+            &[
+                [0x2f, 0x7d, 0x3c, 0xff],
+                [0x8c, 0xaa, 0x50, 0xff],
+                [0xe1, 0xcd, 0x82, 0xff],
+                [0xa0, 0x6e, 0x46, 0xff],
+                [0xfa, 0xfa, 0xfa, 0xff],
+            ],
+        );
+
+        below.append(above);
+
+        below
+    }
+
+    /// Appends stops of another colormap, which must start at or after the last stop.
+    fn append(&mut self, other: Self) {
+        self.positions.extend(other.positions);
+        self.colors.extend(other.colors);
+    }
+
+    /// Returns the color of a value; [`TRANSPARENT`] for NaN.
+    pub fn color(&self, value: f32) -> Rgba8 {
+        if value.is_nan() {
+            return TRANSPARENT;
+        }
+
+        let k = self.positions.partition_point(|&position| position <= value);
+
+        let (a, b, t) = if k == 0 {
+            (0, 0, 0.0)
+        } else if k == self.positions.len() {
+            (k - 1, k - 1, 0.0)
+        } else {
+            // Positions differ, since the previous one is at most the value, and this one is greater.
+            (k - 1, k, (value - self.positions[k - 1]) / (self.positions[k] - self.positions[k - 1]))
+        };
+
+        let (a, b) = (self.colors[a], self.colors[b]);
+
+        [0, 1, 2, 3].map(|c| interpolations::mix(t, a[c], b[c]).round() as u8)
+    }
+
+    /// $O_i = C(S_i)$
+    pub fn map_layer(&self, layer: &Layer<f32>, output: &mut Layer<Rgba8>) {
+        layer.map1(output, |s_i| self.color(s_i));
+    }
+}
+
+/// A list of colors of categories, e.g., of plate or biome IDs.
+///
+/// Category IDs beyond the end of a palette wrap around, so any palette colors every `u8` ID.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Palette {
+    colors: Vec<Rgba8>,
+}
+
+impl Palette {
+    /// Creates a palette, where the $k$-th color is the color of the $k$-th category.
+    ///
+    /// # Panics
+    ///
+    /// If there are no colors.
+    pub fn new(colors: Vec<Rgba8>) -> Self {
+        assert!(!colors.is_empty(), "palette without colors");
+
+        Self { colors }
+    }
+
+    /// Ten distinct colors for unordered categories, from the Tableau 10 palette.
+    pub fn categorical() -> Self {
+        Self::new(vec![
+            [0x4e, 0x79, 0xa7, 0xff],
+            [0xf2, 0x8e, 0x2b, 0xff],
+            [0xe1, 0x57, 0x59, 0xff],
+            [0x76, 0xb7, 0xb2, 0xff],
+            [0x59, 0xa1, 0x4f, 0xff],
+            [0xed, 0xc9, 0x48, 0xff],
+            [0xb0, 0x7a, 0xa1, 0xff],
+            [0xff, 0x9d, 0xa7, 0xff],
+            [0x9c, 0x75, 0x5f, 0xff],
+            [0xba, 0xb0, 0xac, 0xff],
+        ])
+    }
+
+    /// Returns colors of a palette.
+    pub fn colors(&self) -> &[Rgba8] {
+        &self.colors
+    }
+
+    /// Sets the color of a category, growing a palette if needed; new categories before it repeat existing colors.
+    pub fn set_color(&mut self, id: u8, color: Rgba8) {
+        let id = id as usize;
+
+        if id >= self.colors.len() {
+            let grown: Vec<Rgba8> = (self.colors.len()..=id).map(|k| self.colors[k % self.colors.len()]).collect();
+
+            self.colors.extend(grown);
+        }
+
+        self.colors[id] = color;
+    }
+
+    /// Returns the color of a category.
+    pub fn color(&self, id: u8) -> Rgba8 {
+        self.colors[id as usize % self.colors.len()]
+    }
+
+    /// $O_i = P_{S_i}$
+    pub fn map_layer(&self, layer: &Layer<u8>, output: &mut Layer<Rgba8>) {
+        layer.map1(output, |s_i| self.color(s_i));
+    }
+}
@@ -0,0 +1,441 @@
+//! Image export.
+//!
+//! Renders scalar layers defined on vertices of a spherical mesh into grayscale map images, and encodes them as PNG or PGM
+//! files:
+//!
+//! ```
+//! # use isecad::*;
+//! # let positions = Layer::from(vec![V3::new(1.0, 0.0, 0.0), V3::new(0.0, 1.0, 0.0), V3::new(0.0, 0.0, 1.0),
+//! #     V3::new(-1.0, 0.0, 0.0), V3::new(0.0, -1.0, 0.0), V3::new(0.0, 0.0, -1.0)]);
+//! # let triangles = [[0, 1, 2], [1, 3, 2], [3, 4, 2], [4, 0, 2], [1, 0, 5], [3, 1, 5], [4, 3, 5], [0, 4, 5]];
+//! # let elevation = Layer::from(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+//! let sampler = ImageSampler::new(&positions, &triangles, Projection::Mollweide, Sampling::Barycentric, 512, 256).unwrap();
+//! let png = sampler.render(&elevation, BitDepth::Sixteen).to_png();
+//! # assert_eq!(&png[1..4], b"PNG");
+//! ```
+//!
+//! Directions on the sphere follow the [`V3::from_lat_lon`]: the $z$ axis is the north pole, and the $x$ axis is the zero
+//! meridian, so a point at latitude $\varphi$ and longitude $\lambda$ is
+//! $(\cos \varphi \cos \lambda, \cos \varphi \sin \lambda, \sin \varphi)$.
+//!
+//! The mesh is given as unit vertex positions and vertex index triangles, since the [`Grid`] isn’t ported yet. Files are
+//! encoded in memory without dependencies; PNG image data is stored without compression.
+
+use crate::*;
+use std::collections::*;
+use std::f32::consts::*;
+
+/// A map projection; maps pixels to points on the unit sphere.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Projection {
+    /// Plate carrée; longitude and latitude are linear in $x$ and $y$. Best with 2:1 images.
+    Equirectangular,
+
+    /// Equal-area Mollweide projection of the whole sphere into an ellipse. Best with 2:1 images.
+    Mollweide,
+
+    /// View of a hemisphere from an infinite distance, centered at a point. Best with square images.
+    Orthographic {
+        /// Latitude of the center, radians.
+        latitude: f32,
+
+        /// Longitude of the center, radians.
+        longitude: f32,
+    },
+}
+
+impl Projection {
+    /// Returns the point at $(u, v) \in [0, 1]^2$ of a map, where $(0, 0)$ is the top left corner; `None` outside the map.
+    pub fn inverse(self, u: f32, v: f32) -> Option<V3> {
+        match self {
+            Projection::Equirectangular => Some(V3::from_lat_lon((0.5 - v) * PI, (u * 2.0 - 1.0) * PI)),
+            Projection::Mollweide => {
+                let x = (u * 2.0 - 1.0) * 2.0 * SQRT_2;
+                let y = (1.0 - v * 2.0) * SQRT_2;
+
+                let theta = (y / SQRT_2).asin();
+                let longitude = PI * x / (2.0 * SQRT_2 * theta.cos());
+                let latitude = ((2.0 * theta + (2.0 * theta).sin()) / PI).clamp(-1.0, 1.0).asin();
+
+                if longitude.abs() > PI {
+                    None
+                } else {
+                    Some(V3::from_lat_lon(latitude, longitude))
+                }
+            }
+            Projection::Orthographic { latitude, longitude } => {
+                let x = u * 2.0 - 1.0;
+                let y = 1.0 - v * 2.0;
+                let rho_squared = x * x + y * y;
+
+                if rho_squared > 1.0 {
+                    return None;
+                }
+
+                let forward = V3::from_lat_lon(latitude, longitude);
+                let east = V3::new(-longitude.sin(), longitude.cos(), 0.0);
+                let north = V3::new(-latitude.sin() * longitude.cos(), -latitude.sin() * longitude.sin(), latitude.cos());
+
+                Some(east * x + north * y + forward * (1.0 - rho_squared).sqrt())
+            }
+        }
+    }
+}
+
+/// How pixels are sampled from vertices.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Sampling {
+    /// Value of the nearest vertex.
+    Nearest,
+
+    /// Barycentric interpolation of vertices of the triangle containing a pixel.
+    Barycentric,
+}
+
+/// Bit depth of grayscale images.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BitDepth {
+    Eight,
+    Sixteen,
+}
+
+impl BitDepth {
+    /// Returns the largest pixel value.
+    pub fn max_value(self) -> u16 {
+        match self {
+            BitDepth::Eight => u8::MAX as u16,
+            BitDepth::Sixteen => u16::MAX,
+        }
+    }
+}
+
+/// A pixel of an [`ImageSampler`]: up to 3 vertices and their weights.
+#[derive(Clone, Copy, Default, Debug)]
+struct Sample {
+    vertices: [u32; 3],
+    weights: [f32; 3],
+}
+
+/// Precomputed mapping of map pixels to mesh vertices.
+///
+/// Locating pixels on a mesh is the slow part of rendering, so a sampler should be kept and reused for every layer of the
+/// same mesh and projection.
+#[derive(Clone, Debug)]
+pub struct ImageSampler {
+    width: usize,
+    height: usize,
+    samples: Vec<Option<Sample>>,
+}
+
+impl ImageSampler {
+    /// Locates every pixel of a `width`×`height` map on a mesh.
+    ///
+    /// With [`Sampling::Barycentric`], a pixel is interpolated over a triangle adjacent to its nearest vertex. If none of them
+    /// contains the pixel (which may happen with non-Delaunay meshes), the closest one is used, with negative weights
+    /// clamped to zero. Vertices without triangles are sampled as nearest.
+    ///
+    /// Fails with [`LayerError::OutOfBounds`] if a triangle refers to a vertex past the end of positions.
+    pub fn new(
+        positions: &Layer<V3>,
+        triangles: &[[usize; 3]],
+        projection: Projection,
+        sampling: Sampling,
+        width: usize,
+        height: usize,
+    ) -> Result<Self, LayerError> {
+        let length = positions.len();
+
+        if let Some(&index) = triangles.iter().flatten().find(|&&vertex| vertex >= length) {
+            return Err(LayerError::OutOfBounds { index, length });
+        }
+
+        let index = SpatialIndex::new(positions);
+
+        let mut incident = vec![Vec::new(); positions.len()];
+
+        if sampling == Sampling::Barycentric {
+            for (t, triangle) in triangles.iter().enumerate() {
+                for &vertex in triangle {
+                    incident[vertex].push(t);
+                }
+            }
+        }
+
+        let mut samples = vec![None; width * height];
+
+        kernels::map_indices(&mut samples, |pixel| {
+            let u = ((pixel % width) as f32 + 0.5) / width as f32;
+            let v = ((pixel / width) as f32 + 0.5) / height as f32;
+
+            let point = projection.inverse(u, v)?;
+            let nearest = index.nearest(positions, point)?;
+
+            let barycentric = incident[nearest]
+                .iter()
+                .map(|&t| {
+                    let [a, b, c] = triangles[t];
+
+                    (a, b, c, barycentric(point, positions[a], positions[b], positions[c]))
+                })
+                .max_by(|(.., w), (.., w_other)| min3(*w).total_cmp(&min3(*w_other)))
+                .map(|(a, b, c, weights)| (a, b, c, weights.map(|w| w.max(0.0))))
+                .filter(|(.., weights)| weights[0] + weights[1] + weights[2] > 0.0);
+
+            Some(match barycentric {
+                Some((a, b, c, weights)) => {
+                    let sum = weights[0] + weights[1] + weights[2];
+
+                    Sample {
+                        vertices: [a as u32, b as u32, c as u32],
+                        weights: weights.map(|w| w / sum),
+                    }
+                }
+                None => Sample {
+                    vertices: [nearest as u32; 3],
+                    weights: [1.0, 0.0, 0.0],
+                },
+            })
+        });
+
+        Ok(Self { width, height, samples })
+    }
+
+    /// Returns width of maps in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns height of maps in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Renders a scalar layer into a grayscale image.
+    ///
+    /// Rescales the layer to the $[0, 1]$ range with [`Layer::rescale_to_range`] first, so its smallest value is black, and
+    /// its largest value is white. Pixels outside of the map, NaN values, and constant layers are black.
+    pub fn render<T>(&self, layer: &Layer<T>, bit_depth: BitDepth) -> GrayImage
+    where
+        T: Copy + Default + Send + Sync + Into<f32>,
+    {
+        let values = layer.convert::<f32>();
+        let mut normalized = Layer::new(values.len());
+
+        values.rescale_to_range(0.0, 1.0, &mut normalized);
+
+        let max_value = bit_depth.max_value() as f32;
+        let mut pixels = vec![0; self.samples.len()];
+
+        kernels::map_indices(&mut pixels, |pixel| {
+            self.samples[pixel].map_or(0, |sample| {
+                let value = (0..3).fold(0.0, |value, k| value + normalized[sample.vertices[k] as usize] * sample.weights[k]);
+
+                // Saturating casts map NaN to zero.
+                (value.clamp(0.0, 1.0) * max_value).round() as u16
+            })
+        });
+
+        GrayImage {
+            width: self.width,
+            height: self.height,
+            bit_depth,
+            pixels,
+        }
+    }
+}
+
+/// A grayscale image.
+#[derive(Clone, Debug)]
+pub struct GrayImage {
+    pub width: usize,
+    pub height: usize,
+    pub bit_depth: BitDepth,
+
+    /// Pixel values, row by row, from the top left corner; no more than [`BitDepth::max_value`].
+    pub pixels: Vec<u16>,
+}
+
+impl GrayImage {
+    /// Encodes an image as PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.row_length() + 1) * self.height);
+
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            // Filter type: none.
+            raw.push(0);
+
+            self.write_row(&mut raw, row);
+        }
+
+        let mut header = Vec::new();
+
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        header.extend_from_slice(&[self.bit_depth_bits(), 0, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        write_png_chunk(&mut png, b"IHDR", &header);
+        write_png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        write_png_chunk(&mut png, b"IEND", &[]);
+
+        png
+    }
+
+    /// Encodes an image as binary PGM.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut pgm = format!("P5\n{} {}\n{}\n", self.width, self.height, self.bit_depth.max_value()).into_bytes();
+
+        self.write_row(&mut pgm, &self.pixels);
+
+        pgm
+    }
+
+    fn bit_depth_bits(&self) -> u8 {
+        match self.bit_depth {
+            BitDepth::Eight => 8,
+            BitDepth::Sixteen => 16,
+        }
+    }
+
+    fn row_length(&self) -> usize {
+        self.width * self.bit_depth_bits() as usize / 8
+    }
+
+    /// Writes pixels as bytes; both PNG and PGM store 16-bit values in big-endian order.
+    fn write_row(&self, bytes: &mut Vec<u8>, row: &[u16]) {
+        match self.bit_depth {
+            BitDepth::Eight => bytes.extend(row.iter().map(|&p| p as u8)),
+            BitDepth::Sixteen => bytes.extend(row.iter().flat_map(|p| p.to_be_bytes())),
+        }
+    }
+}
+
+/// A hashed uniform 3D grid of vertices for nearest-neighbor queries.
+struct SpatialIndex {
+    cell_size: f32,
+    cells: HashMap<[i32; 3], Vec<u32>>,
+}
+
+impl SpatialIndex {
+    fn new(positions: &Layer<V3>) -> Self {
+        // Average distance between vertices on the unit sphere, so every cell holds about one vertex.
+        let cell_size = (4.0 * PI / positions.len().max(1) as f32).sqrt();
+
+        let mut index = Self {
+            cell_size,
+            cells: HashMap::new(),
+        };
+
+        for (i, &p_i) in positions.iter().enumerate() {
+            index.cells.entry(index.cell(p_i)).or_default().push(i as u32);
+        }
+
+        index
+    }
+
+    fn cell(&self, point: V3) -> [i32; 3] {
+        [point.x, point.y, point.z].map(|c| (c / self.cell_size).floor() as i32)
+    }
+
+    /// Searches shells of cells around a point, until no closer vertex can be in the next shell.
+    fn nearest(&self, positions: &Layer<V3>, point: V3) -> Option<usize> {
+        let center = self.cell(point);
+        let max_radius = (2.0 / self.cell_size).ceil() as i32 + 1;
+
+        let mut best: Option<(f32, usize)> = None;
+
+        for radius in 0..=max_radius {
+            if let Some((distance_squared, _)) = best {
+                let reach = (radius - 1) as f32 * self.cell_size;
+
+                if reach * reach > distance_squared {
+                    break;
+                }
+            }
+
+            for [dx, dy, dz] in shell(radius) {
+                let Some(cell) = self.cells.get(&[center[0] + dx, center[1] + dy, center[2] + dz]) else {
+                    continue;
+                };
+
+                for &i in cell {
+                    let distance_squared = (positions[i as usize] - point).magnitude_proportional();
+
+                    if best.is_none_or(|(best_distance_squared, _)| distance_squared < best_distance_squared) {
+                        best = Some((distance_squared, i as usize));
+                    }
+                }
+            }
+        }
+
+        best.map(|(_, i)| i)
+    }
+}
+
+/// Offsets of cells at the Chebyshev distance of `radius` from a center cell, i.e., with $\max(|d_x|, |d_y|, |d_z|) = r$.
+fn shell(radius: i32) -> impl Iterator<Item = [i32; 3]> {
+    (-radius..=radius).flat_map(move |dx| {
+        (-radius..=radius).flat_map(move |dy| {
+            // Inside of the shell, only its top and bottom faces are taken.
+            let on_side = dx.abs() == radius || dy.abs() == radius;
+            let step = if on_side { 1 } else { 2 * radius as usize };
+
+            (-radius..=radius).step_by(step).map(move |dz| [dx, dy, dz])
+        })
+    })
+}
+
+/// $a \cdot (b \times c)$
+fn triple(a: V3, b: V3, c: V3) -> f32 {
+    a.x * (b.y * c.z - b.z * c.y) + a.y * (b.z * c.x - b.x * c.z) + a.z * (b.x * c.y - b.y * c.x)
+}
+
+/// Weights of a ray from the origin through a point hitting a triangle, regardless of the triangle orientation.
+///
+/// Returns `[-1; 3]` for triangles on the opposite side of the sphere.
+fn barycentric(point: V3, a: V3, b: V3, c: V3) -> [f32; 3] {
+    let weights = [triple(point, b, c), triple(point, c, a), triple(point, a, b)];
+    let sum = weights[0] + weights[1] + weights[2];
+
+    if sum == 0.0 || (a + b + c).dot(point) <= 0.0 {
+        [-1.0; 3]
+    } else {
+        weights.map(|w| w / sum)
+    }
+}
+
+fn min3([a, b, c]: [f32; 3]) -> f32 {
+    a.min(b).min(c)
+}
+
+fn write_png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+
+    let crc = !checksum::crc32_update(checksum::crc32_update(!0, kind), data);
+
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Wraps bytes into a zlib stream of stored (uncompressed) deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const BLOCK_LENGTH: usize = 65535;
+
+    let mut zlib = vec![0x78, 0x01];
+    let blocks = data.len().div_ceil(BLOCK_LENGTH).max(1);
+
+    for (k, block) in data.chunks(BLOCK_LENGTH).chain(std::iter::once(&[][..])).take(blocks).enumerate() {
+        let length = block.len() as u16;
+
+        zlib.push((k + 1 == blocks) as u8);
+        zlib.extend_from_slice(&length.to_le_bytes());
+        zlib.extend_from_slice(&(!length).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+
+    zlib.extend_from_slice(&checksum::adler32(data).to_be_bytes());
+
+    zlib
+}
@@ -0,0 +1,200 @@
+export class Random {
+    constructor(seed?: number);
+    free(): void;
+    random(): number;
+    uniform(min: number, max: number): number;
+    normal(min: number, max: number): number;
+}
+
+export class V3 {
+    constructor(x: number, y: number, z: number);
+    static from_lat_lon(latitude: number, longitude: number): V3;
+    free(): void;
+    x: number;
+    y: number;
+    z: number;
+    magnitude(): number;
+    normalize(): V3;
+    dot(rhs: V3): number;
+    similarity(rhs: V3): number;
+    cross(rhs: V3): V3;
+    angle(rhs: V3): number;
+    great_circle_distance(rhs: V3): number;
+    slerp(rhs: V3, t: number): V3;
+    project_to_tangent(point: V3): V3;
+    to_lat_lon(): Float32Array;
+}
+
+export class Quat {
+    constructor();
+    static from_axis_angle(axis: V3, angle: number): Quat;
+    static from_rotation_vector(vector: V3): Quat;
+    free(): void;
+    readonly x: number;
+    readonly y: number;
+    readonly z: number;
+    readonly w: number;
+    mul(rhs: Quat): Quat;
+    normalize(): Quat;
+    conjugate(): Quat;
+    slerp(other: Quat, t: number): Quat;
+    rotate(vector: V3): V3;
+}
+
+export class LayerF32 {
+    constructor(length: number);
+    static from_array(array: Float32Array): LayerF32;
+    free(): void;
+    to_array(): Float32Array;
+    view(): Float32Array;
+    readonly length: number;
+    get(index: number): number;
+    set(index: number, value: number): void;
+    fill(value: number): void;
+    copy_into(output: LayerF32): void;
+    average(): number;
+    weighted_average(weights: LayerF32): number;
+    min(): number;
+    max(): number;
+    variance(): number;
+    weighted_variance(weights: LayerF32): number;
+    std_dev(): number;
+    median(): number;
+    percentile(p: number): number;
+    histogram(bins: number, lower: number, upper: number): Uint32Array;
+    histogram_by_edges(edges: Float64Array): Uint32Array;
+    rescale_from_to_range(from_lower: number, from_upper: number, to_lower: number, to_upper: number, output: LayerF32): void;
+    rescale_to_range(to_lower: number, to_upper: number, output: LayerF32): void;
+    rescale_from_to(from_upper: number, to_upper: number, output: LayerF32): void;
+    rescale_to(to_upper: number, output: LayerF32): void;
+    normalize(output: LayerF32): void;
+    copy_into_selection(layer_b: LayerF32, mask: LayerBool, output: LayerF32): void;
+    fill_into_selection(value: number, mask: LayerBool, output: LayerF32): void;
+    mix_value_value(v: number, w: number, output: LayerF32): void;
+    mix_value_layer(v: number, layer_c: LayerF32, output: LayerF32): void;
+    mix_layer_value(layer_b: LayerF32, w: number, output: LayerF32): void;
+    mix_layer_layer(layer_b: LayerF32, layer_c: LayerF32, output: LayerF32): void;
+    clamp_value_value(v: number, w: number, output: LayerF32): void;
+    clamp_value_layer(v: number, layer_c: LayerF32, output: LayerF32): void;
+    clamp_layer_value(layer_b: LayerF32, w: number, output: LayerF32): void;
+    clamp_layer_layer(layer_b: LayerF32, layer_c: LayerF32, output: LayerF32): void;
+    step_value(value: number, output: LayerF32): void;
+    step_layer(layer_b: LayerF32, output: LayerF32): void;
+    linearstep_value_value(value_a: number, value_b: number, output: LayerF32): void;
+    linearstep_value_layer(value_a: number, layer_c: LayerF32, output: LayerF32): void;
+    linearstep_layer_value(layer_b: LayerF32, value_b: number, output: LayerF32): void;
+    linearstep_layer_layer(layer_b: LayerF32, layer_c: LayerF32, output: LayerF32): void;
+    smoothstep_value_value(value_a: number, value_b: number, output: LayerF32): void;
+    smoothstep_value_layer(value_a: number, layer_c: LayerF32, output: LayerF32): void;
+    smoothstep_layer_value(layer_b: LayerF32, value_b: number, output: LayerF32): void;
+    smoothstep_layer_layer(layer_b: LayerF32, layer_c: LayerF32, output: LayerF32): void;
+    smoothstep2_value(value: number, output: LayerF32): void;
+    smoothstep2_layer(layer_b: LayerF32, output: LayerF32): void;
+    lerp(xs: Float32Array, ys: Float32Array, output: LayerF32): void;
+    match_histogram_to_curve(xs: Float32Array, ys: Float32Array, output: LayerF32): void;
+    match_histogram_to_curve_weighted(xs: Float32Array, ys: Float32Array, weights: LayerF32, output: LayerF32): void;
+    match_histogram_to_layer(target: LayerF32, output: LayerF32): void;
+}
+
+export class LayerU8 {
+    constructor(length: number);
+    static from_array(array: Uint8Array): LayerU8;
+    free(): void;
+    to_array(): Uint8Array;
+    readonly length: number;
+    get(index: number): number;
+    set(index: number, value: number): void;
+    fill(value: number): void;
+    copy_into(output: LayerU8): void;
+    to_f32(): LayerF32;
+    min(): number;
+    max(): number;
+    variance(): number;
+    std_dev(): number;
+    median(): number;
+    percentile(p: number): number;
+    histogram(bins: number, lower: number, upper: number): Uint32Array;
+    copy_into_selection(layer_b: LayerU8, mask: LayerBool, output: LayerU8): void;
+    fill_into_selection(value: number, mask: LayerBool, output: LayerU8): void;
+}
+
+export class LayerBool {
+    constructor(length: number);
+    free(): void;
+    readonly length: number;
+    get(index: number): boolean;
+    set(index: number, value: boolean): void;
+    fill(value: boolean): void;
+    copy_into(output: LayerBool): void;
+    union(layer_b: LayerBool, output: LayerBool): void;
+    intersection(layer_b: LayerBool, output: LayerBool): void;
+    difference(layer_b: LayerBool, output: LayerBool): void;
+    not(output: LayerBool): void;
+}
+
+export class LayerV3 {
+    constructor(length: number);
+    free(): void;
+    readonly length: number;
+    get(index: number): V3;
+    set(index: number, value: V3): void;
+    fill(value: V3): void;
+    copy_into(output: LayerV3): void;
+    view(): Float32Array;
+    average(): V3;
+    weighted_average(weights: LayerF32): V3;
+    min(): V3;
+    max(): V3;
+    to_magnitudes(output: LayerF32): void;
+    normalize_each(output: LayerV3): void;
+    copy_into_selection(layer_b: LayerV3, mask: LayerBool, output: LayerV3): void;
+    fill_into_selection(value: V3, mask: LayerBool, output: LayerV3): void;
+    cross_layer(layer_b: LayerV3, output: LayerV3): void;
+    angle_value(value: V3, output: LayerF32): void;
+    great_circle_distance_value(value: V3, output: LayerF32): void;
+    slerp_layer(layer_b: LayerV3, t: number, output: LayerV3): void;
+    project_to_tangent(points: LayerV3, output: LayerV3): void;
+    rotate(rotation: Quat, output: LayerV3): void;
+    rotate_assign(rotation: Quat): void;
+    rotate_by_mask(rotation: Quat, mask: LayerBool, output: LayerV3): void;
+    rotate_by_mask_assign(rotation: Quat, mask: LayerBool): void;
+}
+
+export class SaveFile {
+    constructor(grid: bigint);
+    static from_bytes(bytes: Uint8Array): SaveFile;
+    free(): void;
+    to_bytes(): Uint8Array;
+    readonly grid: bigint;
+    random(): Random | undefined;
+    set_random(random: Random): void;
+    metadata(key: string): string | undefined;
+    set_metadata(key: string, value: string): void;
+    layer_f32(name: string): LayerF32;
+    set_layer_f32(name: string, layer: LayerF32): void;
+    layer_u8(name: string): LayerU8;
+    set_layer_u8(name: string, layer: LayerU8): void;
+    layer_bool(name: string): LayerBool;
+    set_layer_bool(name: string, layer: LayerBool): void;
+    layer_v3(name: string): LayerV3;
+    set_layer_v3(name: string, layer: LayerV3): void;
+}
+
+export class Colormap {
+    constructor(positions: Float32Array, colors: Uint8Array);
+    static viridis(lower: number, upper: number): Colormap;
+    static diverging(lower: number, center: number, upper: number): Colormap;
+    static hypsometric(lower: number, sea_level: number, upper: number): Colormap;
+    free(): void;
+    color(value: number): Uint8Array;
+    map_layer(layer: LayerF32): Uint8Array;
+}
+
+export class Palette {
+    constructor(colors: Uint8Array);
+    static categorical(): Palette;
+    free(): void;
+    set_color(id: number, color: Uint8Array): void;
+    color(id: number): Uint8Array;
+    map_layer(layer: LayerU8): Uint8Array;
+}
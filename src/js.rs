@@ -0,0 +1,933 @@
+//! JavaScript API.
+//!
+//! `wasm-bindgen` can’t export generic types, so every supported [`Layer`] specialization has its own wrapper. Wrappers only
+//! forward calls to the [`math`] module, so see the wrapped methods for details.
+//!
+//! Output layers are passed as `&mut`, so passing the same JS object as both an operand and an output will throw.
+//!
+//! # Views
+//!
+//! Some layers may expose their items as typed arrays over the wasm memory without copying; e.g., to upload them into a WebGL
+//! buffer every frame. A view is only valid until one of the following happens:
+//!
+//! -   The wasm memory grows. Any call that allocates may grow it; e.g., creating a layer or a vector, or calling methods
+//!     returning new objects or arrays. Once the memory grows, the underlying `ArrayBuffer` is detached, so the view becomes empty.
+//! -   The viewed layer is freed. The view won’t be detached then, but it will point to memory reused by other allocations.
+//!
+//! So views should be re-acquired after any allocating call, and should never be stored between frames. Item addresses of a
+//! layer never change during its lifetime, so re-acquiring a view is cheap and always yields the same region.
+
+use crate::colormap;
+use crate::math;
+use crate::math::Layer;
+use crate::math::LayerPool;
+use crate::save;
+use std::convert::*;
+use wasm_bindgen::prelude::*;
+
+thread_local! {
+    /// Scratch layers of all operations, so JS calls don’t allocate temporary layers every time.
+    static POOL: LayerPool = LayerPool::new();
+}
+
+/// Installs the panic hook, so Rust panics are reported to the browser console with a message and a stack trace.
+#[wasm_bindgen(start)]
+pub fn start() {
+    console_error_panic_hook::set_once();
+}
+
+/// Returns milliseconds elapsed since the page load, or `0.0` outside of a window context.
+fn now() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map_or(0.0, |performance| performance.now())
+}
+
+/// Creates a `Float32Array` over `length` floats of the wasm memory, starting at `ptr`.
+///
+/// Doesn’t create a Rust slice, so it’s fine to view padding bytes.
+fn f32_view(ptr: *const f32, length: usize) -> js_sys::Float32Array {
+    let memory: js_sys::WebAssembly::Memory = wasm_bindgen::memory().unchecked_into();
+
+    js_sys::Float32Array::new_with_byte_offset_and_length(&memory.buffer(), ptr as u32, length as u32)
+}
+
+/// A [`math::Random`] wrapper.
+#[wasm_bindgen]
+pub struct Random(math::Random);
+
+#[wasm_bindgen]
+impl Random {
+    /// Creates a new generator; if `seed` is omitted, the current time will be used.
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: Option<u32>) -> Self {
+        Self(math::Random::new(seed.unwrap_or_else(|| now() as u32)))
+    }
+
+    /// Returns a uniformly distributed number in the $[0, 1)$ range.
+    pub fn random(&mut self) -> f32 {
+        self.0.f32()
+    }
+
+    /// Returns a uniformly distributed number in the $[min, max)$ range.
+    pub fn uniform(&mut self, min: f32, max: f32) -> f32 {
+        self.0.uniform(min, max)
+    }
+
+    /// Returns a normally distributed number.
+    pub fn normal(&mut self, min: f32, max: f32) -> f32 {
+        self.0.normal(min, max)
+    }
+}
+
+/// A [`math::V3`] wrapper.
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub struct V3(math::V3);
+
+#[wasm_bindgen]
+impl V3 {
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(math::V3::new(x, y, z))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn x(&self) -> f32 {
+        self.0.x
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_x(&mut self, x: f32) {
+        self.0.x = x;
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn y(&self) -> f32 {
+        self.0.y
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_y(&mut self, y: f32) {
+        self.0.y = y;
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn z(&self) -> f32 {
+        self.0.z
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_z(&mut self, z: f32) {
+        self.0.z = z;
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        math::Magnitude::magnitude(self.0)
+    }
+
+    pub fn normalize(&self) -> V3 {
+        Self(math::Normalize::normalize(self.0))
+    }
+
+    pub fn dot(&self, rhs: &V3) -> f32 {
+        math::Dot::dot(self.0, rhs.0)
+    }
+
+    pub fn similarity(&self, rhs: &V3) -> f32 {
+        math::Similarity::similarity(self.0, rhs.0)
+    }
+
+    pub fn cross(&self, rhs: &V3) -> V3 {
+        Self(self.0.cross(rhs.0))
+    }
+
+    pub fn angle(&self, rhs: &V3) -> f32 {
+        self.0.angle(rhs.0)
+    }
+
+    pub fn great_circle_distance(&self, rhs: &V3) -> f32 {
+        self.0.great_circle_distance(rhs.0)
+    }
+
+    pub fn slerp(&self, rhs: &V3, t: f32) -> V3 {
+        Self(self.0.slerp(rhs.0, t))
+    }
+
+    pub fn project_to_tangent(&self, point: &V3) -> V3 {
+        Self(self.0.project_to_tangent(point.0))
+    }
+
+    /// Returns a latitude and a longitude in radians.
+    pub fn to_lat_lon(&self) -> Vec<f32> {
+        let lat_lon = self.0.to_lat_lon();
+
+        vec![lat_lon.x, lat_lon.y]
+    }
+
+    pub fn from_lat_lon(latitude: f32, longitude: f32) -> V3 {
+        Self(math::V3::from_lat_lon(latitude, longitude))
+    }
+}
+
+/// A [`math::Quat`] wrapper.
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub struct Quat(math::Quat);
+
+#[wasm_bindgen]
+impl Quat {
+    /// Creates the identity rotation.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self(math::Quat::default())
+    }
+
+    pub fn from_axis_angle(axis: &V3, angle: f32) -> Self {
+        Self(math::Quat::from_axis_angle(axis.0, angle))
+    }
+
+    pub fn from_rotation_vector(vector: &V3) -> Self {
+        Self(math::Quat::from_rotation_vector(vector.0))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn x(&self) -> f32 {
+        self.0.x
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn y(&self) -> f32 {
+        self.0.y
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn z(&self) -> f32 {
+        self.0.z
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn w(&self) -> f32 {
+        self.0.w
+    }
+
+    /// Hamilton product; rotates by `rhs` first, then by this rotation.
+    pub fn mul(&self, rhs: &Quat) -> Quat {
+        Self(self.0 * rhs.0)
+    }
+
+    pub fn normalize(&self) -> Quat {
+        Self(math::Normalize::normalize(self.0))
+    }
+
+    pub fn conjugate(&self) -> Quat {
+        Self(self.0.conjugate())
+    }
+
+    pub fn slerp(&self, other: &Quat, t: f32) -> Quat {
+        Self(self.0.slerp(other.0, t))
+    }
+
+    pub fn rotate(&self, vector: &V3) -> V3 {
+        V3(self.0.rotate(vector.0))
+    }
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`Layer<f32>`] wrapper.
+#[wasm_bindgen]
+pub struct LayerF32(Layer<f32>);
+
+#[wasm_bindgen]
+impl LayerF32 {
+    // region Core functionality
+    #[wasm_bindgen(constructor)]
+    pub fn new(length: usize) -> Self {
+        Self(Layer::new(length))
+    }
+
+    /// Creates a layer from a copy of given array.
+    pub fn from_array(array: &[f32]) -> Self {
+        Self(array.to_vec().into())
+    }
+
+    /// Copies layer items into a new array.
+    pub fn to_array(&self) -> Vec<f32> {
+        self.0.to_vec()
+    }
+
+    /// Creates a view over layer items; see the [module docs](self#views) for invalidation rules.
+    pub fn view(&self) -> js_sys::Float32Array {
+        f32_view(self.0.as_ptr(), self.0.len())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> f32 {
+        self.0[index]
+    }
+
+    pub fn set(&mut self, index: usize, value: f32) {
+        self.0[index] = value;
+    }
+
+    pub fn fill(&mut self, value: f32) {
+        self.0.fill(value);
+    }
+
+    pub fn copy_into(&self, output: &mut LayerF32) {
+        self.0.copy_into(&mut output.0);
+    }
+    // endregion Core functionality
+
+    // region Statistics
+    pub fn average(&self) -> f32 {
+        self.0.average()
+    }
+
+    pub fn weighted_average(&self, weights: &LayerF32) -> f32 {
+        self.0.weighted_average(&weights.0)
+    }
+
+    pub fn min(&self) -> f32 {
+        self.0.min_max::<f32>().0
+    }
+
+    pub fn max(&self) -> f32 {
+        self.0.min_max::<f32>().1
+    }
+
+    pub fn variance(&self) -> f64 {
+        self.0.variance()
+    }
+
+    pub fn weighted_variance(&self, weights: &LayerF32) -> f64 {
+        self.0.weighted_variance(&weights.0)
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.0.std_dev()
+    }
+
+    pub fn median(&self) -> f64 {
+        self.0.median()
+    }
+
+    pub fn percentile(&self, p: f64) -> f64 {
+        self.0.percentile(p)
+    }
+
+    pub fn histogram(&self, bins: usize, lower: f64, upper: f64) -> Vec<u32> {
+        self.0.histogram(bins, lower, upper).into_iter().map(|count| count as u32).collect()
+    }
+
+    pub fn histogram_by_edges(&self, edges: &[f64]) -> Vec<u32> {
+        self.0.histogram_by_edges(edges).into_iter().map(|count| count as u32).collect()
+    }
+
+    pub fn rescale_from_to_range(&self, from_lower: f32, from_upper: f32, to_lower: f32, to_upper: f32, output: &mut LayerF32) {
+        self.0.rescale_from_to_range(from_lower, from_upper, to_lower, to_upper, &mut output.0);
+    }
+
+    pub fn rescale_to_range(&self, to_lower: f32, to_upper: f32, output: &mut LayerF32) {
+        self.0.rescale_to_range(to_lower, to_upper, &mut output.0);
+    }
+
+    pub fn rescale_from_to(&self, from_upper: f32, to_upper: f32, output: &mut LayerF32) {
+        self.0.rescale_from_to(from_upper, to_upper, &mut output.0);
+    }
+
+    pub fn rescale_to(&self, to_upper: f32, output: &mut LayerF32) {
+        self.0.rescale_to(to_upper, &mut output.0);
+    }
+
+    pub fn normalize(&self, output: &mut LayerF32) {
+        self.0.normalize(&mut output.0);
+    }
+    // endregion Statistics
+
+    // region Raster graphics
+    pub fn copy_into_selection(&self, layer_b: &LayerF32, mask: &LayerBool, output: &mut LayerF32) {
+        self.0.copy_into_selection(&layer_b.0, &mask.0, &mut output.0);
+    }
+
+    pub fn fill_into_selection(&self, value: f32, mask: &LayerBool, output: &mut LayerF32) {
+        self.0.fill_into_selection(value, &mask.0, &mut output.0);
+    }
+    // endregion Raster graphics
+
+    // region Interpolations
+    pub fn mix_value_value(&self, v: f32, w: f32, output: &mut LayerF32) {
+        self.0.mix_value_value(v, w, &mut output.0);
+    }
+
+    pub fn mix_value_layer(&self, v: f32, layer_c: &LayerF32, output: &mut LayerF32) {
+        self.0.mix_value_layer(v, &layer_c.0, &mut output.0);
+    }
+
+    pub fn mix_layer_value(&self, layer_b: &LayerF32, w: f32, output: &mut LayerF32) {
+        self.0.mix_layer_value(&layer_b.0, w, &mut output.0);
+    }
+
+    pub fn mix_layer_layer(&self, layer_b: &LayerF32, layer_c: &LayerF32, output: &mut LayerF32) {
+        self.0.mix_layer_layer(&layer_b.0, &layer_c.0, &mut output.0);
+    }
+
+    pub fn clamp_value_value(&self, v: f32, w: f32, output: &mut LayerF32) {
+        self.0.clamp_value_value(v, w, &mut output.0);
+    }
+
+    pub fn clamp_value_layer(&self, v: f32, layer_c: &LayerF32, output: &mut LayerF32) {
+        self.0.clamp_value_layer(v, &layer_c.0, &mut output.0);
+    }
+
+    pub fn clamp_layer_value(&self, layer_b: &LayerF32, w: f32, output: &mut LayerF32) {
+        self.0.clamp_layer_value(&layer_b.0, w, &mut output.0);
+    }
+
+    pub fn clamp_layer_layer(&self, layer_b: &LayerF32, layer_c: &LayerF32, output: &mut LayerF32) {
+        self.0.clamp_layer_layer(&layer_b.0, &layer_c.0, &mut output.0);
+    }
+
+    pub fn step_value(&self, value: f32, output: &mut LayerF32) {
+        self.0.step_value(value, &mut output.0);
+    }
+
+    pub fn step_layer(&self, layer_b: &LayerF32, output: &mut LayerF32) {
+        self.0.step_layer(&layer_b.0, &mut output.0);
+    }
+
+    pub fn linearstep_value_value(&self, value_a: f32, value_b: f32, output: &mut LayerF32) {
+        self.0.linearstep_value_value(value_a, value_b, &mut output.0);
+    }
+
+    pub fn linearstep_value_layer(&self, value_a: f32, layer_c: &LayerF32, output: &mut LayerF32) {
+        self.0.linearstep_value_layer(value_a, &layer_c.0, &mut output.0);
+    }
+
+    pub fn linearstep_layer_value(&self, layer_b: &LayerF32, value_b: f32, output: &mut LayerF32) {
+        self.0.linearstep_layer_value(&layer_b.0, value_b, &mut output.0);
+    }
+
+    pub fn linearstep_layer_layer(&self, layer_b: &LayerF32, layer_c: &LayerF32, output: &mut LayerF32) {
+        self.0.linearstep_layer_layer(&layer_b.0, &layer_c.0, &mut output.0);
+    }
+
+    pub fn smoothstep_value_value(&self, value_a: f32, value_b: f32, output: &mut LayerF32) {
+        self.0.smoothstep_value_value(value_a, value_b, &mut output.0);
+    }
+
+    pub fn smoothstep_value_layer(&self, value_a: f32, layer_c: &LayerF32, output: &mut LayerF32) {
+        self.0.smoothstep_value_layer(value_a, &layer_c.0, &mut output.0);
+    }
+
+    pub fn smoothstep_layer_value(&self, layer_b: &LayerF32, value_b: f32, output: &mut LayerF32) {
+        self.0.smoothstep_layer_value(&layer_b.0, value_b, &mut output.0);
+    }
+
+    pub fn smoothstep_layer_layer(&self, layer_b: &LayerF32, layer_c: &LayerF32, output: &mut LayerF32) {
+        self.0.smoothstep_layer_layer(&layer_b.0, &layer_c.0, &mut output.0);
+    }
+
+    pub fn smoothstep2_value(&self, value: f32, output: &mut LayerF32) {
+        self.0.smoothstep2_value(value, &mut output.0);
+    }
+
+    pub fn smoothstep2_layer(&self, layer_b: &LayerF32, output: &mut LayerF32) {
+        self.0.smoothstep2_layer(&layer_b.0, &mut output.0);
+    }
+
+    pub fn lerp(&self, xs: &[f32], ys: &[f32], output: &mut LayerF32) {
+        POOL.with(|pool| self.0.lerp(xs, ys, &mut output.0, pool));
+    }
+    // endregion Interpolations
+
+    // region Histogram matching
+    pub fn match_histogram_to_curve(&self, xs: &[f32], ys: &[f32], output: &mut LayerF32) -> Result<(), JsValue> {
+        POOL.with(|pool| self.0.match_histogram_to_curve(xs, ys, &mut output.0, pool))
+            .map_err(layer_error)
+    }
+
+    pub fn match_histogram_to_curve_weighted(&self, xs: &[f32], ys: &[f32], weights: &LayerF32, output: &mut LayerF32) -> Result<(), JsValue> {
+        POOL.with(|pool| self.0.match_histogram_to_curve_weighted(xs, ys, &weights.0, &mut output.0, pool))
+            .map_err(layer_error)
+    }
+
+    pub fn match_histogram_to_layer(&self, target: &LayerF32, output: &mut LayerF32) -> Result<(), JsValue> {
+        POOL.with(|pool| self.0.match_histogram_to_layer(&target.0, &mut output.0, pool))
+            .map_err(layer_error)
+    }
+    // endregion Histogram matching
+}
+
+/// A [`Layer<u8>`] wrapper.
+#[wasm_bindgen]
+pub struct LayerU8(Layer<u8>);
+
+#[wasm_bindgen]
+impl LayerU8 {
+    // region Core functionality
+    #[wasm_bindgen(constructor)]
+    pub fn new(length: usize) -> Self {
+        Self(Layer::new(length))
+    }
+
+    /// Creates a layer from a copy of given array.
+    pub fn from_array(array: &[u8]) -> Self {
+        Self(array.to_vec().into())
+    }
+
+    /// Copies layer items into a new array.
+    pub fn to_array(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> u8 {
+        self.0[index]
+    }
+
+    pub fn set(&mut self, index: usize, value: u8) {
+        self.0[index] = value;
+    }
+
+    pub fn fill(&mut self, value: u8) {
+        self.0.fill(value);
+    }
+
+    pub fn copy_into(&self, output: &mut LayerU8) {
+        self.0.copy_into(&mut output.0);
+    }
+
+    /// Converts a layer into an F32 layer.
+    pub fn to_f32(&self) -> LayerF32 {
+        LayerF32(self.0.convert())
+    }
+    // endregion Core functionality
+
+    // region Statistics
+    pub fn min(&self) -> u8 {
+        self.0.min_max::<u8>().0
+    }
+
+    pub fn max(&self) -> u8 {
+        self.0.min_max::<u8>().1
+    }
+
+    pub fn variance(&self) -> f64 {
+        self.0.variance()
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.0.std_dev()
+    }
+
+    pub fn median(&self) -> f64 {
+        self.0.median()
+    }
+
+    pub fn percentile(&self, p: f64) -> f64 {
+        self.0.percentile(p)
+    }
+
+    pub fn histogram(&self, bins: usize, lower: f64, upper: f64) -> Vec<u32> {
+        self.0.histogram(bins, lower, upper).into_iter().map(|count| count as u32).collect()
+    }
+    // endregion Statistics
+
+    // region Raster graphics
+    pub fn copy_into_selection(&self, layer_b: &LayerU8, mask: &LayerBool, output: &mut LayerU8) {
+        self.0.copy_into_selection(&layer_b.0, &mask.0, &mut output.0);
+    }
+
+    pub fn fill_into_selection(&self, value: u8, mask: &LayerBool, output: &mut LayerU8) {
+        self.0.fill_into_selection(value, &mask.0, &mut output.0);
+    }
+    // endregion Raster graphics
+}
+
+/// A [`Layer<bool>`] wrapper.
+#[wasm_bindgen]
+pub struct LayerBool(Layer<bool>);
+
+#[wasm_bindgen]
+impl LayerBool {
+    // region Core functionality
+    #[wasm_bindgen(constructor)]
+    pub fn new(length: usize) -> Self {
+        Self(Layer::new(length))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        self.0[index]
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.0[index] = value;
+    }
+
+    pub fn fill(&mut self, value: bool) {
+        self.0.fill(value);
+    }
+
+    pub fn copy_into(&self, output: &mut LayerBool) {
+        self.0.copy_into(&mut output.0);
+    }
+    // endregion Core functionality
+
+    // region Morphology
+    pub fn union(&self, layer_b: &LayerBool, output: &mut LayerBool) {
+        self.0.union(&layer_b.0, &mut output.0);
+    }
+
+    pub fn intersection(&self, layer_b: &LayerBool, output: &mut LayerBool) {
+        self.0.intersection(&layer_b.0, &mut output.0);
+    }
+
+    pub fn difference(&self, layer_b: &LayerBool, output: &mut LayerBool) {
+        self.0.difference(&layer_b.0, &mut output.0);
+    }
+
+    pub fn not(&self, output: &mut LayerBool) {
+        self.0.not(&mut output.0);
+    }
+    // endregion Morphology
+}
+
+/// A [`Layer<math::V3>`] wrapper.
+#[wasm_bindgen]
+pub struct LayerV3(Layer<math::V3>);
+
+#[wasm_bindgen]
+impl LayerV3 {
+    // region Core functionality
+    #[wasm_bindgen(constructor)]
+    pub fn new(length: usize) -> Self {
+        Self(Layer::new(length))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn get(&self, index: usize) -> V3 {
+        V3(self.0[index])
+    }
+
+    pub fn set(&mut self, index: usize, value: &V3) {
+        self.0[index] = value.0;
+    }
+
+    pub fn fill(&mut self, value: &V3) {
+        self.0.fill(value.0);
+    }
+
+    pub fn copy_into(&self, output: &mut LayerV3) {
+        self.0.copy_into(&mut output.0);
+    }
+
+    /// Creates a strided view over layer items; see the [module docs](self#views) for invalidation rules.
+    ///
+    /// Each vector is aligned to 16 bytes, so it takes 4 floats: `x`, `y`, `z`, and an unspecified padding value. I.e., the
+    /// $i$-th vector starts at the $4i$-th float, and the view is 4 times longer than the layer. When uploading it to WebGL,
+    /// use a 16 bytes stride; e.g., `gl.vertexAttribPointer(location, 3, gl.FLOAT, false, 16, 0)`.
+    pub fn view(&self) -> js_sys::Float32Array {
+        f32_view(self.0.as_ptr() as *const f32, self.0.len() * 4)
+    }
+    // endregion Core functionality
+
+    // region Statistics
+    pub fn average(&self) -> V3 {
+        V3(self.0.average())
+    }
+
+    pub fn weighted_average(&self, weights: &LayerF32) -> V3 {
+        V3(self.0.weighted_average(&weights.0))
+    }
+
+    /// Returns a vector with the least magnitude.
+    pub fn min(&self) -> V3 {
+        V3(self.0.min_max::<f32>().0)
+    }
+
+    /// Returns a vector with the greatest magnitude.
+    pub fn max(&self) -> V3 {
+        V3(self.0.min_max::<f32>().1)
+    }
+
+    pub fn to_magnitudes(&self, output: &mut LayerF32) {
+        self.0.to_magnitudes(&mut output.0);
+    }
+
+    pub fn normalize_each(&self, output: &mut LayerV3) {
+        self.0.normalize_each(&mut output.0);
+    }
+    // endregion Statistics
+
+    // region Raster graphics
+    pub fn copy_into_selection(&self, layer_b: &LayerV3, mask: &LayerBool, output: &mut LayerV3) {
+        self.0.copy_into_selection(&layer_b.0, &mask.0, &mut output.0);
+    }
+
+    pub fn fill_into_selection(&self, value: &V3, mask: &LayerBool, output: &mut LayerV3) {
+        self.0.fill_into_selection(value.0, &mask.0, &mut output.0);
+    }
+    // endregion Raster graphics
+
+    // region Spherical geometry
+    pub fn cross_layer(&self, layer_b: &LayerV3, output: &mut LayerV3) {
+        self.0.cross_layer(&layer_b.0, &mut output.0);
+    }
+
+    pub fn angle_value(&self, value: &V3, output: &mut LayerF32) {
+        self.0.angle_value(value.0, &mut output.0);
+    }
+
+    pub fn great_circle_distance_value(&self, value: &V3, output: &mut LayerF32) {
+        self.0.great_circle_distance_value(value.0, &mut output.0);
+    }
+
+    pub fn slerp_layer(&self, layer_b: &LayerV3, t: f32, output: &mut LayerV3) {
+        self.0.slerp_layer(&layer_b.0, t, &mut output.0);
+    }
+
+    pub fn project_to_tangent(&self, points: &LayerV3, output: &mut LayerV3) {
+        self.0.project_to_tangent(&points.0, &mut output.0);
+    }
+    // endregion Spherical geometry
+
+    // region Rotation
+    pub fn rotate(&self, rotation: &Quat, output: &mut LayerV3) {
+        self.0.rotate(rotation.0, &mut output.0);
+    }
+
+    pub fn rotate_assign(&mut self, rotation: &Quat) {
+        self.0.rotate_assign(rotation.0);
+    }
+
+    pub fn rotate_by_mask(&self, rotation: &Quat, mask: &LayerBool, output: &mut LayerV3) {
+        self.0.rotate_by_mask(rotation.0, &mask.0, &mut output.0);
+    }
+
+    pub fn rotate_by_mask_assign(&mut self, rotation: &Quat, mask: &LayerBool) {
+        self.0.rotate_by_mask_assign(rotation.0, &mask.0);
+    }
+    // endregion Rotation
+}
+
+/// A [`save::SaveFile`] wrapper.
+///
+/// Layers and random states are copied in and out of a save.
+#[wasm_bindgen]
+pub struct SaveFile(save::SaveFile);
+
+#[wasm_bindgen]
+impl SaveFile {
+    #[wasm_bindgen(constructor)]
+    pub fn new(grid: u64) -> Self {
+        Self(save::SaveFile::new(grid))
+    }
+
+    /// Reads a save from bytes; throws a message of a [`save::SaveError`] on invalid data.
+    pub fn from_bytes(bytes: &[u8]) -> Result<SaveFile, JsValue> {
+        save::SaveFile::from_bytes(bytes).map(Self).map_err(save_error)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn grid(&self) -> u64 {
+        self.0.grid
+    }
+
+    pub fn random(&self) -> Option<Random> {
+        self.0.random.clone().map(Random)
+    }
+
+    pub fn set_random(&mut self, random: &Random) {
+        self.0.random = Some(random.0.clone());
+    }
+
+    pub fn metadata(&self, key: &str) -> Option<String> {
+        self.0.metadata.get(key).cloned()
+    }
+
+    pub fn set_metadata(&mut self, key: String, value: String) {
+        self.0.metadata.insert(key, value);
+    }
+
+    pub fn layer_f32(&self, name: &str) -> Result<LayerF32, JsValue> {
+        self.0.layer(name).map(|layer| LayerF32(layer.clone())).map_err(save_error)
+    }
+
+    pub fn set_layer_f32(&mut self, name: String, layer: &LayerF32) {
+        self.0.insert_layer(name, layer.0.clone());
+    }
+
+    pub fn layer_u8(&self, name: &str) -> Result<LayerU8, JsValue> {
+        self.0.layer(name).map(|layer| LayerU8(layer.clone())).map_err(save_error)
+    }
+
+    pub fn set_layer_u8(&mut self, name: String, layer: &LayerU8) {
+        self.0.insert_layer(name, layer.0.clone());
+    }
+
+    pub fn layer_bool(&self, name: &str) -> Result<LayerBool, JsValue> {
+        self.0.layer(name).map(|layer| LayerBool(layer.clone())).map_err(save_error)
+    }
+
+    pub fn set_layer_bool(&mut self, name: String, layer: &LayerBool) {
+        self.0.insert_layer(name, layer.0.clone());
+    }
+
+    pub fn layer_v3(&self, name: &str) -> Result<LayerV3, JsValue> {
+        self.0.layer(name).map(|layer| LayerV3(layer.clone())).map_err(save_error)
+    }
+
+    pub fn set_layer_v3(&mut self, name: String, layer: &LayerV3) {
+        self.0.insert_layer(name, layer.0.clone());
+    }
+}
+
+fn save_error(error: save::SaveError) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+fn layer_error(error: math::LayerError) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+/// A [`colormap::Colormap`] wrapper.
+///
+/// Colors are returned as flat RGBA bytes, 4 per item, ready for `ImageData` or a WebGL texture.
+#[wasm_bindgen]
+pub struct Colormap(colormap::Colormap);
+
+#[wasm_bindgen]
+impl Colormap {
+    /// Creates a colormap from ascending stop positions and flat RGBA bytes of their colors, 4 per stop.
+    ///
+    /// Throws if there are no stops, positions aren’t ascending, or there aren’t exactly 4 color bytes per stop.
+    #[wasm_bindgen(constructor)]
+    pub fn new(positions: &[f32], colors: &[u8]) -> Result<Colormap, JsValue> {
+        if positions.is_empty() {
+            return Err(JsValue::from_str("colormap without stops"));
+        }
+
+        if !positions.windows(2).all(|pair| pair[0] <= pair[1]) {
+            return Err(JsValue::from_str("colormap stops aren’t ordered"));
+        }
+
+        if colors.len() != positions.len() * 4 {
+            return Err(color_bytes_error(positions.len() * 4, colors.len()));
+        }
+
+        let stops: Vec<(f32, colormap::Rgba8)> = positions
+            .iter()
+            .zip(colors.chunks_exact(4))
+            .map(|(&position, color)| Ok((position, rgba8(color)?)))
+            .collect::<Result<_, JsValue>>()?;
+
+        Ok(Self(colormap::Colormap::new(&stops)))
+    }
+
+    pub fn viridis(lower: f32, upper: f32) -> Self {
+        Self(colormap::Colormap::viridis(lower, upper))
+    }
+
+    pub fn diverging(lower: f32, center: f32, upper: f32) -> Self {
+        Self(colormap::Colormap::diverging(lower, center, upper))
+    }
+
+    pub fn hypsometric(lower: f32, sea_level: f32, upper: f32) -> Self {
+        Self(colormap::Colormap::hypsometric(lower, sea_level, upper))
+    }
+
+    pub fn color(&self, value: f32) -> Vec<u8> {
+        self.0.color(value).to_vec()
+    }
+
+    pub fn map_layer(&self, layer: &LayerF32) -> Vec<u8> {
+        let mut output = Layer::new(layer.0.len());
+
+        self.0.map_layer(&layer.0, &mut output);
+
+        output.iter().flatten().copied().collect()
+    }
+}
+
+/// A [`colormap::Palette`] wrapper.
+///
+/// Colors are passed as flat RGBA bytes, 4 per category or item.
+#[wasm_bindgen]
+pub struct Palette(colormap::Palette);
+
+#[wasm_bindgen]
+impl Palette {
+    /// Creates a palette from flat RGBA bytes of its colors, 4 per category.
+    ///
+    /// Throws if there are no colors, or color bytes aren’t a multiple of 4.
+    #[wasm_bindgen(constructor)]
+    pub fn new(colors: &[u8]) -> Result<Palette, JsValue> {
+        if colors.is_empty() {
+            return Err(JsValue::from_str("palette without colors"));
+        }
+
+        if !colors.len().is_multiple_of(4) {
+            return Err(color_bytes_error(colors.len().next_multiple_of(4), colors.len()));
+        }
+
+        Ok(Self(colormap::Palette::new(colors.chunks_exact(4).map(rgba8).collect::<Result<_, _>>()?)))
+    }
+
+    pub fn categorical() -> Self {
+        Self(colormap::Palette::categorical())
+    }
+
+    /// Throws if a color isn’t exactly 4 bytes.
+    pub fn set_color(&mut self, id: u8, color: &[u8]) -> Result<(), JsValue> {
+        self.0.set_color(id, rgba8(color)?);
+
+        Ok(())
+    }
+
+    pub fn color(&self, id: u8) -> Vec<u8> {
+        self.0.color(id).to_vec()
+    }
+
+    pub fn map_layer(&self, layer: &LayerU8) -> Vec<u8> {
+        let mut output = Layer::new(layer.0.len());
+
+        self.0.map_layer(&layer.0, &mut output);
+
+        output.iter().flatten().copied().collect()
+    }
+}
+
+fn rgba8(color: &[u8]) -> Result<colormap::Rgba8, JsValue> {
+    color.try_into().map_err(|_| color_bytes_error(4, color.len()))
+}
+
+fn color_bytes_error(expected: usize, actual: usize) -> JsValue {
+    JsValue::from_str(&format!("color bytes length mismatch: expected {}, got {}", expected, actual))
+}
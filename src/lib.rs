@@ -0,0 +1,14 @@
+#![allow(mixed_script_confusables)]
+
+pub mod checksum;
+pub mod colormap;
+pub mod image;
+#[cfg(feature = "wasm")]
+pub mod js;
+pub mod math;
+pub mod save;
+
+pub use colormap::*;
+pub use image::*;
+pub use math::*;
+pub use save::*;
@@ -0,0 +1,205 @@
+//! Headless simulator.
+//!
+//! Builds a world from a seed, runs it for a number of iterations, and periodically writes its layers to disk. Every snapshot
+//! layer is stored in its own file as raw little-endian `f32` values:
+//!
+//! ```text
+//! <output>/<iteration>/<layer name>.f32
+//! ```
+//!
+//! Exits with code 1 if any layer gets a non-finite value, and with code 2 on invalid arguments or I/O errors.
+
+use isecad::*;
+use std::fs;
+use std::io::Write;
+use std::path::*;
+use std::process;
+use std::time::*;
+
+const USAGE: &str = "\
+Usage: isecad [options]
+
+Options:
+    --seed <u32>          World seed. Default: 0.
+    --length <usize>      Number of vertices of each layer. Default: 10000.
+    --iterations <usize>  Number of iterations to run. Default: 100.
+    --interval <usize>    Write a snapshot every <interval> iterations; 0 disables snapshots. Default: 10.
+    --output <path>       Snapshots directory. Default: ./snapshots.
+    --help                Print this message.";
+
+struct Options {
+    seed: u32,
+    length: usize,
+    iterations: usize,
+    interval: usize,
+    output: PathBuf,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            seed: 0,
+            length: 10000,
+            iterations: 100,
+            interval: 10,
+            output: PathBuf::from("snapshots"),
+        };
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("Missing value of `{}`.", arg));
+
+            match arg.as_str() {
+                "--seed" => options.seed = parse_number(&arg, value()?)?,
+                "--length" => options.length = parse_number(&arg, value()?)?,
+                "--iterations" => options.iterations = parse_number(&arg, value()?)?,
+                "--interval" => options.interval = parse_number(&arg, value()?)?,
+                "--output" => options.output = PathBuf::from(value()?),
+                "--help" => {
+                    println!("{}", USAGE);
+
+                    process::exit(0);
+                }
+                _ => return Err(format!("Unknown argument `{}`.", arg)),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str, value: String) -> Result<T, String> {
+    value.parse().map_err(|_| format!("Invalid value of `{}`: `{}`.", arg, value))
+}
+
+/// A world to simulate.
+///
+/// There are no ported tectonics or climate systems yet, so the world is just a set of scalar layers driven by a random
+/// uplift, a relaxation to the mean elevation, and an elevation-dependent temperature. It will grow as the systems get ported.
+struct World {
+    random: Random,
+    elevation: Layer<f32>,
+    temperature: Layer<f32>,
+    uplift: Layer<f32>,
+    pool: LayerPool,
+}
+
+impl World {
+    /// Elevations of temperature control points, m.
+    const LAPSE_XS: [f32; 3] = [-11000.0, 0.0, 9000.0];
+
+    /// Temperatures at corresponding elevations, K.
+    const LAPSE_YS: [f32; 3] = [275.0, 288.0, 230.0];
+
+    /// Fraction of a difference between the mean elevation and an elevation, removed every iteration.
+    const RELAXATION: f32 = 0.01;
+
+    /// Standard deviation of the uplift per iteration, m.
+    const UPLIFT: f32 = 10.0;
+
+    fn new(seed: u32, length: usize) -> Self {
+        let mut random = Random::new(seed);
+        let mut elevation = Layer::new(length);
+
+        for e_i in elevation.iter_mut() {
+            *e_i = random.uniform(-4000.0, 4000.0);
+        }
+
+        let mut world = Self {
+            random,
+            elevation,
+            temperature: Layer::new(length),
+            uplift: Layer::new(length),
+            pool: LayerPool::new(),
+        };
+
+        world.update_temperature();
+
+        world
+    }
+
+    fn step(&mut self) {
+        for u_i in self.uplift.iter_mut() {
+            *u_i = self.random.normal(0.0, Self::UPLIFT);
+        }
+
+        self.elevation.add_layer_assign(&self.uplift);
+
+        let mean = self.elevation.average();
+
+        self.elevation.map1_assign(|e_i| e_i + (mean - e_i) * Self::RELAXATION);
+
+        self.update_temperature();
+    }
+
+    fn update_temperature(&mut self) {
+        self.elevation.lerp(&Self::LAPSE_XS, &Self::LAPSE_YS, &mut self.temperature, &self.pool);
+    }
+
+    /// Named layers to snapshot and to check for non-finite values.
+    fn layers(&self) -> [(&'static str, &Layer<f32>); 2] {
+        [("elevation", &self.elevation), ("temperature", &self.temperature)]
+    }
+}
+
+fn write_snapshot(output: &Path, iteration: usize, world: &World) -> std::io::Result<()> {
+    let directory = output.join(format!("{:06}", iteration));
+
+    fs::create_dir_all(&directory)?;
+
+    for (name, layer) in world.layers().iter() {
+        let mut bytes = Vec::with_capacity(layer.len() * 4);
+
+        for l_i in layer.iter() {
+            bytes.extend_from_slice(&l_i.to_le_bytes());
+        }
+
+        fs::File::create(directory.join(format!("{}.f32", name)))?.write_all(&bytes)?;
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let options = Options::parse(std::env::args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("{}\n\n{}", message, USAGE);
+
+        process::exit(2);
+    });
+
+    let snapshot = |iteration: usize, world: &World| {
+        if options.interval != 0 && iteration.is_multiple_of(options.interval) {
+            write_snapshot(&options.output, iteration, world).unwrap_or_else(|error| {
+                eprintln!("Failed to write snapshot {}: {}", iteration, error);
+
+                process::exit(2);
+            });
+        }
+    };
+
+    let mut world = World::new(options.seed, options.length);
+    let total = Instant::now();
+
+    snapshot(0, &world);
+
+    for iteration in 1..=options.iterations {
+        let start = Instant::now();
+
+        world.step();
+
+        let elapsed = start.elapsed();
+
+        println!("step {}: {:.3} ms", iteration, elapsed.as_secs_f64() * 1000.0);
+
+        for (name, layer) in world.layers().iter() {
+            if let Some(i) = layer.iter().position(|l_i| !l_i.is_finite()) {
+                eprintln!("Layer `{}` has a non-finite value {} at {} after step {}.", name, layer[i], i, iteration);
+
+                process::exit(1);
+            }
+        }
+
+        snapshot(iteration, &world);
+    }
+
+    println!("total: {:.3} ms", total.elapsed().as_secs_f64() * 1000.0);
+}
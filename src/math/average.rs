@@ -0,0 +1,43 @@
+use crate::*;
+use std::ops::*;
+
+/// Types averaged in an accumulator type, so sums of many items don’t overflow.
+pub trait Average: Sized {
+    /// Type items are summed and divided in.
+    type Accumulator: Copy + Default + Send + Add<Output = Self::Accumulator> + Sub<Output = Self::Accumulator> + DivUsize + From<Self>;
+
+    /// Converts an average back; an average is always between the smallest and the largest item, so it fits.
+    fn from_accumulator(average: Self::Accumulator) -> Self;
+}
+
+macro_rules! impl_average_self {
+    ($($value:ty),*) => {
+        $(
+            impl Average for $value {
+                type Accumulator = Self;
+
+                fn from_accumulator(average: Self) -> Self {
+                    average
+                }
+            }
+        )*
+    };
+}
+
+impl_average_self!(f32, f64, V2, V3, V3d, V4);
+
+macro_rules! impl_average_integer {
+    ($($integer:ty => $accumulator:ty),*) => {
+        $(
+            impl Average for $integer {
+                type Accumulator = $accumulator;
+
+                fn from_accumulator(average: $accumulator) -> Self {
+                    average as $integer
+                }
+            }
+        )*
+    };
+}
+
+impl_average_integer!(u8 => u64, u16 => u64, u32 => u64, i16 => i64, i32 => i64);
@@ -8,7 +8,20 @@ impl Bounded for f32 {
     const MAX_BOUND: Self = f32::INFINITY;
 }
 
-impl Bounded for u8 {
-    const MIN_BOUND: Self = std::u8::MIN;
-    const MAX_BOUND: Self = std::u8::MAX;
+impl Bounded for f64 {
+    const MIN_BOUND: Self = f64::NEG_INFINITY;
+    const MAX_BOUND: Self = f64::INFINITY;
 }
+
+macro_rules! impl_bounded_integer {
+    ($($integer:ident),*) => {
+        $(
+            impl Bounded for $integer {
+                const MIN_BOUND: Self = $integer::MIN;
+                const MAX_BOUND: Self = $integer::MAX;
+            }
+        )*
+    };
+}
+
+impl_bounded_integer!(u8, u16, u32, i16, i32);
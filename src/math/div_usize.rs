@@ -1,3 +1,5 @@
+use std::convert::*;
+
 pub trait DivUsize {
     fn div_usize(self, rhs: usize) -> Self;
 }
@@ -7,3 +9,25 @@ impl DivUsize for f32 {
         self / rhs as f32
     }
 }
+
+impl DivUsize for f64 {
+    fn div_usize(self, rhs: usize) -> Self {
+        self / rhs as f64
+    }
+}
+
+macro_rules! impl_div_usize_integer {
+    ($($integer:ty),*) => {
+        $(
+            /// Truncating integer division, rounding toward zero; divisors beyond the range of the type give zero. Panics
+            /// if the divisor is zero.
+            impl DivUsize for $integer {
+                fn div_usize(self, rhs: usize) -> Self {
+                    <$integer>::try_from(rhs).map_or(0, |rhs| self / rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_div_usize_integer!(u8, u16, u32, u64, i16, i32, i64);
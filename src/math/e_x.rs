@@ -11,3 +11,11 @@ impl EX for f32 {
         self.exp()
     }
 }
+
+impl EX for f64 {
+    type Output = Self;
+
+    fn e_x(self) -> Self::Output {
+        self.exp()
+    }
+}
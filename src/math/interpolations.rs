@@ -39,13 +39,35 @@ where
     clamp((x - a) * (b - a).inv(), T::default(), T::one())
 }
 
+/// $l^2 (3 - 2l)$, where $l = \text{linearstep}(x, a, b)$
+///
+/// For floats, this is bit-identical to `l * l * (l * -2.0 + 3.0)`: $1 + 1 + 1$, doubling, and negation are exact, so
+/// both forms round the same $3 - 2l$ once.
+///
+/// ```
+/// use isecad::interpolations::*;
+///
+/// for k in -1000..=2000 {
+///     let (x, a, b) = (k as f32 / 1000.0, 0.1, 0.9);
+///     let l = linearstep(x, a, b);
+///
+///     assert_eq!(smoothstep(x, a, b).to_bits(), (l * l * (l * -2.0 + 3.0)).to_bits());
+///     assert_eq!(smoothstep_inv(x, a, 1.0 / (b - a)).to_bits(), {
+///         let l = linearstep_inv(x, a, 1.0 / (b - a));
+///
+///         (l * l * (l * -2.0 + 3.0)).to_bits()
+///     });
+/// }
+/// ```
 pub fn smoothstep<T>(x: T, a: T, b: T) -> T
 where
-    T: Copy + Default + PartialOrd + Mul<Output = T> + Sub<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + Inv + One,
+    T: Copy + Default + PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
 {
     let l = linearstep(x, a, b);
 
-    l * l * (l * -2.0 + 3.0)
+    let one = T::one();
+
+    l * l * (one + one + one - (l + l))
 }
 
 pub fn linearstep_inv<T>(x: T, a: T, inv_diff: T) -> T
@@ -57,25 +79,51 @@ where
 
 pub fn smoothstep_inv<T>(x: T, a: T, inv_diff: T) -> T
 where
-    T: Copy + Default + PartialOrd + Mul<Output = T> + Sub<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + One,
+    T: Copy + Default + PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + One,
 {
     let l = linearstep_inv(x, a, inv_diff);
 
-    l * l * (l * -2.0 + 3.0)
+    let one = T::one();
+
+    l * l * (one + one + one - (l + l))
 }
 
+/// $\frac{2}{e^{-k x} + 1} - 1$
+///
+/// For floats, this is bit-identical to `((-k * x).e_x() + 1.0).inv() * 2.0 + -1.0`, since doubling is exact, and
+/// subtracting one is adding minus one.
+///
+/// ```
+/// use isecad::interpolations::*;
+/// use isecad::*;
+///
+/// for i in -2000..=2000 {
+///     for &k in &[0.5f32, 3.0, 40.0] {
+///         let x = i as f32 / 100.0;
+///
+///         assert_eq!(smoothstep2(x, k).to_bits(), (((-k * x).e_x() + 1.0).inv() * 2.0 + -1.0).to_bits());
+///         assert_eq!(smoothstep2_neg(x, -k).to_bits(), (((-k * x).e_x() + 1.0).inv() * 2.0 + -1.0).to_bits());
+///     }
+/// }
+/// ```
 pub fn smoothstep2<T>(x: T, k: T) -> T
 where
-    T: Copy + Neg<Output = T> + Mul<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + EX<Output = T> + Inv,
+    T: Copy + Neg<Output = T> + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + EX<Output = T> + Inv + One,
 {
-    ((-k * x).e_x() + 1.0).inv() * 2.0 + -1.0
+    let one = T::one();
+    let sigmoid = ((-k * x).e_x() + one).inv();
+
+    sigmoid + sigmoid - one
 }
 
 pub fn smoothstep2_neg<T>(x: T, k: T) -> T
 where
-    T: Copy + Mul<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + EX<Output = T> + Inv,
+    T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + EX<Output = T> + Inv + One,
 {
-    ((k * x).e_x() + 1.0).inv() * 2.0 + -1.0
+    let one = T::one();
+    let sigmoid = ((k * x).e_x() + one).inv();
+
+    sigmoid + sigmoid - one
 }
 
 pub fn lerp<T>(x: T, xs: &[T], ys: &[T]) -> T
@@ -85,7 +133,7 @@ where
     let mut result = ys[0];
 
     for (i, &x_i) in xs[1..].iter().enumerate() {
-        result = mix(linearstep(x, xs[i - 1], x_i), ys[i], result);
+        result = mix(linearstep(x, xs[i], x_i), result, ys[i + 1]);
     }
 
     result
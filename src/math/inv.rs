@@ -7,3 +7,9 @@ impl Inv for f32 {
         self.recip()
     }
 }
+
+impl Inv for f64 {
+    fn inv(self) -> Self {
+        self.recip()
+    }
+}
@@ -0,0 +1,1609 @@
+use crate::*;
+use std::ops::*;
+
+/// $M_i < l$ for all $i$.
+fn check_mapping(mapping: &Layer<usize>, length: usize) -> Result<(), LayerError> {
+    match mapping.iter().find(|&&m_i| m_i >= length) {
+        Some(&index) => Err(LayerError::OutOfBounds { index, length }),
+        None => Ok(()),
+    }
+}
+
+/// Control points must be non-empty, and must have as many $x$ values as $y$ values.
+pub fn check_control_points<T>(xs: &[T], ys: &[T]) -> Result<(), LayerError> {
+    if xs.len() != ys.len() {
+        Err(LayerError::LengthMismatch {
+            expected: xs.len(),
+            actual: ys.len(),
+        })
+    } else if ys.is_empty() {
+        Err(LayerError::OutOfBounds { index: 0, length: 0 })
+    } else {
+        Ok(())
+    }
+}
+
+/// # Length-checked operations
+///
+/// Regular layer operations don’t check lengths of their operands in release builds: e.g., [`Layer::map2`] stops at the end of
+/// the shortest layer, and [`Layer::swizzle`] panics on an out-of-range mapping index. Length-checked operations validate all
+/// layers before writing anything, and return a [`LayerError`] instead.
+///
+/// Length-checked operations are prefixed with `checked_`, while the `try_` prefix means an `Option` result for empty inputs;
+/// e.g., [`Layer::checked_weighted_average`] returns `Result<Option<T>, LayerError>`.
+///
+/// All operand and output layers must have the same length as $S$, except the following:
+///
+/// -   [`Layer::checked_swizzle`] — $L_M = L_O$, and $M_i < L_S$.
+/// -   [`Layer::checked_inverse_swizzle_add`] — $L_M = L_A$, $L_O = L_S$, and $M_i < L_S$.
+/// -   [`Layer::checked_lerp`] — also, control points must be non-empty and of the same length.
+///
+/// In debug builds, regular operations assert the same conditions.
+impl<T: Copy + Default + Send + Sync> Layer<T> {
+    /// $L_X = L_S$
+    pub fn check_length<U>(&self, layer: &Layer<U>) -> Result<(), LayerError> {
+        if layer.len() == self.len() {
+            Ok(())
+        } else {
+            Err(LayerError::LengthMismatch {
+                expected: self.len(),
+                actual: layer.len(),
+            })
+        }
+    }
+
+    /// $L_M = L_S$
+    pub fn check_mask_length<M>(&self, mask: &M) -> Result<(), LayerError>
+    where
+        M: Mask + ?Sized,
+    {
+        if mask.len() == self.len() {
+            Ok(())
+        } else {
+            Err(LayerError::LengthMismatch {
+                expected: self.len(),
+                actual: mask.len(),
+            })
+        }
+    }
+
+    // region Core functionality
+    /// Length-checked [`Layer::map1`].
+    pub fn checked_map1<F, U>(&self, output: &mut Layer<U>, f: F) -> Result<(), LayerError>
+    where
+        F: Fn(T) -> U + Send + Sync,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(output)?;
+
+        self.map1(output, f);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::map1_with`].
+    pub fn checked_map1_with<F, U, W>(&self, value: U, output: &mut Layer<W>, f: F) -> Result<(), LayerError>
+    where
+        F: Fn(T, U) -> W + Send + Sync,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
+    {
+        self.check_length(output)?;
+
+        self.map1_with(value, output, f);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::map2`].
+    pub fn checked_map2<F, U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>, f: F) -> Result<(), LayerError>
+    where
+        F: Fn(T, U) -> W + Send + Sync,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.map2(layer_b, output, f);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::map3`].
+    pub fn checked_map3<F, U, W, X>(&self, layer_b: &Layer<U>, layer_c: &Layer<W>, output: &mut Layer<X>, f: F) -> Result<(), LayerError>
+    where
+        F: Fn(T, U, W) -> X + Send + Sync,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
+        X: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+        self.check_length(output)?;
+
+        self.map3(layer_b, layer_c, output, f);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::map2_assign`].
+    pub fn checked_map2_assign<F, U>(&mut self, layer_b: &Layer<U>, f: F) -> Result<(), LayerError>
+    where
+        F: Fn(T, U) -> T + Send + Sync,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+
+        self.map2_assign(layer_b, f);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::map3_assign`].
+    pub fn checked_map3_assign<F, U, W>(&mut self, layer_b: &Layer<U>, layer_c: &Layer<W>, f: F) -> Result<(), LayerError>
+    where
+        F: Fn(T, U, W) -> T + Send + Sync,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+
+        self.map3_assign(layer_b, layer_c, f);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::swizzle`].
+    pub fn checked_swizzle(&self, mapping: &Layer<usize>, output: &mut Self) -> Result<(), LayerError> {
+        mapping.check_length(output)?;
+        check_mapping(mapping, self.len())?;
+
+        self.swizzle(mapping, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::inverse_swizzle_add`].
+    pub fn checked_inverse_swizzle_add(&self, mapping: &Layer<usize>, add: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: std::ops::Add<Output = T>,
+    {
+        self.check_length(output)?;
+        mapping.check_length(add)?;
+        check_mapping(mapping, self.len())?;
+
+        self.inverse_swizzle_add(mapping, add, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::copy_into`].
+    pub fn checked_copy_into(&self, output: &mut Self) -> Result<(), LayerError> {
+        self.check_length(output)?;
+
+        self.copy_into(output);
+
+        Ok(())
+    }
+    // endregion Core functionality
+
+    // region Statistics
+    /// Length-checked [`Layer::try_weighted_average`].
+    pub fn checked_weighted_average<U>(&self, weights: &Layer<U>) -> Result<Option<T>, LayerError>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<U, Output = T> + Div<U, Output = T>,
+        U: Copy + Default + Send + Sync + Add<Output = U> + Sub<Output = U> + PartialEq,
+    {
+        self.check_length(weights)?;
+
+        Ok(self.try_weighted_average(weights))
+    }
+
+    /// Length-checked [`Layer::rescale_from_to_range`].
+    pub fn checked_rescale_from_to_range<U>(&self, from_lower: U, from_upper: U, to_lower: U, to_upper: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T> + Mul<U, Output = T> + Add<U, Output = T>,
+        U: Copy + Send + Sync + Sub<Output = U> + Div<Output = U>,
+    {
+        self.check_length(output)?;
+
+        self.rescale_from_to_range(from_lower, from_upper, to_lower, to_upper, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::rescale_to_range`].
+    pub fn checked_rescale_to_range<U>(&self, to_lower: U, to_upper: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T> + Mul<U, Output = T> + Add<U, Output = T> + ToNumeric<U>,
+        U: Copy + Send + Sync + Sub<Output = U> + Div<Output = U> + PartialOrd + Bounded,
+    {
+        self.check_length(output)?;
+
+        self.rescale_to_range(to_lower, to_upper, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::normalize`].
+    pub fn checked_normalize<U>(&self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T> + Mul<U, Output = T> + ToNumeric<U>,
+        U: Copy + Send + Sync + Sub<Output = U> + PartialOrd + Bounded + Inv,
+    {
+        self.check_length(output)?;
+
+        self.normalize(output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::rescale_from_to`].
+    pub fn checked_rescale_from_to<U>(&self, from_upper: U, to_upper: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Mul<U, Output = T>,
+        U: Copy + Send + Sync + Div<Output = U>,
+    {
+        self.check_length(output)?;
+
+        self.rescale_from_to(from_upper, to_upper, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::rescale_to`].
+    pub fn checked_rescale_to<U>(&self, to_upper: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Mul<U, Output = T> + ToNumeric<U>,
+        U: Copy + Send + Sync + Div<Output = U> + PartialOrd + Bounded,
+    {
+        self.check_length(output)?;
+
+        self.rescale_to(to_upper, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::normalize_each`].
+    pub fn checked_normalize_each(&self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Normalize,
+    {
+        self.check_length(output)?;
+
+        self.normalize_each(output);
+
+        Ok(())
+    }
+    // endregion Statistics
+
+    // region Field operations
+    /// Length-checked [`Layer::add_value`].
+    pub fn checked_add_value<U>(&self, value: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Send + Sync,
+    {
+        self.check_length(output)?;
+
+        self.add_value(value, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_layer`].
+    pub fn checked_add_layer<U>(&self, layer_b: &Layer<U>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.add_layer(layer_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_layer_assign`].
+    pub fn checked_add_layer_assign<U>(&mut self, layer_b: &Layer<U>) -> Result<(), LayerError>
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+
+        self.add_layer_assign(layer_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_value_weighted`].
+    pub fn checked_add_value_weighted<U, W, X>(&self, value: U, weights: &Layer<W>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Add<X, Output = T>,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(weights)?;
+        self.check_length(output)?;
+
+        self.add_value_weighted(value, weights, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_value_weighted_assign`].
+    pub fn checked_add_value_weighted_assign<U, W, X>(&mut self, value: U, weights: &Layer<W>) -> Result<(), LayerError>
+    where
+        T: Add<X, Output = T>,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(weights)?;
+
+        self.add_value_weighted_assign(value, weights);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_layer_weighted`].
+    pub fn checked_add_layer_weighted<U, W, X>(&self, layer_b: &Layer<U>, weights: &Layer<W>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Add<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(weights)?;
+        self.check_length(output)?;
+
+        self.add_layer_weighted(layer_b, weights, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_layer_weighted_assign`].
+    pub fn checked_add_layer_weighted_assign<U, W, X>(&mut self, layer_b: &Layer<U>, weights: &Layer<W>) -> Result<(), LayerError>
+    where
+        T: Add<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(weights)?;
+
+        self.add_layer_weighted_assign(layer_b, weights);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_value_by_mask`].
+    pub fn checked_add_value_by_mask<U, M>(&self, value: U, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.add_value_by_mask(value, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_value_by_mask_assign`].
+    pub fn checked_add_value_by_mask_assign<U, M>(&mut self, value: U, mask: &M) -> Result<(), LayerError>
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+
+        self.add_value_by_mask_assign(value, mask);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_layer_by_mask`].
+    pub fn checked_add_layer_by_mask<U, M>(&self, layer_b: &Layer<U>, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.add_layer_by_mask(layer_b, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::add_layer_by_mask_assign`].
+    pub fn checked_add_layer_by_mask_assign<U, M>(&mut self, layer_b: &Layer<U>, mask: &M) -> Result<(), LayerError>
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+
+        self.add_layer_by_mask_assign(layer_b, mask);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_value`].
+    pub fn checked_sub_value<U>(&self, value: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Send + Sync,
+    {
+        self.check_length(output)?;
+
+        self.sub_value(value, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_layer`].
+    pub fn checked_sub_layer<U>(&self, layer_b: &Layer<U>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.sub_layer(layer_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_layer_assign`].
+    pub fn checked_sub_layer_assign<U>(&mut self, layer_b: &Layer<U>) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+
+        self.sub_layer_assign(layer_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_value_weighted`].
+    pub fn checked_sub_value_weighted<U, W, X>(&self, weights: &Layer<W>, value: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<X, Output = T>,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
+    {
+        self.check_length(weights)?;
+        self.check_length(output)?;
+
+        self.sub_value_weighted(weights, value, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_value_weighted_assign`].
+    pub fn checked_sub_value_weighted_assign<U, W, X>(&mut self, weights: &Layer<W>, value: U) -> Result<(), LayerError>
+    where
+        T: Sub<X, Output = T>,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
+    {
+        self.check_length(weights)?;
+
+        self.sub_value_weighted_assign(weights, value);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_layer_weighted`].
+    pub fn checked_sub_layer_weighted<U, W, X>(&self, layer_b: &Layer<U>, weights: &Layer<W>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(weights)?;
+        self.check_length(output)?;
+
+        self.sub_layer_weighted(layer_b, weights, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_layer_weighted_assign`].
+    pub fn checked_sub_layer_weighted_assign<U, W, X>(&mut self, layer_b: &Layer<U>, weights: &Layer<W>) -> Result<(), LayerError>
+    where
+        T: Sub<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(weights)?;
+
+        self.sub_layer_weighted_assign(layer_b, weights);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_value_by_mask`].
+    pub fn checked_sub_value_by_mask<U, M>(&self, value: U, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.sub_value_by_mask(value, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_value_by_mask_assign`].
+    pub fn checked_sub_value_by_mask_assign<U, M>(&mut self, value: U, mask: &M) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+
+        self.sub_value_by_mask_assign(value, mask);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_layer_by_mask`].
+    pub fn checked_sub_layer_by_mask<U, M>(&self, layer_b: &Layer<U>, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.sub_layer_by_mask(layer_b, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sub_layer_by_mask_assign`].
+    pub fn checked_sub_layer_by_mask_assign<U, M>(&mut self, layer_b: &Layer<U>, mask: &M) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+
+        self.sub_layer_by_mask_assign(layer_b, mask);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_value`].
+    pub fn checked_entrywise_add_value<U>(&self, value: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Send + Sync,
+    {
+        self.check_length(output)?;
+
+        self.entrywise_add_value(value, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_layer`].
+    pub fn checked_entrywise_add_layer<U>(&self, layer_b: &Layer<U>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.entrywise_add_layer(layer_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_layer_assign`].
+    pub fn checked_entrywise_add_layer_assign<U>(&mut self, layer_b: &Layer<U>) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+
+        self.entrywise_add_layer_assign(layer_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_value_weighted`].
+    pub fn checked_entrywise_add_value_weighted<U, W, X>(&self, value: U, weights: &Layer<W>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<X, Output = T>,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(weights)?;
+        self.check_length(output)?;
+
+        self.entrywise_add_value_weighted(value, weights, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_value_weighted_assign`].
+    pub fn checked_entrywise_add_value_weighted_assign<U, W, X>(&mut self, value: U, weights: &Layer<W>) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<X, Output = T>,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(weights)?;
+
+        self.entrywise_add_value_weighted_assign(value, weights);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_layer_weighted`].
+    pub fn checked_entrywise_add_layer_weighted<U, W, X>(&self, layer_b: &Layer<U>, weights: &Layer<W>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(weights)?;
+        self.check_length(output)?;
+
+        self.entrywise_add_layer_weighted(layer_b, weights, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_layer_weighted_assign`].
+    pub fn checked_entrywise_add_layer_weighted_assign<U, W, X>(&mut self, layer_b: &Layer<U>, weights: &Layer<W>) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(weights)?;
+
+        self.entrywise_add_layer_weighted_assign(layer_b, weights);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_value_by_mask`].
+    pub fn checked_entrywise_add_value_by_mask<U, M>(&self, value: U, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.entrywise_add_value_by_mask(value, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_value_by_mask_assign`].
+    pub fn checked_entrywise_add_value_by_mask_assign<U, M>(&mut self, value: U, mask: &M) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+
+        self.entrywise_add_value_by_mask_assign(value, mask);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_layer_by_mask`].
+    pub fn checked_entrywise_add_layer_by_mask<U, M>(&self, layer_b: &Layer<U>, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.entrywise_add_layer_by_mask(layer_b, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_add_layer_by_mask_assign`].
+    pub fn checked_entrywise_add_layer_by_mask_assign<U, M>(&mut self, layer_b: &Layer<U>, mask: &M) -> Result<(), LayerError>
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+
+        self.entrywise_add_layer_by_mask_assign(layer_b, mask);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_value`].
+    pub fn checked_entrywise_sub_value<U>(&self, value: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Send + Sync,
+    {
+        self.check_length(output)?;
+
+        self.entrywise_sub_value(value, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_layer`].
+    pub fn checked_entrywise_sub_layer<U>(&self, layer_b: &Layer<U>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.entrywise_sub_layer(layer_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_layer_assign`].
+    pub fn checked_entrywise_sub_layer_assign<U>(&mut self, layer_b: &Layer<U>) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(layer_b)?;
+
+        self.entrywise_sub_layer_assign(layer_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_value_weighted`].
+    pub fn checked_entrywise_sub_value_weighted<U, W, X>(&self, weights: &Layer<W>, value: U, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<X, Output = T>,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
+    {
+        self.check_length(weights)?;
+        self.check_length(output)?;
+
+        self.entrywise_sub_value_weighted(weights, value, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_value_weighted_assign`].
+    pub fn checked_entrywise_sub_value_weighted_assign<U, W, X>(&mut self, weights: &Layer<W>, value: U) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<X, Output = T>,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
+    {
+        self.check_length(weights)?;
+
+        self.entrywise_sub_value_weighted_assign(weights, value);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_layer_weighted`].
+    pub fn checked_entrywise_sub_layer_weighted<U, W, X>(&self, layer_b: &Layer<U>, weights: &Layer<W>, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(weights)?;
+        self.check_length(output)?;
+
+        self.entrywise_sub_layer_weighted(layer_b, weights, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_layer_weighted_assign`].
+    pub fn checked_entrywise_sub_layer_weighted_assign<U, W, X>(&mut self, layer_b: &Layer<U>, weights: &Layer<W>) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(weights)?;
+
+        self.entrywise_sub_layer_weighted_assign(layer_b, weights);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_value_by_mask`].
+    pub fn checked_entrywise_sub_value_by_mask<U, M>(&self, value: U, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.entrywise_sub_value_by_mask(value, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_value_by_mask_assign`].
+    pub fn checked_entrywise_sub_value_by_mask_assign<U, M>(&mut self, value: U, mask: &M) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+
+        self.entrywise_sub_value_by_mask_assign(value, mask);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_layer_by_mask`].
+    pub fn checked_entrywise_sub_layer_by_mask<U, M>(&self, layer_b: &Layer<U>, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.entrywise_sub_layer_by_mask(layer_b, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sub_layer_by_mask_assign`].
+    pub fn checked_entrywise_sub_layer_by_mask_assign<U, M>(&mut self, layer_b: &Layer<U>, mask: &M) -> Result<(), LayerError>
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+
+        self.entrywise_sub_layer_by_mask_assign(layer_b, mask);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::to_magnitudes`].
+    pub fn checked_to_magnitudes<U>(&self, output: &mut Layer<U>) -> Result<(), LayerError>
+    where
+        T: Magnitude<Output = U>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(output)?;
+
+        self.to_magnitudes(output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::inv`].
+    pub fn checked_inv(&self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Inv,
+    {
+        self.check_length(output)?;
+
+        self.inv(output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::neg`].
+    pub fn checked_neg(&self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Neg<Output = T>,
+    {
+        self.check_length(output)?;
+
+        self.neg(output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::sqrt`].
+    pub fn checked_sqrt(&self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: SquareRoot,
+    {
+        self.check_length(output)?;
+
+        self.sqrt(output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::e_x`].
+    pub fn checked_e_x<U>(&self, output: &mut Layer<U>) -> Result<(), LayerError>
+    where
+        T: EX<Output = U>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.check_length(output)?;
+
+        self.e_x(output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_inv`].
+    pub fn checked_entrywise_inv(&self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseInv<Output = T>,
+    {
+        self.check_length(output)?;
+
+        self.entrywise_inv(output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_sqrt`].
+    pub fn checked_entrywise_sqrt(&self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseSqrt<Output = T>,
+    {
+        self.check_length(output)?;
+
+        self.entrywise_sqrt(output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::entrywise_e_x`].
+    pub fn checked_entrywise_e_x(&self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: EntrywiseEX<Output = T>,
+    {
+        self.check_length(output)?;
+
+        self.entrywise_e_x(output);
+
+        Ok(())
+    }
+    // endregion Field operations
+
+    // region Raster graphics
+    /// Length-checked [`Layer::copy_into_selection`].
+    pub fn checked_copy_into_selection<M>(&self, layer_b: &Self, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.copy_into_selection(layer_b, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::copy_into_selection_assign`].
+    pub fn checked_copy_into_selection_assign<M>(&mut self, layer_b: &Self, mask: &M) -> Result<(), LayerError>
+    where
+        M: Mask,
+    {
+        self.check_length(layer_b)?;
+        self.check_mask_length(mask)?;
+
+        self.copy_into_selection_assign(layer_b, mask);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::fill_into_selection`].
+    pub fn checked_fill_into_selection<M>(&self, value: T, mask: &M, output: &mut Self) -> Result<(), LayerError>
+    where
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+        self.check_length(output)?;
+
+        self.fill_into_selection(value, mask, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::fill_into_selection_assign`].
+    pub fn checked_fill_into_selection_assign<M>(&mut self, value: T, mask: &M) -> Result<(), LayerError>
+    where
+        M: Mask,
+    {
+        self.check_mask_length(mask)?;
+
+        self.fill_into_selection_assign(value, mask);
+
+        Ok(())
+    }
+    // endregion Raster graphics
+
+    // region Interpolations
+    /// Length-checked [`Layer::mix_value_value`].
+    pub fn checked_mix_value_value(&self, v: T, w: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.check_length(output)?;
+
+        self.mix_value_value(v, w, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::mix_value_layer`].
+    pub fn checked_mix_value_layer(&self, v: T, layer_c: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.check_length(layer_c)?;
+        self.check_length(output)?;
+
+        self.mix_value_layer(v, layer_c, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::mix_value_layer_assign`].
+    pub fn checked_mix_value_layer_assign(&mut self, v: T, layer_c: &Self) -> Result<(), LayerError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.check_length(layer_c)?;
+
+        self.mix_value_layer_assign(v, layer_c);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::mix_layer_value`].
+    pub fn checked_mix_layer_value(&self, layer_b: &Self, w: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.mix_layer_value(layer_b, w, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::mix_layer_value_assign`].
+    pub fn checked_mix_layer_value_assign(&mut self, layer_b: &Self, w: T) -> Result<(), LayerError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.check_length(layer_b)?;
+
+        self.mix_layer_value_assign(layer_b, w);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::mix_layer_layer`].
+    pub fn checked_mix_layer_layer(&self, layer_b: &Self, layer_c: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+        self.check_length(output)?;
+
+        self.mix_layer_layer(layer_b, layer_c, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::mix_layer_layer_assign`].
+    pub fn checked_mix_layer_layer_assign(&mut self, layer_b: &Self, layer_c: &Self) -> Result<(), LayerError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+
+        self.mix_layer_layer_assign(layer_b, layer_c);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::clamp_value_value`].
+    pub fn checked_clamp_value_value(&self, v: T, w: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd,
+    {
+        self.check_length(output)?;
+
+        self.clamp_value_value(v, w, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::clamp_value_layer`].
+    pub fn checked_clamp_value_layer(&self, v: T, layer_c: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd,
+    {
+        self.check_length(layer_c)?;
+        self.check_length(output)?;
+
+        self.clamp_value_layer(v, layer_c, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::clamp_value_layer_assign`].
+    pub fn checked_clamp_value_layer_assign(&mut self, v: T, layer_c: &Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd,
+    {
+        self.check_length(layer_c)?;
+
+        self.clamp_value_layer_assign(v, layer_c);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::clamp_layer_value`].
+    pub fn checked_clamp_layer_value(&self, layer_b: &Self, w: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.clamp_layer_value(layer_b, w, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::clamp_layer_value_assign`].
+    pub fn checked_clamp_layer_value_assign(&mut self, layer_b: &Self, w: T) -> Result<(), LayerError>
+    where
+        T: PartialOrd,
+    {
+        self.check_length(layer_b)?;
+
+        self.clamp_layer_value_assign(layer_b, w);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::clamp_layer_layer`].
+    pub fn checked_clamp_layer_layer(&self, layer_b: &Self, layer_c: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+        self.check_length(output)?;
+
+        self.clamp_layer_layer(layer_b, layer_c, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::clamp_layer_layer_assign`].
+    pub fn checked_clamp_layer_layer_assign(&mut self, layer_b: &Self, layer_c: &Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+
+        self.clamp_layer_layer_assign(layer_b, layer_c);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::step_value`].
+    pub fn checked_step_value(&self, value: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + One,
+    {
+        self.check_length(output)?;
+
+        self.step_value(value, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::step_layer`].
+    pub fn checked_step_layer(&self, layer_b: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + One,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.step_layer(layer_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::step_layer_assign`].
+    pub fn checked_step_layer_assign(&mut self, layer_b: &Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + One,
+    {
+        self.check_length(layer_b)?;
+
+        self.step_layer_assign(layer_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::linearstep_value_value`].
+    pub fn checked_linearstep_value_value(&self, value_a: T, value_b: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.check_length(output)?;
+
+        self.linearstep_value_value(value_a, value_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::linearstep_value_layer`].
+    pub fn checked_linearstep_value_layer(&self, value_a: T, layer_c: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.check_length(layer_c)?;
+        self.check_length(output)?;
+
+        self.linearstep_value_layer(value_a, layer_c, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::linearstep_value_layer_assign`].
+    pub fn checked_linearstep_value_layer_assign(&mut self, value_a: T, layer_c: &Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.check_length(layer_c)?;
+
+        self.linearstep_value_layer_assign(value_a, layer_c);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::linearstep_layer_value`].
+    pub fn checked_linearstep_layer_value(&self, layer_b: &Self, value_b: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.linearstep_layer_value(layer_b, value_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::linearstep_layer_value_assign`].
+    pub fn checked_linearstep_layer_value_assign(&mut self, layer_b: &Self, value_b: T) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+
+        self.linearstep_layer_value_assign(layer_b, value_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::linearstep_layer_layer`].
+    pub fn checked_linearstep_layer_layer(&self, layer_b: &Self, layer_c: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+        self.check_length(output)?;
+
+        self.linearstep_layer_layer(layer_b, layer_c, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::linearstep_layer_layer_assign`].
+    pub fn checked_linearstep_layer_layer_assign(&mut self, layer_b: &Self, layer_c: &Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+
+        self.linearstep_layer_layer_assign(layer_b, layer_c);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep_value_value`].
+    pub fn checked_smoothstep_value_value(&self, value_a: T, value_b: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.check_length(output)?;
+
+        self.smoothstep_value_value(value_a, value_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep_value_layer`].
+    pub fn checked_smoothstep_value_layer(&self, value_a: T, layer_c: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.check_length(layer_c)?;
+        self.check_length(output)?;
+
+        self.smoothstep_value_layer(value_a, layer_c, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep_value_layer_assign`].
+    pub fn checked_smoothstep_value_layer_assign(&mut self, value_a: T, layer_c: &Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.check_length(layer_c)?;
+
+        self.smoothstep_value_layer_assign(value_a, layer_c);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep_layer_value`].
+    pub fn checked_smoothstep_layer_value(&self, layer_b: &Self, value_b: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.smoothstep_layer_value(layer_b, value_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep_layer_value_assign`].
+    pub fn checked_smoothstep_layer_value_assign(&mut self, layer_b: &Self, value_b: T) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+
+        self.smoothstep_layer_value_assign(layer_b, value_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep_layer_layer`].
+    pub fn checked_smoothstep_layer_layer(&self, layer_b: &Self, layer_c: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+        self.check_length(output)?;
+
+        self.smoothstep_layer_layer(layer_b, layer_c, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep_layer_layer_assign`].
+    pub fn checked_smoothstep_layer_layer_assign(&mut self, layer_b: &Self, layer_c: &Self) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(layer_c)?;
+
+        self.smoothstep_layer_layer_assign(layer_b, layer_c);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep2_value`].
+    pub fn checked_smoothstep2_value(&self, value: T, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Neg<Output = T> + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + EX<Output = T> + Inv + One,
+    {
+        self.check_length(output)?;
+
+        self.smoothstep2_value(value, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep2_layer`].
+    pub fn checked_smoothstep2_layer(&self, layer_b: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Neg<Output = T> + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + EX<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.smoothstep2_layer(layer_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::smoothstep2_layer_assign`].
+    pub fn checked_smoothstep2_layer_assign(&mut self, layer_b: &Self) -> Result<(), LayerError>
+    where
+        T: Neg<Output = T> + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + EX<Output = T> + Inv + One,
+    {
+        self.check_length(layer_b)?;
+
+        self.smoothstep2_layer_assign(layer_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::lerp`].
+    pub fn checked_lerp(&self, xs: &[T], ys: &[T], output: &mut Self, pool: &LayerPool) -> Result<(), LayerError>
+    where
+        T: PartialOrd + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Inv + One + 'static,
+    {
+        check_control_points(xs, ys)?;
+        self.check_length(output)?;
+
+        self.lerp(xs, ys, output, pool);
+
+        Ok(())
+    }
+    // endregion Interpolations
+
+    // region Morphology
+    /// Length-checked [`Layer::union`].
+    pub fn checked_union(&self, layer_b: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: BitOr<Output = T>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.union(layer_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::union_assign`].
+    pub fn checked_union_assign(&mut self, layer_b: &Self) -> Result<(), LayerError>
+    where
+        T: BitOr<Output = T>,
+    {
+        self.check_length(layer_b)?;
+
+        self.union_assign(layer_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::intersection`].
+    pub fn checked_intersection(&self, layer_b: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: BitAnd<Output = T>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.intersection(layer_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::intersection_assign`].
+    pub fn checked_intersection_assign(&mut self, layer_b: &Self) -> Result<(), LayerError>
+    where
+        T: BitAnd<Output = T>,
+    {
+        self.check_length(layer_b)?;
+
+        self.intersection_assign(layer_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::difference`].
+    pub fn checked_difference(&self, layer_b: &Self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: BitAnd<Output = T> + Not<Output = T>,
+    {
+        self.check_length(layer_b)?;
+        self.check_length(output)?;
+
+        self.difference(layer_b, output);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::difference_assign`].
+    pub fn checked_difference_assign(&mut self, layer_b: &Self) -> Result<(), LayerError>
+    where
+        T: BitAnd<Output = T> + Not<Output = T>,
+    {
+        self.check_length(layer_b)?;
+
+        self.difference_assign(layer_b);
+
+        Ok(())
+    }
+
+    /// Length-checked [`Layer::not`].
+    pub fn checked_not(&self, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Not<Output = T>,
+    {
+        self.check_length(output)?;
+
+        self.not(output);
+
+        Ok(())
+    }
+    // endregion Morphology
+}
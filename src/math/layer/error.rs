@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// An error of a checked layer operation.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LayerError {
+    /// A layer length doesn’t match length of the source layer of an operation, or other layer it must match.
+    LengthMismatch { expected: usize, actual: usize },
+
+    /// A mapping or a list of control points refers to an item beyond the end of a layer, or a layer an operation reads
+    /// items from is empty.
+    OutOfBounds { index: usize, length: usize },
+
+    /// An item of a layer is NaN, and an operation uses the [`NanPolicy::Error`](crate::NanPolicy::Error) policy.
+    NotANumber { index: usize },
+
+    /// Weights of items an operation ranks don’t sum to a positive number; e.g., all of them are masked out.
+    InvalidWeights,
+}
+
+impl fmt::Display for LayerError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LayerError::LengthMismatch { expected, actual } => write!(fmt, "layer length mismatch: expected {}, got {}", expected, actual),
+            LayerError::OutOfBounds { index, length } => write!(fmt, "index {} is out of bounds of layer of length {}", index, length),
+            LayerError::NotANumber { index } => write!(fmt, "item {} is NaN", index),
+            LayerError::InvalidWeights => write!(fmt, "weights don’t sum to a positive number"),
+        }
+    }
+}
+
+impl std::error::Error for LayerError {}
@@ -0,0 +1,308 @@
+//! Lazy layer expressions.
+//!
+//! Every [`Layer`] method walks its operands and writes a full output layer, so a chain of $n$ operations reads and writes
+//! memory $n$ times. An [`Expr`] only records operations, and evaluates the whole chain for each item in a single pass once
+//! it’s written into a layer:
+//!
+//! ```
+//! # use isecad::*;
+//! # let a = Layer::from(vec![0.25, 0.5, 0.75]);
+//! # let b = Layer::from(vec![0.5, 0.5, 0.5]);
+//! # let mut output = Layer::new(3);
+//! // O_i = clamp(S_i k + B_i, 0, 1)
+//! expr(&a).mul(2.0).add(&b).clamp(0.0, 1.0).write_into(&mut output);
+//! # assert_eq!(&*output, &[1.0, 1.0, 1.0]);
+//! ```
+//!
+//! Operands may be layers, values (scalars, [`V2`], [`V3`], [`V3d`], and [`V4`]), or other expressions. Like the [`Layer::map2`], they
+//! don’t check lengths in release builds.
+
+use crate::*;
+use std::marker::*;
+use std::ops::*;
+
+/// Creates an expression over items of a layer.
+pub fn expr<T>(layer: &Layer<T>) -> Expr<T, impl Fn(usize) -> T + Send + Sync + '_>
+where
+    T: Copy + Default + Send + Sync,
+{
+    Expr::new(layer.len(), move |i| layer[i])
+}
+
+/// An operand of an [`Expr`] node.
+pub trait Operand<T>: Send + Sync {
+    /// Returns $i$-th item of an operand.
+    fn at(&self, i: usize) -> T;
+
+    /// Returns length of an operand, or `None` if it’s a single value.
+    fn length(&self) -> Option<usize>;
+}
+
+/// Layer operand.
+impl<T> Operand<T> for &Layer<T>
+where
+    T: Copy + Send + Sync,
+{
+    fn at(&self, i: usize) -> T {
+        self[i]
+    }
+
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// Nested expression operand.
+impl<T, F> Operand<T> for Expr<T, F>
+where
+    F: Fn(usize) -> T + Send + Sync,
+{
+    fn at(&self, i: usize) -> T {
+        (self.f)(i)
+    }
+
+    fn length(&self) -> Option<usize> {
+        Some(self.length)
+    }
+}
+
+/// Value operands can’t be generic over a value type, since they would overlap with layer and expression ones.
+macro_rules! impl_value_operand {
+    ($($value:ty),*) => {$(
+        /// Value operand.
+        impl Operand<$value> for $value {
+            fn at(&self, _: usize) -> $value {
+                *self
+            }
+
+            fn length(&self) -> Option<usize> {
+                None
+            }
+        }
+    )*};
+}
+
+impl_value_operand!(f32, f64, u8, u16, u32, i16, i32, V2, V3, V3d, V4);
+
+/// A lazy layer expression.
+///
+/// Created with the [`expr()`] function; see the [module docs](self) for details.
+#[derive(Clone, Copy, Debug)]
+pub struct Expr<T, F> {
+    length: usize,
+    f: F,
+    item: PhantomData<fn() -> T>,
+}
+
+/// Arithmetic nodes can’t be operator trait impls, since their output types are unnameable closures.
+#[allow(clippy::should_implement_trait)]
+impl<T, F> Expr<T, F>
+where
+    T: Copy + Send + Sync,
+    F: Fn(usize) -> T + Send + Sync,
+{
+    fn new(length: usize, f: F) -> Self {
+        Self { length, f, item: PhantomData }
+    }
+
+    fn check_length<U>(&self, operand: &impl Operand<U>) {
+        if let Some(length) = operand.length() {
+            debug_assert_eq!(self.length, length, "operand length mismatch");
+        }
+    }
+
+    /// Returns length of layers produced by an expression.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if an expression produces empty layers.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    // region Core functionality
+    /// $O_i = f(S_i)$
+    pub fn map<U, G>(self, g: G) -> Expr<U, impl Fn(usize) -> U + Send + Sync>
+    where
+        U: Copy + Send + Sync,
+        G: Fn(T) -> U + Send + Sync,
+    {
+        let f = self.f;
+
+        Expr::new(self.length, move |i| g(f(i)))
+    }
+
+    /// $O_i = f(S_i, B_i)$
+    pub fn map2<U, W, B, G>(self, b: B, g: G) -> Expr<W, impl Fn(usize) -> W + Send + Sync>
+    where
+        U: Copy + Send + Sync,
+        W: Copy + Send + Sync,
+        B: Operand<U>,
+        G: Fn(T, U) -> W + Send + Sync,
+    {
+        self.check_length(&b);
+
+        let f = self.f;
+
+        Expr::new(self.length, move |i| g(f(i), b.at(i)))
+    }
+
+    /// $O_i = f(S_i, B_i, C_i)$
+    pub fn map3<U, W, X, B, C, G>(self, b: B, c: C, g: G) -> Expr<X, impl Fn(usize) -> X + Send + Sync>
+    where
+        U: Copy + Send + Sync,
+        W: Copy + Send + Sync,
+        X: Copy + Send + Sync,
+        B: Operand<U>,
+        C: Operand<W>,
+        G: Fn(T, U, W) -> X + Send + Sync,
+    {
+        self.check_length(&b);
+        self.check_length(&c);
+
+        let f = self.f;
+
+        Expr::new(self.length, move |i| g(f(i), b.at(i), c.at(i)))
+    }
+
+    /// Evaluates an expression, writes result to an output layer.
+    pub fn write_into(&self, output: &mut Layer<T>) {
+        debug_assert_eq!(self.length, output.len(), "output layer length mismatch");
+
+        kernels::map_indices(output, &self.f);
+    }
+
+    /// Evaluates an expression into new layer.
+    pub fn evaluate(&self) -> Layer<T>
+    where
+        T: Default,
+    {
+        let mut output = Layer::new(self.length);
+
+        self.write_into(&mut output);
+
+        output
+    }
+    // endregion Core functionality
+
+    // region Field operations
+    /// $O_i = S_i + B_i$
+    pub fn add<U, B>(self, b: B) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Send + Sync,
+        B: Operand<U>,
+    {
+        self.map2(b, T::add)
+    }
+
+    /// $O_i = S_i - B_i$
+    pub fn sub<U, B>(self, b: B) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Send + Sync,
+        B: Operand<U>,
+    {
+        self.map2(b, T::sub)
+    }
+
+    /// $O_i = S_i B_i$
+    pub fn mul<U, B>(self, b: B) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Mul<U, Output = T>,
+        U: Copy + Send + Sync,
+        B: Operand<U>,
+    {
+        self.map2(b, T::mul)
+    }
+
+    /// $O_i = \frac{S_i}{B_i}$
+    pub fn div<U, B>(self, b: B) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Div<U, Output = T>,
+        U: Copy + Send + Sync,
+        B: Operand<U>,
+    {
+        self.map2(b, T::div)
+    }
+
+    /// $O_i = -S_i$
+    pub fn neg(self) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Neg<Output = T>,
+    {
+        self.map(T::neg)
+    }
+
+    /// $O_i = S_i + W_i B_i$
+    pub fn add_weighted<U, W, X, B, C>(self, b: B, weights: C) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Add<X, Output = T>,
+        U: Copy + Send + Sync,
+        W: Copy + Send + Sync + Mul<U, Output = X>,
+        B: Operand<U>,
+        C: Operand<W>,
+    {
+        self.map3(b, weights, |s_i, b_i, w_i| s_i + w_i * b_i)
+    }
+
+    /// $O_i = S_i - W_i B_i$
+    pub fn sub_weighted<U, W, X, B, C>(self, b: B, weights: C) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Sub<X, Output = T>,
+        U: Copy + Send + Sync,
+        W: Copy + Send + Sync + Mul<U, Output = X>,
+        B: Operand<U>,
+        C: Operand<W>,
+    {
+        self.map3(b, weights, |s_i, b_i, w_i| s_i - w_i * b_i)
+    }
+    // endregion Field operations
+
+    // region Interpolations
+    /// $O_i = S_i (C_i - B_i) + B_i$
+    pub fn mix<B, C>(self, b: B, c: C) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+        B: Operand<T>,
+        C: Operand<T>,
+    {
+        self.map3(b, c, interpolations::mix)
+    }
+
+    /// $O_i = \begin{cases}
+    ///     B_i, & \text{if}   & S_i < B_i \\
+    ///     C_i, & \text{if}   & S_i > C_i \\
+    ///     S_i, & \text{else} &           \\
+    /// \end{cases}$
+    pub fn clamp<B, C>(self, b: B, c: C) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: PartialOrd,
+        B: Operand<T>,
+        C: Operand<T>,
+    {
+        self.map3(b, c, interpolations::clamp)
+    }
+
+    /// $O_i = \operatorname{clamp}(\frac{S_i - B_i}{C_i - B_i}, 0, 1)$
+    pub fn linearstep<B, C>(self, b: B, c: C) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Default + PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+        B: Operand<T>,
+        C: Operand<T>,
+    {
+        self.map3(b, c, interpolations::linearstep)
+    }
+
+    /// $O_i = L_i^2 (3 - 2 L_i)$, where $L_i = \operatorname{clamp}(\frac{S_i - B_i}{C_i - B_i}, 0, 1)$.
+    pub fn smoothstep<B, C>(self, b: B, c: C) -> Expr<T, impl Fn(usize) -> T + Send + Sync>
+    where
+        T: Default + PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+        B: Operand<T>,
+        C: Operand<T>,
+    {
+        self.map3(b, c, interpolations::smoothstep)
+    }
+    // endregion Interpolations
+}
@@ -0,0 +1,529 @@
+//! Layer kernels.
+//!
+//! All layer operations are built on top of these functions, so they’re the only place to choose between serial and parallel
+//! evaluation. With the `parallel` feature enabled, kernels split layers into chunks of [`CHUNK_LENGTH`] items and process
+//! them on the rayon thread pool.
+//!
+//! Reductions are always evaluated chunk by chunk, even without the `parallel` feature: every chunk is reduced on its own,
+//! then chunk results are combined sequentially from left to right. Chunk boundaries only depend on a layer length, so
+//! results are bit-identical regardless of the feature and of the number of threads.
+//!
+//! Sums are also compensated, both within chunks and across them (see [`CompensatedSum`]), so they don’t drift on large
+//! layers.
+
+use crate::*;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::ops::*;
+
+/// Number of items processed by a single task.
+pub const CHUNK_LENGTH: usize = 4096;
+
+/// $O_i = f(S_i)$
+pub fn map1<T, U, F>(source: &[T], output: &mut [U], f: F)
+where
+    T: Copy + Send + Sync,
+    U: Send,
+    F: Fn(T) -> U + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    output
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(source.par_chunks(CHUNK_LENGTH))
+        .for_each(|(output, source)| map1_serial(source, output, &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map1_serial(source, output, &f);
+}
+
+fn map1_serial<T, U, F>(source: &[T], output: &mut [U], f: &F)
+where
+    T: Copy,
+    F: Fn(T) -> U,
+{
+    for (o_i, &s_i) in output.iter_mut().zip(source) {
+        *o_i = f(s_i);
+    }
+}
+
+/// $O_i = f(S_i, B_i)$
+pub fn map2<T, U, W, F>(source: &[T], layer_b: &[U], output: &mut [W], f: F)
+where
+    T: Copy + Send + Sync,
+    U: Copy + Send + Sync,
+    W: Send,
+    F: Fn(T, U) -> W + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    output
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(source.par_chunks(CHUNK_LENGTH))
+        .zip(layer_b.par_chunks(CHUNK_LENGTH))
+        .for_each(|((output, source), layer_b)| map2_serial(source, layer_b, output, &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map2_serial(source, layer_b, output, &f);
+}
+
+fn map2_serial<T, U, W, F>(source: &[T], layer_b: &[U], output: &mut [W], f: &F)
+where
+    T: Copy,
+    U: Copy,
+    F: Fn(T, U) -> W,
+{
+    for ((o_i, &s_i), &b_i) in output.iter_mut().zip(source).zip(layer_b) {
+        *o_i = f(s_i, b_i);
+    }
+}
+
+/// $O_i = f(S_i, B_i, C_i)$
+pub fn map3<T, U, W, X, F>(source: &[T], layer_b: &[U], layer_c: &[W], output: &mut [X], f: F)
+where
+    T: Copy + Send + Sync,
+    U: Copy + Send + Sync,
+    W: Copy + Send + Sync,
+    X: Send,
+    F: Fn(T, U, W) -> X + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    output
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(source.par_chunks(CHUNK_LENGTH))
+        .zip(layer_b.par_chunks(CHUNK_LENGTH))
+        .zip(layer_c.par_chunks(CHUNK_LENGTH))
+        .for_each(|(((output, source), layer_b), layer_c)| map3_serial(source, layer_b, layer_c, output, &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map3_serial(source, layer_b, layer_c, output, &f);
+}
+
+fn map3_serial<T, U, W, X, F>(source: &[T], layer_b: &[U], layer_c: &[W], output: &mut [X], f: &F)
+where
+    T: Copy,
+    U: Copy,
+    W: Copy,
+    F: Fn(T, U, W) -> X,
+{
+    for (((o_i, &s_i), &b_i), &c_i) in output.iter_mut().zip(source).zip(layer_b).zip(layer_c) {
+        *o_i = f(s_i, b_i, c_i);
+    }
+}
+
+/// $O_i = f(i)$
+pub fn map_indices<U, F>(output: &mut [U], f: F)
+where
+    U: Send,
+    F: Fn(usize) -> U + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    output
+        .par_chunks_mut(CHUNK_LENGTH)
+        .enumerate()
+        .for_each(|(chunk, output)| map_indices_serial(chunk * CHUNK_LENGTH, output, &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map_indices_serial(0, output, &f);
+}
+
+fn map_indices_serial<U, F>(offset: usize, output: &mut [U], f: &F)
+where
+    F: Fn(usize) -> U,
+{
+    for (i, o_i) in output.iter_mut().enumerate() {
+        *o_i = f(offset + i);
+    }
+}
+
+/// $S_i \gets f(S_i)$
+pub fn map1_assign<T, F>(source: &mut [T], f: F)
+where
+    T: Copy + Send + Sync,
+    F: Fn(T) -> T + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    source.par_chunks_mut(CHUNK_LENGTH).for_each(|source| map1_assign_serial(source, &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map1_assign_serial(source, &f);
+}
+
+fn map1_assign_serial<T, F>(source: &mut [T], f: &F)
+where
+    T: Copy,
+    F: Fn(T) -> T,
+{
+    for s_i in source.iter_mut() {
+        *s_i = f(*s_i);
+    }
+}
+
+/// $S_i \gets f(S_i, B_i)$
+pub fn map2_assign<T, U, F>(source: &mut [T], layer_b: &[U], f: F)
+where
+    T: Copy + Send + Sync,
+    U: Copy + Send + Sync,
+    F: Fn(T, U) -> T + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    source
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(layer_b.par_chunks(CHUNK_LENGTH))
+        .for_each(|(source, layer_b)| map2_assign_serial(source, layer_b, &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map2_assign_serial(source, layer_b, &f);
+}
+
+fn map2_assign_serial<T, U, F>(source: &mut [T], layer_b: &[U], f: &F)
+where
+    T: Copy,
+    U: Copy,
+    F: Fn(T, U) -> T,
+{
+    for (s_i, &b_i) in source.iter_mut().zip(layer_b) {
+        *s_i = f(*s_i, b_i);
+    }
+}
+
+/// $S_i \gets f(S_i, B_i, C_i)$
+pub fn map3_assign<T, U, W, F>(source: &mut [T], layer_b: &[U], layer_c: &[W], f: F)
+where
+    T: Copy + Send + Sync,
+    U: Copy + Send + Sync,
+    W: Copy + Send + Sync,
+    F: Fn(T, U, W) -> T + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    source
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(layer_b.par_chunks(CHUNK_LENGTH))
+        .zip(layer_c.par_chunks(CHUNK_LENGTH))
+        .for_each(|((source, layer_b), layer_c)| map3_assign_serial(source, layer_b, layer_c, &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map3_assign_serial(source, layer_b, layer_c, &f);
+}
+
+fn map3_assign_serial<T, U, W, F>(source: &mut [T], layer_b: &[U], layer_c: &[W], f: &F)
+where
+    T: Copy,
+    U: Copy,
+    W: Copy,
+    F: Fn(T, U, W) -> T,
+{
+    for ((s_i, &b_i), &c_i) in source.iter_mut().zip(layer_b).zip(layer_c) {
+        *s_i = f(*s_i, b_i, c_i);
+    }
+}
+
+/// $O_i = f(S_i, M_i)$
+pub fn map1_masked<T, U, M, F>(source: &[T], mask: &M, output: &mut [U], f: F)
+where
+    T: Copy + Send + Sync,
+    U: Send,
+    M: Mask + ?Sized,
+    F: Fn(T, bool) -> U + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    output
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(source.par_chunks(CHUNK_LENGTH))
+        .enumerate()
+        .for_each(|(chunk, (output, source))| map1_masked_serial(source, mask_chunk(mask, chunk, source.len()), output, &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map1_masked_serial(source, mask_chunk(mask, 0, source.len()), output, &f);
+}
+
+fn map1_masked_serial<T, U, F>(source: &[T], mask: impl Iterator<Item = bool>, output: &mut [U], f: &F)
+where
+    T: Copy,
+    F: Fn(T, bool) -> U,
+{
+    for ((o_i, &s_i), m_i) in output.iter_mut().zip(source).zip(mask) {
+        *o_i = f(s_i, m_i);
+    }
+}
+
+/// $O_i = f(S_i, B_i, M_i)$
+pub fn map2_masked<T, U, W, M, F>(source: &[T], layer_b: &[U], mask: &M, output: &mut [W], f: F)
+where
+    T: Copy + Send + Sync,
+    U: Copy + Send + Sync,
+    W: Send,
+    M: Mask + ?Sized,
+    F: Fn(T, U, bool) -> W + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    output
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(source.par_chunks(CHUNK_LENGTH))
+        .zip(layer_b.par_chunks(CHUNK_LENGTH))
+        .enumerate()
+        .for_each(|(chunk, ((output, source), layer_b))| map2_masked_serial(source, layer_b, mask_chunk(mask, chunk, source.len()), output, &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map2_masked_serial(source, layer_b, mask_chunk(mask, 0, source.len()), output, &f);
+}
+
+fn map2_masked_serial<T, U, W, F>(source: &[T], layer_b: &[U], mask: impl Iterator<Item = bool>, output: &mut [W], f: &F)
+where
+    T: Copy,
+    U: Copy,
+    F: Fn(T, U, bool) -> W,
+{
+    for (((o_i, &s_i), &b_i), m_i) in output.iter_mut().zip(source).zip(layer_b).zip(mask) {
+        *o_i = f(s_i, b_i, m_i);
+    }
+}
+
+/// $S_i \gets f(S_i, M_i)$
+pub fn map1_masked_assign<T, M, F>(source: &mut [T], mask: &M, f: F)
+where
+    T: Copy + Send + Sync,
+    M: Mask + ?Sized,
+    F: Fn(T, bool) -> T + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    source
+        .par_chunks_mut(CHUNK_LENGTH)
+        .enumerate()
+        .for_each(|(chunk, source)| map1_masked_assign_serial(source, mask_chunk(mask, chunk, source.len()), &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map1_masked_assign_serial(source, mask_chunk(mask, 0, source.len()), &f);
+}
+
+fn map1_masked_assign_serial<T, F>(source: &mut [T], mask: impl Iterator<Item = bool>, f: &F)
+where
+    T: Copy,
+    F: Fn(T, bool) -> T,
+{
+    for (s_i, m_i) in source.iter_mut().zip(mask) {
+        *s_i = f(*s_i, m_i);
+    }
+}
+
+/// $S_i \gets f(S_i, B_i, M_i)$
+pub fn map2_masked_assign<T, U, M, F>(source: &mut [T], layer_b: &[U], mask: &M, f: F)
+where
+    T: Copy + Send + Sync,
+    U: Copy + Send + Sync,
+    M: Mask + ?Sized,
+    F: Fn(T, U, bool) -> T + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    source
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(layer_b.par_chunks(CHUNK_LENGTH))
+        .enumerate()
+        .for_each(|(chunk, (source, layer_b))| map2_masked_assign_serial(source, layer_b, mask_chunk(mask, chunk, source.len()), &f));
+
+    #[cfg(not(feature = "parallel"))]
+    map2_masked_assign_serial(source, layer_b, mask_chunk(mask, 0, source.len()), &f);
+}
+
+fn map2_masked_assign_serial<T, U, F>(source: &mut [T], layer_b: &[U], mask: impl Iterator<Item = bool>, f: &F)
+where
+    T: Copy,
+    U: Copy,
+    F: Fn(T, U, bool) -> T,
+{
+    for ((s_i, &b_i), m_i) in source.iter_mut().zip(layer_b).zip(mask) {
+        *s_i = f(*s_i, b_i, m_i);
+    }
+}
+
+/// $O_{(k)} = f(S_{(k)})$
+///
+/// Like the [`map1`], but calls `f` once per chunk of [`CHUNK_LENGTH`] items, so it may process several items at once; e.g.,
+/// with SIMD.
+pub fn map_chunks1<T, U, F>(source: &[T], output: &mut [U], f: F)
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&[T], &mut [U]) + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    output
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(source.par_chunks(CHUNK_LENGTH))
+        .for_each(|(output, source)| f(source, output));
+
+    #[cfg(not(feature = "parallel"))]
+    for (output, source) in output.chunks_mut(CHUNK_LENGTH).zip(source.chunks(CHUNK_LENGTH)) {
+        f(source, output);
+    }
+}
+
+/// $O_{(k)} = f(S_{(k)}, B_{(k)})$
+///
+/// Like the [`map2`], but calls `f` once per chunk of [`CHUNK_LENGTH`] items.
+pub fn map_chunks2<T, U, W, F>(source: &[T], layer_b: &[U], output: &mut [W], f: F)
+where
+    T: Sync,
+    U: Sync,
+    W: Send,
+    F: Fn(&[T], &[U], &mut [W]) + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    output
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(source.par_chunks(CHUNK_LENGTH))
+        .zip(layer_b.par_chunks(CHUNK_LENGTH))
+        .for_each(|((output, source), layer_b)| f(source, layer_b, output));
+
+    #[cfg(not(feature = "parallel"))]
+    for ((output, source), layer_b) in output
+        .chunks_mut(CHUNK_LENGTH)
+        .zip(source.chunks(CHUNK_LENGTH))
+        .zip(layer_b.chunks(CHUNK_LENGTH))
+    {
+        f(source, layer_b, output);
+    }
+}
+
+/// $S_{(k)} \gets f(S_{(k)})$
+///
+/// Like the [`map1_assign`], but calls `f` once per chunk of [`CHUNK_LENGTH`] items.
+pub fn map_chunks1_assign<T, F>(source: &mut [T], f: F)
+where
+    T: Send,
+    F: Fn(&mut [T]) + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    source.par_chunks_mut(CHUNK_LENGTH).for_each(f);
+
+    #[cfg(not(feature = "parallel"))]
+    source.chunks_mut(CHUNK_LENGTH).for_each(f);
+}
+
+/// $S_{(k)} \gets f(S_{(k)}, B_{(k)})$
+///
+/// Like the [`map2_assign`], but calls `f` once per chunk of [`CHUNK_LENGTH`] items.
+pub fn map_chunks2_assign<T, U, F>(source: &mut [T], layer_b: &[U], f: F)
+where
+    T: Send,
+    U: Sync,
+    F: Fn(&mut [T], &[U]) + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    source
+        .par_chunks_mut(CHUNK_LENGTH)
+        .zip(layer_b.par_chunks(CHUNK_LENGTH))
+        .for_each(|(source, layer_b)| f(source, layer_b));
+
+    #[cfg(not(feature = "parallel"))]
+    for (source, layer_b) in source.chunks_mut(CHUNK_LENGTH).zip(layer_b.chunks(CHUNK_LENGTH)) {
+        f(source, layer_b);
+    }
+}
+
+/// Iterates over items of a mask in the `chunk`-th chunk, stopping at the end of a mask.
+fn mask_chunk<M>(mask: &M, chunk: usize, length: usize) -> impl Iterator<Item = bool> + '_
+where
+    M: Mask + ?Sized,
+{
+    let end = usize::min(chunk * CHUNK_LENGTH + length, mask.len());
+    let start = usize::min(chunk * CHUNK_LENGTH, end);
+
+    mask.iter_range(start..end)
+}
+
+/// Reduces items in the $[0, l)$ range chunk by chunk.
+///
+/// Calls `chunk` for ranges of [`CHUNK_LENGTH`] items (the last one may be shorter), then folds their results from left to
+/// right, starting with `identity`.
+pub fn reduce<R, C, F>(length: usize, identity: R, chunk: C, combine: F) -> R
+where
+    R: Send,
+    C: Fn(Range<usize>) -> R + Send + Sync,
+    F: Fn(R, R) -> R,
+{
+    let chunk_range = |i: usize| i * CHUNK_LENGTH..usize::min((i + 1) * CHUNK_LENGTH, length);
+    let chunks = length.div_ceil(CHUNK_LENGTH);
+
+    #[cfg(feature = "parallel")]
+    let results = (0..chunks).into_par_iter().map(|i| chunk(chunk_range(i))).collect::<Vec<_>>().into_iter();
+
+    #[cfg(not(feature = "parallel"))]
+    let results = (0..chunks).map(|i| chunk(chunk_range(i)));
+
+    results.fold(identity, combine)
+}
+
+/// Compensated running sum.
+///
+/// Uses Kahan summation: rounding error of every addition is kept in a separate compensation term and subtracted from the
+/// next item, so the error doesn’t grow with the number of items. Only addition and subtraction are used, so vectors are
+/// compensated componentwise.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CompensatedSum<T> {
+    sum: T,
+    compensation: T,
+}
+
+impl<T> CompensatedSum<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+{
+    /// Adds an item to a sum.
+    pub fn push(&mut self, x: T) {
+        let y = x - self.compensation;
+        let sum = self.sum + y;
+
+        self.compensation = (sum - self.sum) - y;
+        self.sum = sum;
+    }
+
+    /// Adds another sum to a sum, keeping compensations of both.
+    pub fn combine(mut self, other: Self) -> Self {
+        self.push(other.sum);
+        self.compensation = self.compensation + other.compensation;
+
+        self
+    }
+
+    /// Returns compensated value of a sum.
+    pub fn value(self) -> T {
+        self.sum - self.compensation
+    }
+}
+
+/// $\sum_i f(i)$ for $i \in [0, l)$
+///
+/// Sums items with [`CompensatedSum`] within each chunk, then combines chunk sums from left to right like the [`reduce`].
+pub fn sum<T, F>(length: usize, f: F) -> T
+where
+    T: Copy + Default + Send + Add<Output = T> + Sub<Output = T>,
+    F: Fn(usize) -> T + Send + Sync,
+{
+    reduce(
+        length,
+        CompensatedSum::default(),
+        |range| {
+            let mut sum = CompensatedSum::default();
+
+            for i in range {
+                sum.push(f(i));
+            }
+
+            sum
+        },
+        CompensatedSum::combine,
+    )
+    .value()
+}
+
+/// Sorts items with a comparator function, without preserving the order of equal items.
+pub fn sort_unstable_by<T, F>(items: &mut [T], compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> std::cmp::Ordering + Sync,
+{
+    #[cfg(feature = "parallel")]
+    items.par_sort_unstable_by(compare);
+
+    #[cfg(not(feature = "parallel"))]
+    items.sort_unstable_by(compare);
+}
@@ -6,7 +6,7 @@ use std::collections::*;
 use std::hash::*;
 use std::ops::*;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Layer<T>(Box<[T]>);
 
 impl<T> std::ops::Deref for Layer<T> {
@@ -23,6 +23,18 @@ impl<T> std::ops::DerefMut for Layer<T> {
     }
 }
 
+impl<T> From<Vec<T>> for Layer<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self(vec.into_boxed_slice())
+    }
+}
+
+impl<T> std::iter::FromIterator<T> for Layer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 impl<'a, T: Copy + Default> IntoIterator for &'a Layer<T> {
     type Item = &'a T;
     type IntoIter = std::slice::Iter<'a, T>;
@@ -32,6 +44,53 @@ impl<'a, T: Copy + Default> IntoIterator for &'a Layer<T> {
     }
 }
 
+/// Running extrema of numeric proportionals of items.
+///
+/// Bounds are only initial placeholders: extrema are taken from the first comparable item, so items equal to the bounds
+/// are found too.
+struct Extrema<U> {
+    min: U,
+    max: U,
+    min_index: Option<usize>,
+    max_index: Option<usize>,
+    nan_index: Option<usize>,
+}
+
+impl<U: Copy + PartialOrd + Bounded> Extrema<U> {
+    fn new() -> Self {
+        Self {
+            min: U::MAX_BOUND,
+            max: U::MIN_BOUND,
+            min_index: None,
+            max_index: None,
+            nan_index: None,
+        }
+    }
+
+    /// Combines extrema of two consecutive ranges, preferring the first one on ties.
+    fn combine(a: Self, b: Self) -> Self {
+        let (min, min_index) = if b.min_index.is_some() && (a.min_index.is_none() || b.min < a.min) {
+            (b.min, b.min_index)
+        } else {
+            (a.min, a.min_index)
+        };
+
+        let (max, max_index) = if b.max_index.is_some() && (a.max_index.is_none() || b.max > a.max) {
+            (b.max, b.max_index)
+        } else {
+            (a.max, a.max_index)
+        };
+
+        Self {
+            min,
+            max,
+            min_index,
+            max_index,
+            nan_index: a.nan_index.or(b.nan_index),
+        }
+    }
+}
+
 /// # Common abbreviations
 ///
 /// -   Layers:
@@ -46,7 +105,7 @@ impl<'a, T: Copy + Default> IntoIterator for &'a Layer<T> {
 ///     -   $\min X$, $\max X$ — $\min(X_0 \mathellipsis X_n), \max(X_0 \mathellipsis X_n)$ — min and max values of $X$ layer.
 ///     -   $X_n$ — $X_{L_X - 1}$ — last element of $X$ layer.
 ///     -   $v$ — `value` — single-value operand of some operations.
-impl<T: Copy + Default> Layer<T> {
+impl<T: Copy + Default + Send + Sync> Layer<T> {
     // region Core functionality
     /// Creates new layer of specified length.
     pub fn new(length: usize) -> Self {
@@ -57,59 +116,165 @@ impl<T: Copy + Default> Layer<T> {
         Self(vec.into_boxed_slice())
     }
 
+    /// $S_i = f(i)$
+    ///
+    /// Creates new layer of specified length using a callback.
+    pub fn from_fn<F>(length: usize, f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        (0..length).map(f).collect()
+    }
+
     /// Applies a one-argument callback to each item of a layer, writes result of a callback to an output layer.
     pub fn map1<F, U>(&self, output: &mut Layer<U>, f: F)
     where
-        F: Fn(T) -> U,
-        U: Copy + Default,
+        F: Fn(T) -> U + Send + Sync,
+        U: Copy + Default + Send + Sync,
     {
-        for (i, &s_i) in self.iter().enumerate() {
-            output[i] = f(s_i);
-        }
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
+        kernels::map1(self, output, f);
     }
 
     /// Applies a two-argument callback to each item of a layer and given value, writes result of a callback to an output layer.
     pub fn map1_with<F, U, W>(&self, value: U, output: &mut Layer<W>, f: F)
     where
-        F: Fn(T, U) -> W,
-        U: Copy,
-        W: Copy + Default,
+        F: Fn(T, U) -> W + Send + Sync,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
-        for (i, &s_i) in self.iter().enumerate() {
-            output[i] = f(s_i, value);
-        }
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
+        kernels::map1(self, output, |s_i| f(s_i, value));
     }
 
     /// Applies a two-argument callback to each item of two layers, writes result of a callback to an output layer.
     pub fn map2<F, U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>, f: F)
     where
-        F: Fn(T, U) -> W,
-        U: Copy + Default,
-        W: Copy + Default,
+        F: Fn(T, U) -> W + Send + Sync,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
-        for ((i, &s_i), &b_i) in self.iter().enumerate().zip(layer_b) {
-            output[i] = f(s_i, b_i);
-        }
+        debug_assert_eq!(self.len(), layer_b.len(), "second operand layer length mismatch");
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
+        kernels::map2(self, layer_b, output, f);
     }
 
     /// Applies a three-argument callback to each item of three layers, writes result of a callback to an output layer.
     pub fn map3<F, U, W, X>(&self, layer_b: &Layer<U>, layer_c: &Layer<W>, output: &mut Layer<X>, f: F)
     where
-        F: Fn(T, U, W) -> X,
-        U: Copy + Default,
-        W: Copy + Default,
-        X: Copy + Default,
+        F: Fn(T, U, W) -> X + Send + Sync,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
+        X: Copy + Default + Send + Sync,
     {
-        for (((i, &s_i), &b_i), &c_i) in self.iter().enumerate().zip(layer_b).zip(layer_c) {
-            output[i] = f(s_i, b_i, c_i);
-        }
+        debug_assert_eq!(self.len(), layer_b.len(), "second operand layer length mismatch");
+        debug_assert_eq!(self.len(), layer_c.len(), "third operand layer length mismatch");
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
+        kernels::map3(self, layer_b, layer_c, output, f);
+    }
+
+    /// Applies a one-argument callback to each item of a layer, writes result of a callback back to the layer.
+    pub fn map1_assign<F>(&mut self, f: F)
+    where
+        F: Fn(T) -> T + Send + Sync,
+    {
+        kernels::map1_assign(self, f);
+    }
+
+    /// Applies a two-argument callback to each item of a layer and given value, writes result of a callback back to the layer.
+    pub fn map1_with_assign<F, U>(&mut self, value: U, f: F)
+    where
+        F: Fn(T, U) -> T + Send + Sync,
+        U: Copy + Send + Sync,
+    {
+        kernels::map1_assign(self, |s_i| f(s_i, value));
+    }
+
+    /// Applies a two-argument callback to each item of two layers, writes result of a callback back to the first layer.
+    pub fn map2_assign<F, U>(&mut self, layer_b: &Layer<U>, f: F)
+    where
+        F: Fn(T, U) -> T + Send + Sync,
+        U: Copy + Default + Send + Sync,
+    {
+        debug_assert_eq!(self.len(), layer_b.len(), "second operand layer length mismatch");
+
+        kernels::map2_assign(self, layer_b, f);
+    }
+
+    /// Applies a three-argument callback to each item of three layers, writes result of a callback back to the first layer.
+    pub fn map3_assign<F, U, W>(&mut self, layer_b: &Layer<U>, layer_c: &Layer<W>, f: F)
+    where
+        F: Fn(T, U, W) -> T + Send + Sync,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
+    {
+        debug_assert_eq!(self.len(), layer_b.len(), "second operand layer length mismatch");
+        debug_assert_eq!(self.len(), layer_c.len(), "third operand layer length mismatch");
+
+        kernels::map3_assign(self, layer_b, layer_c, f);
+    }
+
+    /// Applies a two-argument callback to each item of a layer and a mask, writes result of a callback to an output layer.
+    pub fn map_masked<F, U, M>(&self, mask: &M, output: &mut Layer<U>, f: F)
+    where
+        F: Fn(T, bool) -> U + Send + Sync,
+        U: Copy + Default + Send + Sync,
+        M: Mask + ?Sized,
+    {
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
+        kernels::map1_masked(self, mask, output, f);
+    }
+
+    /// Applies a three-argument callback to each item of two layers and a mask, writes result of a callback to an output layer.
+    pub fn map2_masked<F, U, W, M>(&self, layer_b: &Layer<U>, mask: &M, output: &mut Layer<W>, f: F)
+    where
+        F: Fn(T, U, bool) -> W + Send + Sync,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
+        M: Mask + ?Sized,
+    {
+        debug_assert_eq!(self.len(), layer_b.len(), "second operand layer length mismatch");
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
+        kernels::map2_masked(self, layer_b, mask, output, f);
+    }
+
+    /// Applies a two-argument callback to each item of a layer and a mask, writes result of a callback back to the layer.
+    pub fn map_masked_assign<F, M>(&mut self, mask: &M, f: F)
+    where
+        F: Fn(T, bool) -> T + Send + Sync,
+        M: Mask + ?Sized,
+    {
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        kernels::map1_masked_assign(self, mask, f);
+    }
+
+    /// Applies a three-argument callback to each item of two layers and a mask, writes result of a callback back to the first layer.
+    pub fn map2_masked_assign<F, U, M>(&mut self, layer_b: &Layer<U>, mask: &M, f: F)
+    where
+        F: Fn(T, U, bool) -> T + Send + Sync,
+        U: Copy + Default + Send + Sync,
+        M: Mask + ?Sized,
+    {
+        debug_assert_eq!(self.len(), layer_b.len(), "second operand layer length mismatch");
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        kernels::map2_masked_assign(self, layer_b, mask, f);
     }
 
     /// Converts a layer into another type.
     pub fn convert<U>(&self) -> Layer<U>
     where
         T: Into<U>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
     {
         let mut new = Layer::new(self.len());
 
@@ -134,6 +299,8 @@ impl<T: Copy + Default> Layer<T> {
     /// -   `mapping` — $M$ — the mapping layer.
     /// -   `output` — $O$ — the output layer to copy items into.
     pub fn swizzle(&self, mapping: &Layer<usize>, output: &mut Self) {
+        debug_assert_eq!(mapping.len(), output.len(), "output layer length mismatch");
+
         mapping.map1(output, |m_i| self[m_i]);
     }
 
@@ -157,6 +324,9 @@ impl<T: Copy + Default> Layer<T> {
     where
         T: std::ops::Add<Output = T>,
     {
+        debug_assert_eq!(mapping.len(), add.len(), "added layer length mismatch");
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
         for (&m_i, &a_i) in mapping.iter().zip(add) {
             output[m_i] = self[m_i] + a_i;
         }
@@ -175,43 +345,141 @@ impl<T: Copy + Default> Layer<T> {
     pub fn copy_into(&self, output: &mut Self) {
         output.copy_from_slice(self);
     }
+    // endregion Core functionality
+
+    // region Statistics
+    /// $\sum_i S_i$
+    pub fn sum(&self) -> T
+    where
+        T: Add<Output = T> + Sub<Output = T>,
+    {
+        self.sum_as()
+    }
+
+    /// $\sum_i S_i$
+    ///
+    /// Converts items into an accumulator type before summing them, e.g., into `f64` for `f32` layers.
+    pub fn sum_as<A>(&self) -> A
+    where
+        T: Into<A>,
+        A: Copy + Default + Send + Add<Output = A> + Sub<Output = A>,
+    {
+        kernels::sum(self.len(), |i| self[i].into())
+    }
 
-    /// Unsafely converts an immutable layer reference to a mutable.
+    /// Calculates average value of an field.
     ///
-    /// May be used in calls like:
+    /// Items are summed in their [`Average::Accumulator`], so integer items are summed in 64-bit integers and don’t
+    /// overflow. Averages of integer items are truncated toward zero, like their division is; see [`Layer::average_as`] to
+    /// average them in a floating-point accumulator instead.
     ///
     /// ```
-    /// let layer = &mut Layer::new(10);
+    /// use isecad::*;
+    ///
+    /// let layer = Layer::from(vec![-1i32, 2, 6]);
     ///
-    /// layer.add_value(1.0, layer.to_mut()); // Equivalent to `layer += 1.0`.
+    /// assert_eq!(layer.average(), 2);
+    /// assert_eq!(layer.average_as::<f64>(), 7.0 / 3.0);
+    /// assert_eq!(Layer::from(vec![-1i16, -2]).average(), -1);
+    /// assert_eq!(Layer::from(vec![200u8, 200]).average(), 200);
     /// ```
-    pub unsafe fn to_mut(&self) -> &mut Self {
-        std::mem::transmute_copy(&self)
+    pub fn average(&self) -> T
+    where
+        T: Average,
+    {
+        T::from_accumulator(self.average_as())
     }
-    // endregion Core functionality
 
-    // region Statistics
-    /// Calculates average value of an field.
-    pub fn average(&self) -> T
+    /// [`Layer::average`] evaluated in an accumulator type.
+    pub fn average_as<A>(&self) -> A
     where
-        T: Add<Output = T> + DivUsize,
+        T: Into<A>,
+        A: Copy + Default + Send + Add<Output = A> + Sub<Output = A> + DivUsize,
     {
-        self.iter().fold(T::default(), |sum, &x| sum + x).div_usize(self.len())
+        self.sum_as::<A>().div_usize(self.len())
+    }
+
+    /// [`Layer::average`], or `None` if a layer is empty.
+    pub fn try_average(&self) -> Option<T>
+    where
+        T: Average,
+    {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.average())
+        }
     }
 
     /// Calculates weighted average of a field using given weights layer.
     pub fn weighted_average<U>(&self, weights: &Layer<U>) -> T
     where
-        T: Add<Output = T> + Mul<U, Output = T> + Div<U, Output = T>,
-        U: Copy + Default + Add<Output = U>,
+        T: Add<Output = T> + Sub<Output = T> + Mul<U, Output = T> + Div<U, Output = T>,
+        U: Copy + Default + Send + Sync + Add<Output = U> + Sub<Output = U>,
     {
-        let (sum, weights_sum) = self.iter().zip(weights).fold((T::default(), U::default()), |(sum, weights_sum), (&l_i, &w_i)| {
-            (sum + l_i * w_i, weights_sum + w_i)
-        });
+        self.weighted_average_as(weights)
+    }
+
+    /// [`Layer::weighted_average`] evaluated in accumulator types: `A` for items, and `V` for weights.
+    pub fn weighted_average_as<A, V, U>(&self, weights: &Layer<U>) -> A
+    where
+        T: Into<A>,
+        A: Copy + Default + Send + Add<Output = A> + Sub<Output = A> + Mul<V, Output = A> + Div<V, Output = A>,
+        V: Copy + Default + Send + Add<Output = V> + Sub<Output = V>,
+        U: Copy + Send + Sync + Into<V>,
+    {
+        let (sum, weights_sum) = self.weighted_sums_as(weights);
 
         sum / weights_sum
     }
 
+    /// [`Layer::weighted_average`], or `None` if weights sum to zero; e.g., if a layer is empty.
+    pub fn try_weighted_average<U>(&self, weights: &Layer<U>) -> Option<T>
+    where
+        T: Add<Output = T> + Sub<Output = T> + Mul<U, Output = T> + Div<U, Output = T>,
+        U: Copy + Default + Send + Sync + Add<Output = U> + Sub<Output = U> + PartialEq,
+    {
+        let (sum, weights_sum) = self.weighted_sums_as::<T, U, U>(weights);
+
+        if weights_sum == U::default() {
+            None
+        } else {
+            Some(sum / weights_sum)
+        }
+    }
+
+    /// $(\sum_i S_i W_i, \sum_i W_i)$ evaluated in accumulator types.
+    fn weighted_sums_as<A, V, U>(&self, weights: &Layer<U>) -> (A, V)
+    where
+        T: Into<A>,
+        A: Copy + Default + Send + Add<Output = A> + Sub<Output = A> + Mul<V, Output = A>,
+        V: Copy + Default + Send + Add<Output = V> + Sub<Output = V>,
+        U: Copy + Send + Sync + Into<V>,
+    {
+        debug_assert_eq!(self.len(), weights.len(), "weights layer length mismatch");
+
+        let (sum, weights_sum) = kernels::reduce(
+            self.len(),
+            (kernels::CompensatedSum::default(), kernels::CompensatedSum::default()),
+            |range| {
+                let mut sum = kernels::CompensatedSum::default();
+                let mut weights_sum = kernels::CompensatedSum::default();
+
+                for i in range {
+                    let w_i: V = weights[i].into();
+
+                    sum.push(self[i].into() * w_i);
+                    weights_sum.push(w_i);
+                }
+
+                (sum, weights_sum)
+            },
+            |(sum_a, weights_sum_a), (sum_b, weights_sum_b)| (sum_a.combine(sum_b), weights_sum_a.combine(weights_sum_b)),
+        );
+
+        (sum.value(), weights_sum.value())
+    }
+
     /// Creates a set of unique values of a layer.
     pub fn unique(&self) -> HashSet<T>
     where
@@ -227,61 +495,113 @@ impl<T: Copy + Default> Layer<T> {
     }
 
     /// $(\min S, \max S)$
+    ///
+    /// Skips NaN items; returns default values if a layer is empty or has only NaN items.
     pub fn min_max<U>(&self) -> (T, T)
     where
         T: ToNumeric<U>,
-        U: Copy + PartialOrd + Bounded,
+        U: Copy + Send + PartialOrd + Bounded,
+    {
+        self.try_min_max().unwrap_or_default()
+    }
+
+    /// $(\argmin S, \argmax S)$
+    ///
+    /// Skips NaN items; returns zeros if a layer is empty or has only NaN items.
+    pub fn min_max_indices<U>(&self) -> (usize, usize)
+    where
+        T: ToNumeric<U>,
+        U: Copy + Send + PartialOrd + Bounded,
     {
-        let mut min_proportional = U::MAX_BOUND;
-        let mut max_proportional = U::MIN_BOUND;
+        self.try_min_max_indices().unwrap_or_default()
+    }
 
-        let mut min = T::default();
-        let mut max = T::default();
+    /// [`Layer::min_max`], or `None` if a layer is empty or has only NaN items.
+    pub fn try_min_max<U>(&self) -> Option<(T, T)>
+    where
+        T: ToNumeric<U>,
+        U: Copy + Send + PartialOrd + Bounded,
+    {
+        self.try_min_max_indices().map(|(min_index, max_index)| (self[min_index], self[max_index]))
+    }
 
-        for &s_i in self {
-            let numeric_proportional = s_i.into_numeric_proportional();
+    /// [`Layer::min_max_indices`], or `None` if a layer is empty or has only NaN items.
+    pub fn try_min_max_indices<U>(&self) -> Option<(usize, usize)>
+    where
+        T: ToNumeric<U>,
+        U: Copy + Send + PartialOrd + Bounded,
+    {
+        let extrema = self.extrema();
 
-            if numeric_proportional < min_proportional {
-                min_proportional = numeric_proportional;
-                min = s_i;
-            }
+        Some((extrema.min_index?, extrema.max_index?))
+    }
 
-            if numeric_proportional > max_proportional {
-                max_proportional = numeric_proportional;
-                max = s_i;
-            }
-        }
+    /// [`Layer::try_min_max`] with specified NaN policy.
+    ///
+    /// With [`NanPolicy::Propagate`], both values are the first NaN item.
+    pub fn min_max_with<U>(&self, nan: NanPolicy) -> Result<Option<(T, T)>, LayerError>
+    where
+        T: ToNumeric<U>,
+        U: Copy + Send + PartialOrd + Bounded,
+    {
+        let indices = self.min_max_indices_with(nan)?;
 
-        (min, max)
+        Ok(indices.map(|(min_index, max_index)| (self[min_index], self[max_index])))
     }
 
-    /// $(\argmin S, \argmax S)$
-    pub fn min_max_indices<U>(&self) -> (usize, usize)
+    /// [`Layer::try_min_max_indices`] with specified NaN policy.
+    ///
+    /// With [`NanPolicy::Propagate`], both indices point to the first NaN item.
+    pub fn min_max_indices_with<U>(&self, nan: NanPolicy) -> Result<Option<(usize, usize)>, LayerError>
     where
         T: ToNumeric<U>,
-        U: Copy + PartialOrd + Bounded,
+        U: Copy + Send + PartialOrd + Bounded,
     {
-        let mut min_proportional = U::MAX_BOUND;
-        let mut max_proportional = U::MIN_BOUND;
+        let extrema = self.extrema();
 
-        let mut min_index = 0;
-        let mut max_index = 0;
+        match (nan, extrema.nan_index) {
+            (NanPolicy::Propagate, Some(index)) => Ok(Some((index, index))),
+            (NanPolicy::Error, Some(index)) => Err(LayerError::NotANumber { index }),
+            _ => Ok(extrema.min_index.zip(extrema.max_index)),
+        }
+    }
 
-        for (i, &s_i) in self.iter().enumerate() {
-            let numeric_proportional = s_i.into_numeric_proportional();
+    /// Finds first items with the smallest and the largest numeric proportionals, and the first NaN item.
+    fn extrema<U>(&self) -> Extrema<U>
+    where
+        T: ToNumeric<U>,
+        U: Copy + Send + PartialOrd + Bounded,
+    {
+        kernels::reduce(
+            self.len(),
+            Extrema::new(),
+            |range| {
+                let mut extrema = Extrema::new();
 
-            if numeric_proportional < min_proportional {
-                min_proportional = numeric_proportional;
-                min_index = i;
-            }
+                for i in range {
+                    let numeric_proportional = self[i].into_numeric_proportional();
 
-            if numeric_proportional > max_proportional {
-                max_proportional = numeric_proportional;
-                max_index = i;
-            }
-        }
+                    if numeric_proportional.partial_cmp(&numeric_proportional).is_none() {
+                        extrema.nan_index = extrema.nan_index.or(Some(i));
+
+                        continue;
+                    }
+
+                    if extrema.min_index.is_none() || numeric_proportional < extrema.min {
+                        extrema.min = numeric_proportional;
+                        extrema.min_index = Some(i);
+                    }
+
+                    if extrema.max_index.is_none() || numeric_proportional > extrema.max {
+                        extrema.max = numeric_proportional;
+                        extrema.max_index = Some(i);
+                    }
+                }
 
-        (min_index, max_index)
+                extrema
+            },
+            Extrema::combine,
+        )
     }
 
     /// $O_i = \frac{u_t - l_t}{u_f - l_f} (S_i - l_f) + l_t$
@@ -299,7 +619,7 @@ impl<T: Copy + Default> Layer<T> {
     pub fn rescale_from_to_range<U>(&self, from_lower: U, from_upper: U, to_lower: U, to_upper: U, output: &mut Self)
     where
         T: Sub<U, Output = T> + Mul<U, Output = T> + Add<U, Output = T>,
-        U: Copy + Sub<Output = U> + Div<Output = U>,
+        U: Copy + Send + Sync + Sub<Output = U> + Div<Output = U>,
     {
         let scaling_factor = (to_upper - to_lower) / (from_upper - from_lower);
 
@@ -319,7 +639,7 @@ impl<T: Copy + Default> Layer<T> {
     pub fn rescale_to_range<U>(&self, to_lower: U, to_upper: U, output: &mut Self)
     where
         T: Sub<U, Output = T> + Mul<U, Output = T> + Add<U, Output = T> + ToNumeric<U>,
-        U: Copy + Sub<Output = U> + Div<Output = U> + PartialOrd + Bounded,
+        U: Copy + Send + Sync + Sub<Output = U> + Div<Output = U> + PartialOrd + Bounded,
     {
         let (from_lower, from_upper) = self.min_max();
 
@@ -335,7 +655,7 @@ impl<T: Copy + Default> Layer<T> {
     pub fn normalize<U>(&self, output: &mut Self)
     where
         T: Sub<U, Output = T> + Mul<U, Output = T> + ToNumeric<U>,
-        U: Copy + Sub<Output = U> + PartialOrd + Bounded + Inv,
+        U: Copy + Send + Sync + Sub<Output = U> + PartialOrd + Bounded + Inv,
     {
         let (from_lower, from_upper) = self.min_max();
 
@@ -360,7 +680,7 @@ impl<T: Copy + Default> Layer<T> {
     pub fn rescale_from_to<U>(&self, from_upper: U, to_upper: U, output: &mut Self)
     where
         T: Mul<U, Output = T>,
-        U: Copy + Div<Output = U>,
+        U: Copy + Send + Sync + Div<Output = U>,
     {
         let scaling_factor = to_upper / from_upper;
 
@@ -379,7 +699,7 @@ impl<T: Copy + Default> Layer<T> {
     pub fn rescale_to<U>(&self, to_upper: U, output: &mut Self)
     where
         T: Mul<U, Output = T> + ToNumeric<U>,
-        U: Copy + Div<Output = U> + PartialOrd + Bounded,
+        U: Copy + Send + Sync + Div<Output = U> + PartialOrd + Bounded,
     {
         let (_, from_upper) = self.min_max();
 
@@ -388,6 +708,70 @@ impl<T: Copy + Default> Layer<T> {
         self.rescale_from_to(from_upper, to_upper, output);
     }
 
+    /// [`Layer::rescale_to_range`] with specified NaN policy.
+    ///
+    /// With [`NanPolicy::Propagate`], NaN items make the whole output NaN. Also checks the output length, like the
+    /// [`Layer::checked_rescale_to_range`].
+    pub fn rescale_to_range_with<U>(&self, to_lower: U, to_upper: U, nan: NanPolicy, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T> + Mul<U, Output = T> + Add<U, Output = T> + ToNumeric<U>,
+        U: Copy + Send + Sync + Sub<Output = U> + Div<Output = U> + PartialOrd + Bounded,
+    {
+        self.check_length(output)?;
+
+        let (from_lower, from_upper) = self.min_max_with(nan)?.unwrap_or_default();
+
+        let from_lower = from_lower.into_numeric();
+        let from_upper = from_upper.into_numeric();
+
+        self.rescale_from_to_range(from_lower, from_upper, to_lower, to_upper, output);
+
+        Ok(())
+    }
+
+    /// [`Layer::normalize`] with specified NaN policy.
+    ///
+    /// With [`NanPolicy::Propagate`], NaN items make the whole output NaN. Also checks the output length, like the
+    /// [`Layer::checked_normalize`].
+    pub fn normalize_with<U>(&self, nan: NanPolicy, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Sub<U, Output = T> + Mul<U, Output = T> + ToNumeric<U>,
+        U: Copy + Send + Sync + Sub<Output = U> + PartialOrd + Bounded + Inv,
+    {
+        self.check_length(output)?;
+
+        let (from_lower, from_upper) = self.min_max_with(nan)?.unwrap_or_default();
+
+        let from_lower = from_lower.into_numeric();
+        let from_upper = from_upper.into_numeric();
+
+        let scaling_factor = (from_upper - from_lower).inv();
+
+        self.map1(output, |s_i| (s_i - from_lower) * scaling_factor);
+
+        Ok(())
+    }
+
+    /// [`Layer::rescale_to`] with specified NaN policy.
+    ///
+    /// With [`NanPolicy::Propagate`], NaN items make the whole output NaN. Also checks the output length, like the
+    /// [`Layer::checked_rescale_to`].
+    pub fn rescale_to_with<U>(&self, to_upper: U, nan: NanPolicy, output: &mut Self) -> Result<(), LayerError>
+    where
+        T: Mul<U, Output = T> + ToNumeric<U>,
+        U: Copy + Send + Sync + Div<Output = U> + PartialOrd + Bounded,
+    {
+        self.check_length(output)?;
+
+        let (_, from_upper) = self.min_max_with(nan)?.unwrap_or_default();
+
+        let from_upper = from_upper.into_numeric();
+
+        self.rescale_from_to(from_upper, to_upper, output);
+
+        Ok(())
+    }
+
     /// Normalizes each value in a field.
     pub fn normalize_each(&self, output: &mut Self)
     where
@@ -583,8 +967,8 @@ impl<T: Copy + Default> Layer<T> {
     fn mul_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
         T: Mul<U, Output = W>,
-        U: Copy,
-        W: Copy + Default,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map1_with(value, output, T::mul);
     }
@@ -593,8 +977,8 @@ impl<T: Copy + Default> Layer<T> {
     fn mul_layer<U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>)
     where
         T: Mul<U, Output = W>,
-        U: Copy + Default,
-        W: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::mul);
     }
@@ -605,8 +989,8 @@ impl<T: Copy + Default> Layer<T> {
     fn div_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
         T: Div<U, Output = W>,
-        U: Copy,
-        W: Copy + Default,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map1_with(value, output, T::div);
     }
@@ -615,8 +999,8 @@ impl<T: Copy + Default> Layer<T> {
     fn div_layer<U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>)
     where
         T: Div<U, Output = W>,
-        U: Copy + Default,
-        W: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::div);
     }
@@ -627,8 +1011,8 @@ impl<T: Copy + Default> Layer<T> {
     fn pow_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
         T: Power<U, Output = W>,
-        U: Copy,
-        W: Copy + Default,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map1_with(value, output, T::power);
     }
@@ -636,8 +1020,8 @@ impl<T: Copy + Default> Layer<T> {
     /// $O_i = v^{S_i}$
     fn exp_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
-        U: Copy + Power<T, Output = W>,
-        W: Copy + Default,
+        U: Copy + Send + Sync + Power<T, Output = W>,
+        W: Copy + Default + Send + Sync,
     {
         self.map1(output, |s_i| value.power(s_i));
     }
@@ -646,8 +1030,8 @@ impl<T: Copy + Default> Layer<T> {
     fn pow_layer<U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>)
     where
         T: Power<U, Output = W>,
-        U: Copy + Default,
-        W: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::power);
     }
@@ -658,8 +1042,8 @@ impl<T: Copy + Default> Layer<T> {
     fn entrywise_mul_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
         T: EntrywiseMul<U, Output = W>,
-        U: Copy,
-        W: Copy + Default,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map1_with(value, output, T::entrywise_mul);
     }
@@ -668,8 +1052,8 @@ impl<T: Copy + Default> Layer<T> {
     fn entrywise_mul_layer<U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>)
     where
         T: EntrywiseMul<U, Output = W>,
-        U: Copy + Default,
-        W: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::entrywise_mul);
     }
@@ -680,8 +1064,8 @@ impl<T: Copy + Default> Layer<T> {
     fn entrywise_div_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
         T: EntrywiseDiv<U, Output = W>,
-        U: Copy,
-        W: Copy + Default,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map1_with(value, output, T::entrywise_div);
     }
@@ -690,8 +1074,8 @@ impl<T: Copy + Default> Layer<T> {
     fn entrywise_div_layer<U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>)
     where
         T: EntrywiseDiv<U, Output = W>,
-        U: Copy + Default,
-        W: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::entrywise_div);
     }
@@ -702,8 +1086,8 @@ impl<T: Copy + Default> Layer<T> {
     fn entrywise_pow_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
         T: EntrywisePow<U, Output = W>,
-        U: Copy,
-        W: Copy + Default,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map1_with(value, output, T::entrywise_pow);
     }
@@ -711,8 +1095,8 @@ impl<T: Copy + Default> Layer<T> {
     /// $O_{i_j} = v^{S_{i_j}}$
     fn entrywise_exp_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
-        U: Copy + EntrywisePow<T, Output = W>,
-        W: Copy + Default,
+        U: Copy + Send + Sync + EntrywisePow<T, Output = W>,
+        W: Copy + Default + Send + Sync,
     {
         self.map1(output, |s_i| value.entrywise_pow(s_i));
     }
@@ -721,8 +1105,8 @@ impl<T: Copy + Default> Layer<T> {
     fn entrywise_pow_layer<U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>)
     where
         T: EntrywisePow<U, Output = W>,
-        U: Copy + Default,
-        W: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::entrywise_pow);
     }
@@ -733,8 +1117,8 @@ impl<T: Copy + Default> Layer<T> {
     fn dot_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
         T: Dot<U, Output = W>,
-        U: Copy,
-        W: Copy + Default,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map1_with(value, output, T::dot);
     }
@@ -743,8 +1127,8 @@ impl<T: Copy + Default> Layer<T> {
     fn dot_layer<U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>)
     where
         T: Dot<U, Output = W>,
-        U: Copy + Default,
-        W: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::dot);
     }
@@ -755,8 +1139,8 @@ impl<T: Copy + Default> Layer<T> {
     fn similarity_value<U, W>(&self, value: U, output: &mut Layer<W>)
     where
         T: Similarity<U, Output = W>,
-        U: Copy,
-        W: Copy + Default,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map1_with(value, output, T::similarity);
     }
@@ -765,8 +1149,8 @@ impl<T: Copy + Default> Layer<T> {
     fn similarity_layer<U, W>(&self, layer_b: &Layer<U>, output: &mut Layer<W>)
     where
         T: Similarity<U, Output = W>,
-        U: Copy + Default,
-        W: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::similarity);
     }
@@ -777,19 +1161,37 @@ impl<T: Copy + Default> Layer<T> {
     pub fn add_value<U>(&self, value: U, output: &mut Self)
     where
         T: Add<U, Output = T>,
-        U: Copy,
+        U: Copy + Send + Sync,
     {
         self.map1_with(value, output, T::add);
     }
 
+    /// [`Layer::add_value`] with assignment.
+    pub fn add_value_assign<U>(&mut self, value: U)
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Send + Sync,
+    {
+        self.map1_with_assign(value, T::add);
+    }
+
     /// $O_i = S_i + B_i$
     pub fn add_layer<U>(&self, layer_b: &Layer<U>, output: &mut Self)
     where
         T: Add<U, Output = T>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::add);
     }
+
+    /// [`Layer::add_layer`] with assignment.
+    pub fn add_layer_assign<U>(&mut self, layer_b: &Layer<U>)
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.map2_assign(layer_b, T::add);
+    }
     // endregion add
 
     // region add_weighted
@@ -799,23 +1201,43 @@ impl<T: Copy + Default> Layer<T> {
     pub fn add_value_weighted<U, W, X>(&self, value: U, weights: &Layer<W>, output: &mut Self)
     where
         T: Add<X, Output = T>,
-        U: Copy,
-        W: Copy + Default + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
     {
         self.map2(weights, output, |s_i, w_i| s_i + w_i * value);
     }
 
+    /// [`Layer::add_value_weighted`] with assignment.
+    pub fn add_value_weighted_assign<U, W, X>(&mut self, value: U, weights: &Layer<W>)
+    where
+        T: Add<X, Output = T>,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.map2_assign(weights, |s_i, w_i| s_i + w_i * value);
+    }
+
     /// $O_i = S_i + W_i B_i$
     ///
     /// `add_field_term` in Tectonics.js.
     pub fn add_layer_weighted<U, W, X>(&self, layer_b: &Layer<U>, weights: &Layer<W>, output: &mut Self)
     where
         T: Add<X, Output = T>,
-        U: Copy + Default,
-        W: Copy + Default + Mul<U, Output = X>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
     {
         self.map3(layer_b, weights, output, |s_i, b_i, w_i| s_i + w_i * b_i);
     }
+
+    /// [`Layer::add_layer_weighted`] with assignment.
+    pub fn add_layer_weighted_assign<U, W, X>(&mut self, layer_b: &Layer<U>, weights: &Layer<W>)
+    where
+        T: Add<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.map3_assign(layer_b, weights, |s_i, b_i, w_i| s_i + w_i * b_i);
+    }
     // endregion add_weighted
 
     // region add_by_mask
@@ -825,12 +1247,23 @@ impl<T: Copy + Default> Layer<T> {
     /// \end{cases}$
     ///
     /// `add_scalar_term` in Tectonics.js.
-    pub fn add_value_by_mask<U>(&self, value: U, mask: &Layer<bool>, output: &mut Self)
+    pub fn add_value_by_mask<U, M>(&self, value: U, mask: &M, output: &mut Self)
     where
         T: Add<U, Output = T>,
-        U: Copy,
+        U: Copy + Send + Sync,
+        M: Mask,
     {
-        self.map2(mask, output, |s_i, m_i| if m_i { s_i + value } else { s_i });
+        self.map_masked(mask, output, |s_i, m_i| if m_i { s_i + value } else { s_i });
+    }
+
+    /// [`Layer::add_value_by_mask`] with assignment.
+    pub fn add_value_by_mask_assign<U, M>(&mut self, value: U, mask: &M)
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.map_masked_assign(mask, |s_i, m_i| if m_i { s_i + value } else { s_i });
     }
 
     /// $O_i = \begin{cases}
@@ -843,12 +1276,23 @@ impl<T: Copy + Default> Layer<T> {
     /// TODO:
     ///  Mask should be read repeatedly; or we have to reconsider `Lithosphere#merge_plates_to_master` code to make
     ///  `globalized_plate_mask` match length of `master.total_crust` and `globalized_crust` crusts.
-    pub fn add_layer_by_mask<U>(&self, layer_b: &Layer<U>, mask: &Layer<bool>, output: &mut Self)
+    pub fn add_layer_by_mask<U, M>(&self, layer_b: &Layer<U>, mask: &M, output: &mut Self)
     where
         T: Add<U, Output = T>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
     {
-        self.map3(layer_b, mask, output, |s_i, b_i, m_i| if m_i { s_i + b_i } else { s_i });
+        self.map2_masked(layer_b, mask, output, |s_i, b_i, m_i| if m_i { s_i + b_i } else { s_i });
+    }
+
+    /// [`Layer::add_layer_by_mask`] with assignment.
+    pub fn add_layer_by_mask_assign<U, M>(&mut self, layer_b: &Layer<U>, mask: &M)
+    where
+        T: Add<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.map2_masked_assign(layer_b, mask, |s_i, b_i, m_i| if m_i { s_i + b_i } else { s_i });
     }
     // endregion add_by_mask
 
@@ -857,19 +1301,37 @@ impl<T: Copy + Default> Layer<T> {
     pub fn sub_value<U>(&self, value: U, output: &mut Self)
     where
         T: Sub<U, Output = T>,
-        U: Copy,
+        U: Copy + Send + Sync,
     {
         self.map1_with(value, output, T::sub);
     }
 
+    /// [`Layer::sub_value`] with assignment.
+    pub fn sub_value_assign<U>(&mut self, value: U)
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Send + Sync,
+    {
+        self.map1_with_assign(value, T::sub);
+    }
+
     /// $O_i = S_i - B_i$
     pub fn sub_layer<U>(&self, layer_b: &Layer<U>, output: &mut Self)
     where
         T: Sub<U, Output = T>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::sub);
     }
+
+    /// [`Layer::sub_layer`] with assignment.
+    pub fn sub_layer_assign<U>(&mut self, layer_b: &Layer<U>)
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.map2_assign(layer_b, T::sub);
+    }
     // endregion sub
 
     // region sub_weighted
@@ -879,23 +1341,43 @@ impl<T: Copy + Default> Layer<T> {
     pub fn sub_value_weighted<U, W, X>(&self, weights: &Layer<W>, value: U, output: &mut Self)
     where
         T: Sub<X, Output = T>,
-        W: Copy + Default + Mul<U, Output = X>,
-        U: Copy,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
     {
         self.map2(weights, output, |s_i, w_i| s_i - w_i * value);
     }
 
+    /// [`Layer::sub_value_weighted`] with assignment.
+    pub fn sub_value_weighted_assign<U, W, X>(&mut self, weights: &Layer<W>, value: U)
+    where
+        T: Sub<X, Output = T>,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
+    {
+        self.map2_assign(weights, |s_i, w_i| s_i - w_i * value);
+    }
+
     /// $O_i = S_i - W_i B_i$
     ///
     /// `sub_field_term` in Tectonics.js.
     pub fn sub_layer_weighted<U, W, X>(&self, layer_b: &Layer<U>, weights: &Layer<W>, output: &mut Self)
     where
         T: Sub<X, Output = T>,
-        U: Copy + Default,
-        W: Copy + Default + Mul<U, Output = X>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
     {
         self.map3(layer_b, weights, output, |s_i, b_i, w_i| s_i - w_i * b_i);
     }
+
+    /// [`Layer::sub_layer_weighted`] with assignment.
+    pub fn sub_layer_weighted_assign<U, W, X>(&mut self, layer_b: &Layer<U>, weights: &Layer<W>)
+    where
+        T: Sub<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.map3_assign(layer_b, weights, |s_i, b_i, w_i| s_i - w_i * b_i);
+    }
     // endregion sub_weighted
 
     // region sub_by_mask
@@ -905,12 +1387,23 @@ impl<T: Copy + Default> Layer<T> {
     /// \end{cases}$
     ///
     /// `sub_scalar_term` in Tectonics.js.
-    pub fn sub_value_by_mask<U>(&self, value: U, mask: &Layer<bool>, output: &mut Self)
+    pub fn sub_value_by_mask<U, M>(&self, value: U, mask: &M, output: &mut Self)
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.map_masked(mask, output, |s_i, m_i| if m_i { s_i - value } else { s_i });
+    }
+
+    /// [`Layer::sub_value_by_mask`] with assignment.
+    pub fn sub_value_by_mask_assign<U, M>(&mut self, value: U, mask: &M)
     where
         T: Sub<U, Output = T>,
-        U: Copy,
+        U: Copy + Send + Sync,
+        M: Mask,
     {
-        self.map2(mask, output, |s_i, m_i| if m_i { s_i - value } else { s_i });
+        self.map_masked_assign(mask, |s_i, m_i| if m_i { s_i - value } else { s_i });
     }
 
     /// $O_i = \begin{cases}
@@ -919,12 +1412,23 @@ impl<T: Copy + Default> Layer<T> {
     /// \end{cases}$
     ///
     /// `sub_field_term` in Tectonics.js.
-    pub fn sub_layer_by_mask<U>(&self, layer_b: &Layer<U>, mask: &Layer<bool>, output: &mut Self)
+    pub fn sub_layer_by_mask<U, M>(&self, layer_b: &Layer<U>, mask: &M, output: &mut Self)
     where
         T: Sub<U, Output = T>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
     {
-        self.map3(layer_b, mask, output, |s_i, b_i, m_i| if m_i { s_i - b_i } else { s_i });
+        self.map2_masked(layer_b, mask, output, |s_i, b_i, m_i| if m_i { s_i - b_i } else { s_i });
+    }
+
+    /// [`Layer::sub_layer_by_mask`] with assignment.
+    pub fn sub_layer_by_mask_assign<U, M>(&mut self, layer_b: &Layer<U>, mask: &M)
+    where
+        T: Sub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.map2_masked_assign(layer_b, mask, |s_i, b_i, m_i| if m_i { s_i - b_i } else { s_i });
     }
     // endregion sub_by_mask
 
@@ -933,19 +1437,37 @@ impl<T: Copy + Default> Layer<T> {
     pub fn entrywise_add_value<U>(&self, value: U, output: &mut Self)
     where
         T: EntrywiseAdd<U, Output = T>,
-        U: Copy,
+        U: Copy + Send + Sync,
     {
         self.map1_with(value, output, T::entrywise_add);
     }
 
+    /// [`Layer::entrywise_add_value`] with assignment.
+    pub fn entrywise_add_value_assign<U>(&mut self, value: U)
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Send + Sync,
+    {
+        self.map1_with_assign(value, T::entrywise_add);
+    }
+
     /// $O_i = S_i \oplus B_i$
     pub fn entrywise_add_layer<U>(&self, layer_b: &Layer<U>, output: &mut Self)
     where
         T: EntrywiseAdd<U, Output = T>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::entrywise_add);
     }
+
+    /// [`Layer::entrywise_add_layer`] with assignment.
+    pub fn entrywise_add_layer_assign<U>(&mut self, layer_b: &Layer<U>)
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.map2_assign(layer_b, T::entrywise_add);
+    }
     // endregion entrywise_add
 
     // region entrywise_add_weighted
@@ -953,21 +1475,41 @@ impl<T: Copy + Default> Layer<T> {
     pub fn entrywise_add_value_weighted<U, W, X>(&self, value: U, weights: &Layer<W>, output: &mut Self)
     where
         T: EntrywiseAdd<X, Output = T>,
-        U: Copy,
-        W: Copy + Default + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
     {
         self.map2(weights, output, |s_i, w_i| s_i.entrywise_add(w_i * value));
     }
 
+    /// [`Layer::entrywise_add_value_weighted`] with assignment.
+    pub fn entrywise_add_value_weighted_assign<U, W, X>(&mut self, value: U, weights: &Layer<W>)
+    where
+        T: EntrywiseAdd<X, Output = T>,
+        U: Copy + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.map2_assign(weights, |s_i, w_i| s_i.entrywise_add(w_i * value));
+    }
+
     /// $O_i = S_i \oplus W_i B_i$
     pub fn entrywise_add_layer_weighted<U, W, X>(&self, layer_b: &Layer<U>, weights: &Layer<W>, output: &mut Self)
     where
         T: EntrywiseAdd<X, Output = T>,
-        U: Copy + Default,
-        W: Copy + Default + Mul<U, Output = X>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
     {
         self.map3(layer_b, weights, output, |s_i, b_i, w_i| s_i.entrywise_add(w_i * b_i));
     }
+
+    /// [`Layer::entrywise_add_layer_weighted`] with assignment.
+    pub fn entrywise_add_layer_weighted_assign<U, W, X>(&mut self, layer_b: &Layer<U>, weights: &Layer<W>)
+    where
+        T: EntrywiseAdd<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.map3_assign(layer_b, weights, |s_i, b_i, w_i| s_i.entrywise_add(w_i * b_i));
+    }
     // endregion entrywise_add_weighted
 
     // region entrywise_add_by_mask
@@ -975,24 +1517,46 @@ impl<T: Copy + Default> Layer<T> {
     ///     S_i \oplus v, & \text{if}   & M_i = \texttt{true} \\
     ///     S_i,          & \text{else} &                     \\
     /// \end{cases}$
-    pub fn entrywise_add_value_by_mask<U>(&self, value: U, mask: &Layer<bool>, output: &mut Self)
+    pub fn entrywise_add_value_by_mask<U, M>(&self, value: U, mask: &M, output: &mut Self)
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.map_masked(mask, output, |s_i, m_i| if m_i { s_i.entrywise_add(value) } else { s_i });
+    }
+
+    /// [`Layer::entrywise_add_value_by_mask`] with assignment.
+    pub fn entrywise_add_value_by_mask_assign<U, M>(&mut self, value: U, mask: &M)
     where
         T: EntrywiseAdd<U, Output = T>,
-        U: Copy,
+        U: Copy + Send + Sync,
+        M: Mask,
     {
-        self.map2(mask, output, |s_i, m_i| if m_i { s_i.entrywise_add(value) } else { s_i });
+        self.map_masked_assign(mask, |s_i, m_i| if m_i { s_i.entrywise_add(value) } else { s_i });
     }
 
     /// $O_i = \begin{cases}
     ///     S_i \oplus B_i, & \text{if}   & M_i = \texttt{true} \\
     ///     S_i,            & \text{else} &                     \\
     /// \end{cases}$
-    pub fn entrywise_add_layer_by_mask<U>(&self, layer_b: &Layer<U>, mask: &Layer<bool>, output: &mut Self)
+    pub fn entrywise_add_layer_by_mask<U, M>(&self, layer_b: &Layer<U>, mask: &M, output: &mut Self)
     where
         T: EntrywiseAdd<U, Output = T>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
     {
-        self.map3(layer_b, mask, output, |s_i, b_i, m_i| if m_i { s_i.entrywise_add(b_i) } else { s_i });
+        self.map2_masked(layer_b, mask, output, |s_i, b_i, m_i| if m_i { s_i.entrywise_add(b_i) } else { s_i });
+    }
+
+    /// [`Layer::entrywise_add_layer_by_mask`] with assignment.
+    pub fn entrywise_add_layer_by_mask_assign<U, M>(&mut self, layer_b: &Layer<U>, mask: &M)
+    where
+        T: EntrywiseAdd<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.map2_masked_assign(layer_b, mask, |s_i, b_i, m_i| if m_i { s_i.entrywise_add(b_i) } else { s_i });
     }
     // endregion entrywise_add_by_mask
 
@@ -1001,19 +1565,37 @@ impl<T: Copy + Default> Layer<T> {
     pub fn entrywise_sub_value<U>(&self, value: U, output: &mut Self)
     where
         T: EntrywiseSub<U, Output = T>,
-        U: Copy,
+        U: Copy + Send + Sync,
     {
         self.map1_with(value, output, T::entrywise_sub);
     }
 
+    /// [`Layer::entrywise_sub_value`] with assignment.
+    pub fn entrywise_sub_value_assign<U>(&mut self, value: U)
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Send + Sync,
+    {
+        self.map1_with_assign(value, T::entrywise_sub);
+    }
+
     /// $O_i = S_i \ominus B_i$
     pub fn entrywise_sub_layer<U>(&self, layer_b: &Layer<U>, output: &mut Self)
     where
         T: EntrywiseSub<U, Output = T>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
     {
         self.map2(layer_b, output, T::entrywise_sub);
     }
+
+    /// [`Layer::entrywise_sub_layer`] with assignment.
+    pub fn entrywise_sub_layer_assign<U>(&mut self, layer_b: &Layer<U>)
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+    {
+        self.map2_assign(layer_b, T::entrywise_sub);
+    }
     // endregion entrywise_sub
 
     // region entrywise_sub_weighted
@@ -1021,21 +1603,41 @@ impl<T: Copy + Default> Layer<T> {
     pub fn entrywise_sub_value_weighted<U, W, X>(&self, weights: &Layer<W>, value: U, output: &mut Self)
     where
         T: EntrywiseSub<X, Output = T>,
-        W: Copy + Default + Mul<U, Output = X>,
-        U: Copy,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
     {
         self.map2(weights, output, |s_i, w_i| s_i.entrywise_sub(w_i * value));
     }
 
+    /// [`Layer::entrywise_sub_value_weighted`] with assignment.
+    pub fn entrywise_sub_value_weighted_assign<U, W, X>(&mut self, weights: &Layer<W>, value: U)
+    where
+        T: EntrywiseSub<X, Output = T>,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+        U: Copy + Send + Sync,
+    {
+        self.map2_assign(weights, |s_i, w_i| s_i.entrywise_sub(w_i * value));
+    }
+
     /// $O_i = S_i \ominus W_i B_i$
     pub fn entrywise_sub_layer_weighted<U, W, X>(&self, layer_b: &Layer<U>, weights: &Layer<W>, output: &mut Self)
     where
         T: EntrywiseSub<X, Output = T>,
-        U: Copy + Default,
-        W: Copy + Default + Mul<U, Output = X>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
     {
         self.map3(layer_b, weights, output, |s_i, b_i, w_i| s_i.entrywise_sub(w_i * b_i));
     }
+
+    /// [`Layer::entrywise_sub_layer_weighted`] with assignment.
+    pub fn entrywise_sub_layer_weighted_assign<U, W, X>(&mut self, layer_b: &Layer<U>, weights: &Layer<W>)
+    where
+        T: EntrywiseSub<X, Output = T>,
+        U: Copy + Default + Send + Sync,
+        W: Copy + Default + Send + Sync + Mul<U, Output = X>,
+    {
+        self.map3_assign(layer_b, weights, |s_i, b_i, w_i| s_i.entrywise_sub(w_i * b_i));
+    }
     // endregion entrywise_sub_weighted
 
     // region entrywise_sub_by_mask
@@ -1043,24 +1645,46 @@ impl<T: Copy + Default> Layer<T> {
     ///     S_i \ominus v, & \text{if}   & M_i = \texttt{true} \\
     ///     S_i,           & \text{else} &                     \\
     /// \end{cases}$
-    pub fn entrywise_sub_value_by_mask<U>(&self, value: U, mask: &Layer<bool>, output: &mut Self)
+    pub fn entrywise_sub_value_by_mask<U, M>(&self, value: U, mask: &M, output: &mut Self)
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Send + Sync,
+        M: Mask,
+    {
+        self.map_masked(mask, output, |s_i, m_i| if m_i { s_i.entrywise_sub(value) } else { s_i });
+    }
+
+    /// [`Layer::entrywise_sub_value_by_mask`] with assignment.
+    pub fn entrywise_sub_value_by_mask_assign<U, M>(&mut self, value: U, mask: &M)
     where
         T: EntrywiseSub<U, Output = T>,
-        U: Copy,
+        U: Copy + Send + Sync,
+        M: Mask,
     {
-        self.map2(mask, output, |s_i, m_i| if m_i { s_i.entrywise_sub(value) } else { s_i });
+        self.map_masked_assign(mask, |s_i, m_i| if m_i { s_i.entrywise_sub(value) } else { s_i });
     }
 
     /// $O_i = \begin{cases}
     ///     S_i \ominus B_i, & \text{if}   & M_i = \texttt{true} \\
     ///     S_i,             & \text{else} &                     \\
     /// \end{cases}$
-    pub fn entrywise_sub_layer_by_mask<U>(&self, layer_b: &Layer<U>, mask: &Layer<bool>, output: &mut Self)
+    pub fn entrywise_sub_layer_by_mask<U, M>(&self, layer_b: &Layer<U>, mask: &M, output: &mut Self)
     where
         T: EntrywiseSub<U, Output = T>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
     {
-        self.map3(layer_b, mask, output, |s_i, b_i, m_i| if m_i { s_i.entrywise_sub(b_i) } else { s_i });
+        self.map2_masked(layer_b, mask, output, |s_i, b_i, m_i| if m_i { s_i.entrywise_sub(b_i) } else { s_i });
+    }
+
+    /// [`Layer::entrywise_sub_layer_by_mask`] with assignment.
+    pub fn entrywise_sub_layer_by_mask_assign<U, M>(&mut self, layer_b: &Layer<U>, mask: &M)
+    where
+        T: EntrywiseSub<U, Output = T>,
+        U: Copy + Default + Send + Sync,
+        M: Mask,
+    {
+        self.map2_masked_assign(layer_b, mask, |s_i, b_i, m_i| if m_i { s_i.entrywise_sub(b_i) } else { s_i });
     }
     // endregion entrywise_sub_by_mask
 
@@ -1069,7 +1693,7 @@ impl<T: Copy + Default> Layer<T> {
     pub fn to_magnitudes<U>(&self, output: &mut Layer<U>)
     where
         T: Magnitude<Output = U>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
     {
         self.map1(output, T::magnitude);
     }
@@ -1082,6 +1706,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map1(output, T::inv);
     }
 
+    /// [`Layer::inv`] with assignment.
+    pub fn inv_assign(&mut self)
+    where
+        T: Inv,
+    {
+        self.map1_assign(T::inv);
+    }
+
     /// $O_i = -S_i$
     pub fn neg(&self, output: &mut Self)
     where
@@ -1090,6 +1722,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map1(output, T::neg);
     }
 
+    /// [`Layer::neg`] with assignment.
+    pub fn neg_assign(&mut self)
+    where
+        T: Neg<Output = T>,
+    {
+        self.map1_assign(T::neg);
+    }
+
     /// $O_i = \sqrt{S_i}$
     pub fn sqrt(&self, output: &mut Self)
     where
@@ -1098,11 +1738,19 @@ impl<T: Copy + Default> Layer<T> {
         self.map1(output, T::square_root);
     }
 
+    /// [`Layer::sqrt`] with assignment.
+    pub fn sqrt_assign(&mut self)
+    where
+        T: SquareRoot,
+    {
+        self.map1_assign(T::square_root);
+    }
+
     /// $O_i = e^{S_i}$
     pub fn e_x<U>(&self, output: &mut Layer<U>)
     where
         T: EX<Output = U>,
-        U: Copy + Default,
+        U: Copy + Default + Send + Sync,
     {
         self.map1(output, T::e_x);
     }
@@ -1115,6 +1763,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map1(output, T::entrywise_inv);
     }
 
+    /// [`Layer::entrywise_inv`] with assignment.
+    pub fn entrywise_inv_assign(&mut self)
+    where
+        T: EntrywiseInv<Output = T>,
+    {
+        self.map1_assign(T::entrywise_inv);
+    }
+
     /// $O_{i_j} = \sqrt{S_{i_j}}$
     pub fn entrywise_sqrt(&self, output: &mut Self)
     where
@@ -1123,6 +1779,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map1(output, T::entrywise_sqrt);
     }
 
+    /// [`Layer::entrywise_sqrt`] with assignment.
+    pub fn entrywise_sqrt_assign(&mut self)
+    where
+        T: EntrywiseSqrt<Output = T>,
+    {
+        self.map1_assign(T::entrywise_sqrt);
+    }
+
     /// $O_{i_j} = e^{S_{i_j}}$
     pub fn entrywise_e_x(&self, output: &mut Self)
     where
@@ -1130,6 +1794,14 @@ impl<T: Copy + Default> Layer<T> {
     {
         self.map1(output, T::entrywise_e_x);
     }
+
+    /// [`Layer::entrywise_e_x`] with assignment.
+    pub fn entrywise_e_x_assign(&mut self)
+    where
+        T: EntrywiseEX<Output = T>,
+    {
+        self.map1_assign(T::entrywise_e_x);
+    }
     // endregion Misc
 
     // TODO:
@@ -1147,16 +1819,38 @@ impl<T: Copy + Default> Layer<T> {
     ///     B_i, & \text{if}   & M_i = \texttt{true} \\
     ///     S_i, & \text{else} &                     \\
     /// \end{cases}$
-    pub fn copy_into_selection(&self, layer_b: &Self, mask: &Layer<bool>, output: &mut Self) {
-        self.map3(layer_b, mask, output, |s_i, f_i, m_i| if m_i { f_i } else { s_i });
+    pub fn copy_into_selection<M>(&self, layer_b: &Self, mask: &M, output: &mut Self)
+    where
+        M: Mask,
+    {
+        self.map2_masked(layer_b, mask, output, |s_i, f_i, m_i| if m_i { f_i } else { s_i });
+    }
+
+    /// [`Layer::copy_into_selection`] with assignment.
+    pub fn copy_into_selection_assign<M>(&mut self, layer_b: &Self, mask: &M)
+    where
+        M: Mask,
+    {
+        self.map2_masked_assign(layer_b, mask, |s_i, f_i, m_i| if m_i { f_i } else { s_i });
     }
 
     /// $O_i = \begin{cases}
     ///     v,   & \text{if}   & M_i = \texttt{true} \\
     ///     S_i, & \text{else} &                     \\
     /// \end{cases}$
-    pub fn fill_into_selection(&self, value: T, mask: &Layer<bool>, output: &mut Self) {
-        self.map2(mask, output, |s_i, m_i| if m_i { value } else { s_i });
+    pub fn fill_into_selection<M>(&self, value: T, mask: &M, output: &mut Self)
+    where
+        M: Mask,
+    {
+        self.map_masked(mask, output, |s_i, m_i| if m_i { value } else { s_i });
+    }
+
+    /// [`Layer::fill_into_selection`] with assignment.
+    pub fn fill_into_selection_assign<M>(&mut self, value: T, mask: &M)
+    where
+        M: Mask,
+    {
+        self.map_masked_assign(mask, |s_i, m_i| if m_i { value } else { s_i });
     }
 
     // TODO:
@@ -1174,6 +1868,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map1(output, |s_i| interpolations::mix(s_i, v, w))
     }
 
+    /// [`Layer::mix_value_value`] with assignment.
+    pub fn mix_value_value_assign(&mut self, v: T, w: T)
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.map1_assign(|s_i| interpolations::mix(s_i, v, w))
+    }
+
     // $O_i = S_i (C_i - v) + v$
     pub fn mix_value_layer(&self, v: T, layer_c: &Self, output: &mut Self)
     where
@@ -1182,6 +1884,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map2(layer_c, output, |s_i, c_i| interpolations::mix(s_i, v, c_i))
     }
 
+    /// [`Layer::mix_value_layer`] with assignment.
+    pub fn mix_value_layer_assign(&mut self, v: T, layer_c: &Self)
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.map2_assign(layer_c, |s_i, c_i| interpolations::mix(s_i, v, c_i))
+    }
+
     // $O_i = S_i (w - B_i) + B_i$
     pub fn mix_layer_value(&self, layer_b: &Self, w: T, output: &mut Self)
     where
@@ -1190,6 +1900,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map2(layer_b, output, |s_i, b_i| interpolations::mix(s_i, b_i, w))
     }
 
+    /// [`Layer::mix_layer_value`] with assignment.
+    pub fn mix_layer_value_assign(&mut self, layer_b: &Self, w: T)
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.map2_assign(layer_b, |s_i, b_i| interpolations::mix(s_i, b_i, w))
+    }
+
     // $O_i = S_i (C_i - B_i) + B_i$
     pub fn mix_layer_layer(&self, layer_b: &Self, layer_c: &Self, output: &mut Self)
     where
@@ -1197,6 +1915,14 @@ impl<T: Copy + Default> Layer<T> {
     {
         self.map3(layer_b, layer_c, output, |s_i, b_i, c_i| interpolations::mix(s_i, b_i, c_i))
     }
+
+    /// [`Layer::mix_layer_layer`] with assignment.
+    pub fn mix_layer_layer_assign(&mut self, layer_b: &Self, layer_c: &Self)
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.map3_assign(layer_b, layer_c, |s_i, b_i, c_i| interpolations::mix(s_i, b_i, c_i))
+    }
     // endregion mix
 
     // region clamp
@@ -1212,6 +1938,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map1(output, |s_i| interpolations::clamp(s_i, v, w))
     }
 
+    /// [`Layer::clamp_value_value`] with assignment.
+    pub fn clamp_value_value_assign(&mut self, v: T, w: T)
+    where
+        T: PartialOrd,
+    {
+        self.map1_assign(|s_i| interpolations::clamp(s_i, v, w))
+    }
+
     /// O_i = \begin{cases}
     ///     v,   & \text{if}   & S_i < v   \\
     ///     C_i, & \text{if}   & S_i > C_i \\
@@ -1224,6 +1958,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map2(layer_c, output, |s_i, c_i| interpolations::clamp(s_i, v, c_i))
     }
 
+    /// [`Layer::clamp_value_layer`] with assignment.
+    pub fn clamp_value_layer_assign(&mut self, v: T, layer_c: &Self)
+    where
+        T: PartialOrd,
+    {
+        self.map2_assign(layer_c, |s_i, c_i| interpolations::clamp(s_i, v, c_i))
+    }
+
     /// O_i = \begin{cases}
     ///     B_i, & \text{if}   & S_i < B_i \\
     ///     w,   & \text{if}   & S_i > w   \\
@@ -1236,6 +1978,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map2(layer_b, output, |s_i, b_i| interpolations::clamp(s_i, b_i, w))
     }
 
+    /// [`Layer::clamp_layer_value`] with assignment.
+    pub fn clamp_layer_value_assign(&mut self, layer_b: &Self, w: T)
+    where
+        T: PartialOrd,
+    {
+        self.map2_assign(layer_b, |s_i, b_i| interpolations::clamp(s_i, b_i, w))
+    }
+
     /// O_i = \begin{cases}
     ///     B_i, & \text{if}   & S_i < B_i \\
     ///     C_i, & \text{if}   & S_i > C_i \\
@@ -1247,6 +1997,14 @@ impl<T: Copy + Default> Layer<T> {
     {
         self.map3(layer_b, layer_c, output, |s_i, b_i, c_i| interpolations::clamp(s_i, b_i, c_i))
     }
+
+    /// [`Layer::clamp_layer_layer`] with assignment.
+    pub fn clamp_layer_layer_assign(&mut self, layer_b: &Self, layer_c: &Self)
+    where
+        T: PartialOrd,
+    {
+        self.map3_assign(layer_b, layer_c, |s_i, b_i, c_i| interpolations::clamp(s_i, b_i, c_i))
+    }
     // endregion clamp
 
     // region step
@@ -1261,6 +2019,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map1_with(value, output, interpolations::step)
     }
 
+    /// [`Layer::step_value`] with assignment.
+    pub fn step_value_assign(&mut self, value: T)
+    where
+        T: PartialOrd + One,
+    {
+        self.map1_with_assign(value, interpolations::step)
+    }
+
     /// $O_i = \begin{cases}
     ///     1, & \text{if}   & S_i > B_i \\
     ///     0, & \text{else} &           \\
@@ -1271,6 +2037,14 @@ impl<T: Copy + Default> Layer<T> {
     {
         self.map2(layer_b, output, interpolations::step)
     }
+
+    /// [`Layer::step_layer`] with assignment.
+    pub fn step_layer_assign(&mut self, layer_b: &Self)
+    where
+        T: PartialOrd + One,
+    {
+        self.map2_assign(layer_b, interpolations::step)
+    }
     // endregion step
 
     // region linearstep
@@ -1288,6 +2062,16 @@ impl<T: Copy + Default> Layer<T> {
         self.map1(output, |s_i| interpolations::linearstep_inv(s_i, value_a, inv_diff));
     }
 
+    /// [`Layer::linearstep_value_value`] with assignment.
+    pub fn linearstep_value_value_assign(&mut self, value_a: T, value_b: T)
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        let inv_diff = (value_b - value_a).inv();
+
+        self.map1_assign(|s_i| interpolations::linearstep_inv(s_i, value_a, inv_diff));
+    }
+
     /// $O_i = \begin{cases}
     ///     0,                     & \text{if}   & \frac{x - a}{C_i - a} < 0 \\
     ///     1,                     & \text{if}   & \frac{x - a}{C_i - a} > 1 \\
@@ -1300,6 +2084,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map2(layer_c, output, |s_i, c_i| interpolations::linearstep(s_i, value_a, c_i));
     }
 
+    /// [`Layer::linearstep_value_layer`] with assignment.
+    pub fn linearstep_value_layer_assign(&mut self, value_a: T, layer_c: &Self)
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.map2_assign(layer_c, |s_i, c_i| interpolations::linearstep(s_i, value_a, c_i));
+    }
+
     /// $O_i = \begin{cases}
     ///     0,                       & \text{if}   & \frac{x - B_i}{b - B_i} < 0 \\
     ///     1,                       & \text{if}   & \frac{x - B_i}{b - B_i} > 1 \\
@@ -1312,6 +2104,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map2(layer_b, output, |s_i, b_i| interpolations::linearstep(s_i, b_i, value_b));
     }
 
+    /// [`Layer::linearstep_layer_value`] with assignment.
+    pub fn linearstep_layer_value_assign(&mut self, layer_b: &Self, value_b: T)
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.map2_assign(layer_b, |s_i, b_i| interpolations::linearstep(s_i, b_i, value_b));
+    }
+
     /// $O_i = \begin{cases}
     ///     0,                         & \text{if}   & \frac{x - B_i}{C_i - B_i} < 0 \\
     ///     1,                         & \text{if}   & \frac{x - B_i}{C_i - B_i} > 1 \\
@@ -1323,78 +2123,145 @@ impl<T: Copy + Default> Layer<T> {
     {
         self.map3(layer_b, layer_c, output, |s_i, b_i, c_i| interpolations::linearstep(s_i, b_i, c_i));
     }
+
+    /// [`Layer::linearstep_layer_layer`] with assignment.
+    pub fn linearstep_layer_layer_assign(&mut self, layer_b: &Self, layer_c: &Self)
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Inv + One,
+    {
+        self.map3_assign(layer_b, layer_c, |s_i, b_i, c_i| interpolations::linearstep(s_i, b_i, c_i));
+    }
     // endregion linearstep
 
     // region smoothstep
     /// $O_i = l^2 (3 - 2l)$, where $l = \text{linearstep}(S_i, a, b)$
     pub fn smoothstep_value_value(&self, value_a: T, value_b: T, output: &mut Self)
     where
-        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + Inv + One,
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
     {
         let inv_diff = (value_b - value_a).inv();
 
         self.map1(output, |s_i| interpolations::smoothstep_inv(s_i, value_a, inv_diff));
     }
 
+    /// [`Layer::smoothstep_value_value`] with assignment.
+    pub fn smoothstep_value_value_assign(&mut self, value_a: T, value_b: T)
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        let inv_diff = (value_b - value_a).inv();
+
+        self.map1_assign(|s_i| interpolations::smoothstep_inv(s_i, value_a, inv_diff));
+    }
+
     /// $O_i = l^2 (3 - 2l)$, where $l = \text{linearstep}(S_i, a, C_i)$
     pub fn smoothstep_value_layer(&self, value_a: T, layer_c: &Self, output: &mut Self)
     where
-        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + Inv + One,
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
     {
         self.map2(layer_c, output, |s_i, c_i| interpolations::smoothstep(s_i, value_a, c_i));
     }
 
+    /// [`Layer::smoothstep_value_layer`] with assignment.
+    pub fn smoothstep_value_layer_assign(&mut self, value_a: T, layer_c: &Self)
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.map2_assign(layer_c, |s_i, c_i| interpolations::smoothstep(s_i, value_a, c_i));
+    }
+
     /// $O_i = l^2 (3 - 2l)$, where $l = \text{linearstep}(S_i, B_i, b)$
     pub fn smoothstep_layer_value(&self, layer_b: &Self, value_b: T, output: &mut Self)
     where
-        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + Inv + One,
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
     {
         self.map2(layer_b, output, |s_i, b_i| interpolations::smoothstep(s_i, b_i, value_b));
     }
 
+    /// [`Layer::smoothstep_layer_value`] with assignment.
+    pub fn smoothstep_layer_value_assign(&mut self, layer_b: &Self, value_b: T)
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.map2_assign(layer_b, |s_i, b_i| interpolations::smoothstep(s_i, b_i, value_b));
+    }
+
     /// $O_i = l^2 (3 - 2l)$, where $l = \text{linearstep}(S_i, B_i, C_i)$
     pub fn smoothstep_layer_layer(&self, layer_b: &Self, layer_c: &Self, output: &mut Self)
     where
-        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + Inv + One,
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
     {
         self.map3(layer_b, layer_c, output, |s_i, b_i, c_i| interpolations::smoothstep(s_i, b_i, c_i));
     }
+
+    /// [`Layer::smoothstep_layer_layer`] with assignment.
+    pub fn smoothstep_layer_layer_assign(&mut self, layer_b: &Self, layer_c: &Self)
+    where
+        T: PartialOrd + Mul<Output = T> + Sub<Output = T> + Add<Output = T> + Inv + One,
+    {
+        self.map3_assign(layer_b, layer_c, |s_i, b_i, c_i| interpolations::smoothstep(s_i, b_i, c_i));
+    }
     // endregion smoothstep
 
     // region smoothstep2
     /// $\frac{2}{e^{-v S_i} + 1} - 1$
     pub fn smoothstep2_value(&self, value: T, output: &mut Self)
     where
-        T: Neg<Output = T> + Mul<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + EX<Output = T> + Inv,
+        T: Neg<Output = T> + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + EX<Output = T> + Inv + One,
     {
         let k_neg = -value;
 
         self.map1_with(k_neg, output, interpolations::smoothstep2_neg);
     }
 
+    /// [`Layer::smoothstep2_value`] with assignment.
+    pub fn smoothstep2_value_assign(&mut self, value: T)
+    where
+        T: Neg<Output = T> + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + EX<Output = T> + Inv + One,
+    {
+        let k_neg = -value;
+
+        self.map1_with_assign(k_neg, interpolations::smoothstep2_neg);
+    }
+
     /// $\frac{2}{e^{-B_i S_i} + 1} - 1$
     pub fn smoothstep2_layer(&self, layer_b: &Self, output: &mut Self)
     where
-        T: Neg<Output = T> + Mul<Output = T> + Mul<f32, Output = T> + Add<f32, Output = T> + EX<Output = T> + Inv,
+        T: Neg<Output = T> + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + EX<Output = T> + Inv + One,
     {
         self.map2(layer_b, output, interpolations::smoothstep2);
     }
+
+    /// [`Layer::smoothstep2_layer`] with assignment.
+    pub fn smoothstep2_layer_assign(&mut self, layer_b: &Self)
+    where
+        T: Neg<Output = T> + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + EX<Output = T> + Inv + One,
+    {
+        self.map2_assign(layer_b, interpolations::smoothstep2);
+    }
     // endregion smoothstep2
 
     // region lerp
     /// Linear piecewise interpolation by control points.
-    pub fn lerp(&self, xs: &[T], ys: &[T], output: &mut Self, temp1: &mut Self, temp2: &mut Self)
+    ///
+    /// Takes temporary layers from given pool.
+    pub fn lerp(&self, xs: &[T], ys: &[T], output: &mut Self, pool: &LayerPool)
     where
-        T: PartialOrd + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Inv + One,
+        T: PartialOrd + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Inv + One + 'static,
     {
+        debug_assert_eq!(xs.len(), ys.len(), "control points length mismatch");
+
+        let mut temp1 = pool.get(self.len());
+        let mut temp2 = pool.get(self.len());
+
         temp2.fill(ys[0]);
 
         for (i, &x_i) in xs[1..].iter().enumerate() {
-            self.linearstep_value_value(xs[i], x_i, temp1);
+            self.linearstep_value_value(xs[i], x_i, &mut temp1);
 
-            unsafe {
-                temp1.mix_layer_value(temp2, ys[i + 1], temp2.to_mut());
-            }
+            temp1.mix_layer_value_assign(&temp2, ys[i + 1]);
+
+            std::mem::swap(&mut *temp1, &mut *temp2);
         }
 
         temp2.copy_into(output)
@@ -1411,6 +2278,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map2(layer_b, output, T::bitor)
     }
 
+    /// [`Layer::union`] with assignment.
+    pub fn union_assign(&mut self, layer_b: &Self)
+    where
+        T: BitOr<Output = T>,
+    {
+        self.map2_assign(layer_b, T::bitor)
+    }
+
     /// $O_i = S_i \land B_i$
     pub fn intersection(&self, layer_b: &Self, output: &mut Self)
     where
@@ -1419,6 +2294,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map2(layer_b, output, T::bitand)
     }
 
+    /// [`Layer::intersection`] with assignment.
+    pub fn intersection_assign(&mut self, layer_b: &Self)
+    where
+        T: BitAnd<Output = T>,
+    {
+        self.map2_assign(layer_b, T::bitand)
+    }
+
     /// $O_i = S_i \land \overline{B_i}$
     pub fn difference(&self, layer_b: &Self, output: &mut Self)
     where
@@ -1427,6 +2310,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map2(layer_b, output, |s_i, b_i| s_i & !b_i)
     }
 
+    /// [`Layer::difference`] with assignment.
+    pub fn difference_assign(&mut self, layer_b: &Self)
+    where
+        T: BitAnd<Output = T> + Not<Output = T>,
+    {
+        self.map2_assign(layer_b, |s_i, b_i| s_i & !b_i)
+    }
+
     /// $O_i = \overline{S_i}$
     pub fn not(&self, output: &mut Self)
     where
@@ -1435,6 +2326,14 @@ impl<T: Copy + Default> Layer<T> {
         self.map1(output, T::not);
     }
 
+    /// [`Layer::not`] with assignment.
+    pub fn not_assign(&mut self)
+    where
+        T: Not<Output = T>,
+    {
+        self.map1_assign(T::not);
+    }
+
     // TODO:
     //  -   `dilation` (requires grid).
     //  -   `erosion` (requires grid).
@@ -0,0 +1,298 @@
+use crate::*;
+use std::ops::*;
+
+/// A layer of booleans used to select items of other layers.
+///
+/// Implemented by both the regular [`Layer<bool>`] and the bit-packed [`MaskLayer`], so raster operations like
+/// [`Layer::copy_into_selection`] and `*_by_mask` arithmetic accept either of them.
+pub trait Mask: Sync {
+    /// Returns number of items in a mask.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if a mask has no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// $M_i$
+    fn is_set(&self, i: usize) -> bool;
+
+    /// Iterates over mask items in a range.
+    fn iter_range(&self, range: Range<usize>) -> impl Iterator<Item = bool> + '_;
+}
+
+impl Mask for Layer<bool> {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn is_set(&self, i: usize) -> bool {
+        self[i]
+    }
+
+    fn iter_range(&self, range: Range<usize>) -> impl Iterator<Item = bool> + '_ {
+        self[range].iter().copied()
+    }
+}
+
+/// A bit-packed layer of booleans.
+///
+/// Stores 64 items per `u64` word, so it takes 8 times less memory than a [`Layer<bool>`]. Morphology operations work on
+/// whole words, and raster operations read bits in place through the [`Mask`] trait, without unpacking a mask.
+///
+/// Bits past the end of a layer in its last word are always zero.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaskLayer {
+    words: Box<[u64]>,
+    length: usize,
+}
+
+impl Mask for MaskLayer {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn is_set(&self, i: usize) -> bool {
+        self.get(i)
+    }
+
+    fn iter_range(&self, range: Range<usize>) -> impl Iterator<Item = bool> + '_ {
+        debug_assert!(range.end <= self.length, "mask range out of bounds");
+
+        range.map(move |i| self.words[i / 64] >> (i % 64) & 1 != 0)
+    }
+}
+
+impl From<&Layer<bool>> for MaskLayer {
+    fn from(layer: &Layer<bool>) -> Self {
+        let mut new = Self::new(layer.len());
+
+        new.copy_from_layer(layer);
+
+        new
+    }
+}
+
+impl From<&MaskLayer> for Layer<bool> {
+    fn from(layer: &MaskLayer) -> Self {
+        let mut new = Layer::new(layer.len());
+
+        layer.copy_into_layer(&mut new);
+
+        new
+    }
+}
+
+impl std::iter::FromIterator<bool> for MaskLayer {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut words = Vec::new();
+        let mut length: usize = 0;
+
+        for item in iter {
+            if length.is_multiple_of(64) {
+                words.push(0);
+            }
+
+            if item {
+                words[length / 64] |= 1 << (length % 64);
+            }
+
+            length += 1;
+        }
+
+        Self {
+            words: words.into_boxed_slice(),
+            length,
+        }
+    }
+}
+
+impl MaskLayer {
+    // region Core functionality
+    /// Creates new layer of specified length with all items unset.
+    pub fn new(length: usize) -> Self {
+        Self {
+            words: vec![0; length.div_ceil(64)].into_boxed_slice(),
+            length,
+        }
+    }
+
+    /// Returns number of items in a layer.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if a layer has no items.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns packed words of a layer; $i$-th item is stored in the $i \bmod 64$ bit of the $\lfloor i / 64 \rfloor$ word.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// $S_i$
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.length, "mask index out of bounds");
+
+        self.words[i / 64] >> (i % 64) & 1 != 0
+    }
+
+    /// $S_i = v$
+    pub fn set(&mut self, i: usize, value: bool) {
+        assert!(i < self.length, "mask index out of bounds");
+
+        if value {
+            self.words[i / 64] |= 1 << (i % 64);
+        } else {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    /// Iterates over items of a layer.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        self.iter_range(0..self.length)
+    }
+
+    /// Fills a layer with specified value.
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(if value { !0 } else { 0 });
+        self.clear_tail();
+    }
+
+    /// Copies items from this layer to the output.
+    pub fn copy_into(&self, output: &mut Self) {
+        debug_assert_eq!(self.length, output.length, "output layer length mismatch");
+
+        output.words.copy_from_slice(&self.words);
+    }
+
+    /// Packs items of a regular boolean layer into this layer.
+    pub fn copy_from_layer(&mut self, layer: &Layer<bool>) {
+        debug_assert_eq!(self.length, layer.len(), "source layer length mismatch");
+
+        kernels::map_indices(&mut self.words, |k| {
+            layer[k * 64..usize::min(k * 64 + 64, layer.len())]
+                .iter()
+                .enumerate()
+                .fold(0, |word, (j, &l_j)| word | (l_j as u64) << j)
+        });
+    }
+
+    /// Unpacks items of this layer into a regular boolean layer.
+    pub fn copy_into_layer(&self, output: &mut Layer<bool>) {
+        debug_assert_eq!(self.length, output.len(), "output layer length mismatch");
+
+        kernels::map_indices(output, |i| self.get(i));
+    }
+
+    /// Zeroes bits past the end of a layer.
+    fn clear_tail(&mut self) {
+        if !self.length.is_multiple_of(64) {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << (self.length % 64)) - 1;
+            }
+        }
+    }
+    // endregion Core functionality
+
+    // region Statistics
+    /// $\sum_i S_i$
+    ///
+    /// Counts set items.
+    pub fn count_ones(&self) -> usize {
+        let words = &self.words;
+
+        kernels::reduce(words.len(), 0, |range| words[range].iter().map(|w| w.count_ones() as usize).sum(), usize::add)
+    }
+
+    /// $\exists i : S_i$
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+
+    /// $\forall i : S_i$
+    pub fn all(&self) -> bool {
+        self.count_ones() == self.length
+    }
+    // endregion Statistics
+
+    // region Morphology
+    /// $O_i = S_i \lor B_i$
+    pub fn union(&self, layer_b: &Self, output: &mut Self) {
+        debug_assert_eq!(self.length, layer_b.length, "second operand layer length mismatch");
+        debug_assert_eq!(self.length, output.length, "output layer length mismatch");
+
+        kernels::map2(&self.words, &layer_b.words, &mut output.words, u64::bitor);
+    }
+
+    /// [`MaskLayer::union`] with assignment.
+    pub fn union_assign(&mut self, layer_b: &Self) {
+        debug_assert_eq!(self.length, layer_b.length, "second operand layer length mismatch");
+
+        kernels::map2_assign(&mut self.words, &layer_b.words, u64::bitor);
+    }
+
+    /// $O_i = S_i \land B_i$
+    pub fn intersection(&self, layer_b: &Self, output: &mut Self) {
+        debug_assert_eq!(self.length, layer_b.length, "second operand layer length mismatch");
+        debug_assert_eq!(self.length, output.length, "output layer length mismatch");
+
+        kernels::map2(&self.words, &layer_b.words, &mut output.words, u64::bitand);
+    }
+
+    /// [`MaskLayer::intersection`] with assignment.
+    pub fn intersection_assign(&mut self, layer_b: &Self) {
+        debug_assert_eq!(self.length, layer_b.length, "second operand layer length mismatch");
+
+        kernels::map2_assign(&mut self.words, &layer_b.words, u64::bitand);
+    }
+
+    /// $O_i = S_i \land \overline{B_i}$
+    pub fn difference(&self, layer_b: &Self, output: &mut Self) {
+        debug_assert_eq!(self.length, layer_b.length, "second operand layer length mismatch");
+        debug_assert_eq!(self.length, output.length, "output layer length mismatch");
+
+        kernels::map2(&self.words, &layer_b.words, &mut output.words, |s_k, b_k| s_k & !b_k);
+    }
+
+    /// [`MaskLayer::difference`] with assignment.
+    pub fn difference_assign(&mut self, layer_b: &Self) {
+        debug_assert_eq!(self.length, layer_b.length, "second operand layer length mismatch");
+
+        kernels::map2_assign(&mut self.words, &layer_b.words, |s_k, b_k| s_k & !b_k);
+    }
+
+    /// $O_i = S_i \oplus B_i$
+    pub fn xor(&self, layer_b: &Self, output: &mut Self) {
+        debug_assert_eq!(self.length, layer_b.length, "second operand layer length mismatch");
+        debug_assert_eq!(self.length, output.length, "output layer length mismatch");
+
+        kernels::map2(&self.words, &layer_b.words, &mut output.words, u64::bitxor);
+    }
+
+    /// [`MaskLayer::xor`] with assignment.
+    pub fn xor_assign(&mut self, layer_b: &Self) {
+        debug_assert_eq!(self.length, layer_b.length, "second operand layer length mismatch");
+
+        kernels::map2_assign(&mut self.words, &layer_b.words, u64::bitxor);
+    }
+
+    /// $O_i = \overline{S_i}$
+    pub fn not(&self, output: &mut Self) {
+        debug_assert_eq!(self.length, output.length, "output layer length mismatch");
+
+        kernels::map1(&self.words, &mut output.words, u64::not);
+
+        output.clear_tail();
+    }
+
+    /// [`MaskLayer::not`] with assignment.
+    pub fn not_assign(&mut self) {
+        kernels::map1_assign(&mut self.words, u64::not);
+
+        self.clear_tail();
+    }
+    // endregion Morphology
+}
@@ -0,0 +1,225 @@
+use crate::*;
+
+/// # Histogram matching
+///
+/// Remaps a scalar field so its cumulative distribution matches a target one, e.g., to give generated terrain an Earth-like
+/// hypsometric curve.
+///
+/// Every item gets a cumulative fraction $F_i \in (0, 1)$: the sum of weights of smaller items plus a half of the weight of
+/// items equal to $S_i$, divided by the sum of all weights. Equal items get equal fractions, and larger items never get
+/// smaller ones, so the remapping is rank-stable. Then $F_i$ is mapped through a target curve:
+///
+/// -   `*_to_curve` — control points: $x_k$ are ascending cumulative fractions, and $y_k$ are values of a field at them, so
+///     $O_i = \operatorname{lerp}(F_i, x, y)$, evaluated with [`Layer::lerp`].
+/// -   `*_to_layer` — another layer, counting its items equally: $O_i$ is the value at the $F_i$ fraction of its sorted
+///     items, linearly interpolated between them.
+///
+/// `*_weighted` versions weigh items by $W_i$, e.g., by vertex areas. `*_by_mask` versions only rank and remap items with
+/// set $M_i$, and copy other items as is. NaN items are never ranked, and are copied as is too.
+///
+/// If weights of ranked items don’t sum to a positive number (e.g., if all items are masked out, NaN, or have zero weights),
+/// there are no fractions to match, so operations fail with [`LayerError::InvalidWeights`] and leave the output as is.
+impl Layer<f32> {
+    // region match_histogram_to_curve
+    /// $O_i = \operatorname{lerp}(F_i, x, y)$
+    ///
+    /// Fails with [`LayerError::LengthMismatch`] if control points have different numbers of $x$ and $y$ values, or with
+    /// [`LayerError::OutOfBounds`] if there are none.
+    pub fn match_histogram_to_curve(&self, xs: &[f32], ys: &[f32], output: &mut Self, pool: &LayerPool) -> Result<(), LayerError> {
+        self.match_histogram_to_curve_where(xs, ys, |_| 1.0, |_| true, output, pool)
+    }
+
+    /// [`Layer::match_histogram_to_curve`] with weights.
+    pub fn match_histogram_to_curve_weighted(&self, xs: &[f32], ys: &[f32], weights: &Self, output: &mut Self, pool: &LayerPool) -> Result<(), LayerError> {
+        debug_assert_eq!(self.len(), weights.len(), "weights layer length mismatch");
+
+        self.match_histogram_to_curve_where(xs, ys, |i| weights[i], |_| true, output, pool)
+    }
+
+    /// [`Layer::match_histogram_to_curve`] by mask.
+    pub fn match_histogram_to_curve_by_mask<M>(&self, xs: &[f32], ys: &[f32], mask: &M, output: &mut Self, pool: &LayerPool) -> Result<(), LayerError>
+    where
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        self.match_histogram_to_curve_where(xs, ys, |_| 1.0, |i| mask.is_set(i), output, pool)
+    }
+
+    /// [`Layer::match_histogram_to_curve`] with weights, by mask.
+    pub fn match_histogram_to_curve_weighted_by_mask<M>(
+        &self,
+        xs: &[f32],
+        ys: &[f32],
+        weights: &Self,
+        mask: &M,
+        output: &mut Self,
+        pool: &LayerPool,
+    ) -> Result<(), LayerError>
+    where
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), weights.len(), "weights layer length mismatch");
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        self.match_histogram_to_curve_where(xs, ys, |i| weights[i], |i| mask.is_set(i), output, pool)
+    }
+    // endregion match_histogram_to_curve
+
+    // region match_histogram_to_layer
+    /// $O_i = \operatorname{quantile}(T, F_i)$
+    ///
+    /// Fails with [`LayerError::OutOfBounds`] if the target layer is empty, or has only NaN items.
+    pub fn match_histogram_to_layer(&self, target: &Self, output: &mut Self, pool: &LayerPool) -> Result<(), LayerError> {
+        self.match_histogram_to_layer_where(target, |_| 1.0, |_| true, output, pool)
+    }
+
+    /// [`Layer::match_histogram_to_layer`] with weights of this layer.
+    pub fn match_histogram_to_layer_weighted(&self, target: &Self, weights: &Self, output: &mut Self, pool: &LayerPool) -> Result<(), LayerError> {
+        debug_assert_eq!(self.len(), weights.len(), "weights layer length mismatch");
+
+        self.match_histogram_to_layer_where(target, |i| weights[i], |_| true, output, pool)
+    }
+
+    /// [`Layer::match_histogram_to_layer`] by mask.
+    pub fn match_histogram_to_layer_by_mask<M>(&self, target: &Self, mask: &M, output: &mut Self, pool: &LayerPool) -> Result<(), LayerError>
+    where
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        self.match_histogram_to_layer_where(target, |_| 1.0, |i| mask.is_set(i), output, pool)
+    }
+
+    /// [`Layer::match_histogram_to_layer`] with weights of this layer, by mask.
+    pub fn match_histogram_to_layer_weighted_by_mask<M>(
+        &self,
+        target: &Self,
+        weights: &Self,
+        mask: &M,
+        output: &mut Self,
+        pool: &LayerPool,
+    ) -> Result<(), LayerError>
+    where
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), weights.len(), "weights layer length mismatch");
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        self.match_histogram_to_layer_where(target, |i| weights[i], |i| mask.is_set(i), output, pool)
+    }
+    // endregion match_histogram_to_layer
+
+    fn match_histogram_to_curve_where<W, F>(
+        &self,
+        xs: &[f32],
+        ys: &[f32],
+        weight: W,
+        selected: F,
+        output: &mut Self,
+        pool: &LayerPool,
+    ) -> Result<(), LayerError>
+    where
+        W: Fn(usize) -> f32,
+        F: Fn(usize) -> bool,
+    {
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
+        checked::check_control_points(xs, ys)?;
+
+        let mut fractions = pool.get(self.len());
+        let mut matched = pool.get(self.len());
+
+        self.cumulative_fractions(weight, selected, &mut fractions)?;
+
+        fractions.lerp(xs, ys, &mut matched, pool);
+
+        self.map3(&fractions, &matched, output, |s_i, f_i, m_i| if f_i.is_nan() { s_i } else { m_i });
+
+        Ok(())
+    }
+
+    fn match_histogram_to_layer_where<W, F>(&self, target: &Self, weight: W, selected: F, output: &mut Self, pool: &LayerPool) -> Result<(), LayerError>
+    where
+        W: Fn(usize) -> f32,
+        F: Fn(usize) -> bool,
+    {
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
+        let mut sorted: Vec<f32> = target.iter().copied().filter(|t_k| !t_k.is_nan()).collect();
+
+        if sorted.is_empty() {
+            return Err(LayerError::OutOfBounds { index: 0, length: 0 });
+        }
+
+        kernels::sort_unstable_by(&mut sorted, f32::total_cmp);
+
+        let mut fractions = pool.get(self.len());
+
+        self.cumulative_fractions(weight, selected, &mut fractions)?;
+
+        self.map2(&fractions, output, |s_i, f_i| if f_i.is_nan() { s_i } else { quantile_of_sorted(&sorted, f_i) });
+
+        Ok(())
+    }
+
+    /// Writes cumulative fractions $F_i$ of selected non-NaN items into the output, and NaN for other items.
+    ///
+    /// Fails without writing anything if weights of these items don’t sum to a positive number; e.g., if all of them are
+    /// masked out.
+    fn cumulative_fractions<W, F>(&self, weight: W, selected: F, output: &mut Self) -> Result<(), LayerError>
+    where
+        W: Fn(usize) -> f32,
+        F: Fn(usize) -> bool,
+    {
+        let mut ranked: Vec<usize> = (0..self.len()).filter(|&i| selected(i) && !self[i].is_nan()).collect();
+
+        let mut weights_sum = kernels::CompensatedSum::default();
+
+        for &i in &ranked {
+            weights_sum.push(weight(i) as f64);
+        }
+
+        let weights_sum = weights_sum.value();
+
+        if weights_sum.is_nan() || weights_sum <= 0.0 {
+            return Err(LayerError::InvalidWeights);
+        }
+
+        kernels::sort_unstable_by(&mut ranked, |&i, &j| self[i].total_cmp(&self[j]));
+
+        output.fill(f32::NAN);
+
+        let mut cumulative = kernels::CompensatedSum::default();
+
+        for group in ranked.chunk_by(|&i, &j| self[i] == self[j]) {
+            let mut group_sum = kernels::CompensatedSum::default();
+
+            for &i in group {
+                group_sum.push(weight(i) as f64);
+            }
+
+            let group_sum = group_sum.value();
+            let fraction = ((cumulative.value() + group_sum * 0.5) / weights_sum) as f32;
+
+            for &i in group {
+                output[i] = fraction;
+            }
+
+            cumulative.push(group_sum);
+        }
+
+        Ok(())
+    }
+}
+
+/// Value at the $f$ fraction of non-empty sorted items, where the $k$-th item is at $\frac{k + 0.5}{L}$.
+fn quantile_of_sorted(sorted: &[f32], f: f32) -> f32 {
+    let last = sorted.len() - 1;
+
+    let rank = interpolations::clamp(f * sorted.len() as f32 - 0.5, 0.0, last as f32);
+    let lower = rank.floor() as usize;
+    let upper = usize::min(lower + 1, last);
+
+    interpolations::mix(rank - lower as f32, sorted[lower], sorted[upper])
+}
@@ -1,12 +1,32 @@
+pub mod checked;
+pub mod error;
+pub mod expr;
 pub mod geometry;
 pub mod grid;
+pub mod kernels;
 pub mod lattice;
+#[allow(clippy::module_inception)]
 pub mod layer;
+pub mod mask_layer;
+pub mod matching;
+pub mod nan_policy;
+pub mod ops;
+pub mod pool;
+pub mod rotation;
+pub mod spherical;
+pub mod statistics;
+pub mod vector_kernels;
+pub mod vector_layer;
 pub mod voronoi;
 
+pub use error::*;
+pub use expr::*;
 pub use geometry::*;
 pub use grid::*;
 pub use lattice::*;
 pub use layer::*;
-pub use layer::*;
+pub use mask_layer::*;
+pub use nan_policy::*;
+pub use pool::*;
+pub use vector_layer::*;
 pub use voronoi::*;
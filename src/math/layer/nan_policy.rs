@@ -0,0 +1,13 @@
+/// How statistics treat NaN items, i.e., items whose numeric proportionals aren’t comparable with themselves.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum NanPolicy {
+    /// Skip NaN items, as if they weren’t in a layer.
+    #[default]
+    Skip,
+
+    /// Return the first NaN item as the result.
+    Propagate,
+
+    /// Fail with [`LayerError::NotANumber`](crate::LayerError::NotANumber) pointing to the first NaN item.
+    Error,
+}
@@ -0,0 +1,325 @@
+//! Operator overloading for layers.
+//!
+//! Binary operators on layer references allocate a new layer of length of the left operand, e.g., `&a + &b` or `&a * 2.0`;
+//! assignment operators update the left operand in place, e.g., `a += &b`. Like the [`Layer::map2`], they don’t check lengths
+//! in release builds; use the output-parameter methods with length-checked variants where it matters.
+//!
+//! Values may be any scalar, [`V2`], [`V3`], [`V3d`], or [`V4`], as long as the item type supports the corresponding operation.
+
+use crate::*;
+use std::ops::*;
+
+/// Layer ⋅ layer addition.
+impl<T, U, W> Add<&Layer<U>> for &Layer<T>
+where
+    T: Copy + Default + Send + Sync + Add<U, Output = W>,
+    U: Copy + Default + Send + Sync,
+    W: Copy + Default + Send + Sync,
+{
+    type Output = Layer<W>;
+
+    fn add(self, rhs: &Layer<U>) -> Self::Output {
+        let mut output = Layer::new(self.len());
+
+        self.map2(rhs, &mut output, T::add);
+
+        output
+    }
+}
+
+/// Layer ⋅ layer addition with assignment.
+impl<T, U> AddAssign<&Layer<U>> for Layer<T>
+where
+    T: Copy + Default + Send + Sync + Add<U, Output = T>,
+    U: Copy + Default + Send + Sync,
+{
+    fn add_assign(&mut self, rhs: &Layer<U>) {
+        self.map2_assign(rhs, T::add);
+    }
+}
+
+/// Layer ⋅ layer subtraction.
+impl<T, U, W> Sub<&Layer<U>> for &Layer<T>
+where
+    T: Copy + Default + Send + Sync + Sub<U, Output = W>,
+    U: Copy + Default + Send + Sync,
+    W: Copy + Default + Send + Sync,
+{
+    type Output = Layer<W>;
+
+    fn sub(self, rhs: &Layer<U>) -> Self::Output {
+        let mut output = Layer::new(self.len());
+
+        self.map2(rhs, &mut output, T::sub);
+
+        output
+    }
+}
+
+/// Layer ⋅ layer subtraction with assignment.
+impl<T, U> SubAssign<&Layer<U>> for Layer<T>
+where
+    T: Copy + Default + Send + Sync + Sub<U, Output = T>,
+    U: Copy + Default + Send + Sync,
+{
+    fn sub_assign(&mut self, rhs: &Layer<U>) {
+        self.map2_assign(rhs, T::sub);
+    }
+}
+
+/// Layer ⋅ layer multiplication.
+impl<T, U, W> Mul<&Layer<U>> for &Layer<T>
+where
+    T: Copy + Default + Send + Sync + Mul<U, Output = W>,
+    U: Copy + Default + Send + Sync,
+    W: Copy + Default + Send + Sync,
+{
+    type Output = Layer<W>;
+
+    fn mul(self, rhs: &Layer<U>) -> Self::Output {
+        let mut output = Layer::new(self.len());
+
+        self.map2(rhs, &mut output, T::mul);
+
+        output
+    }
+}
+
+/// Layer ⋅ layer multiplication with assignment.
+impl<T, U> MulAssign<&Layer<U>> for Layer<T>
+where
+    T: Copy + Default + Send + Sync + Mul<U, Output = T>,
+    U: Copy + Default + Send + Sync,
+{
+    fn mul_assign(&mut self, rhs: &Layer<U>) {
+        self.map2_assign(rhs, T::mul);
+    }
+}
+
+/// Layer ⋅ layer division.
+impl<T, U, W> Div<&Layer<U>> for &Layer<T>
+where
+    T: Copy + Default + Send + Sync + Div<U, Output = W>,
+    U: Copy + Default + Send + Sync,
+    W: Copy + Default + Send + Sync,
+{
+    type Output = Layer<W>;
+
+    fn div(self, rhs: &Layer<U>) -> Self::Output {
+        let mut output = Layer::new(self.len());
+
+        self.map2(rhs, &mut output, T::div);
+
+        output
+    }
+}
+
+/// Layer ⋅ layer division with assignment.
+impl<T, U> DivAssign<&Layer<U>> for Layer<T>
+where
+    T: Copy + Default + Send + Sync + Div<U, Output = T>,
+    U: Copy + Default + Send + Sync,
+{
+    fn div_assign(&mut self, rhs: &Layer<U>) {
+        self.map2_assign(rhs, T::div);
+    }
+}
+
+/// Layer union.
+impl<T> BitOr for &Layer<T>
+where
+    T: Copy + Default + Send + Sync + BitOr<Output = T>,
+{
+    type Output = Layer<T>;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let mut output = Layer::new(self.len());
+
+        self.union(rhs, &mut output);
+
+        output
+    }
+}
+
+/// Layer union with assignment.
+impl<T> BitOrAssign<&Layer<T>> for Layer<T>
+where
+    T: Copy + Default + Send + Sync + BitOr<Output = T>,
+{
+    fn bitor_assign(&mut self, rhs: &Layer<T>) {
+        self.union_assign(rhs);
+    }
+}
+
+/// Layer intersection.
+impl<T> BitAnd for &Layer<T>
+where
+    T: Copy + Default + Send + Sync + BitAnd<Output = T>,
+{
+    type Output = Layer<T>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let mut output = Layer::new(self.len());
+
+        self.intersection(rhs, &mut output);
+
+        output
+    }
+}
+
+/// Layer intersection with assignment.
+impl<T> BitAndAssign<&Layer<T>> for Layer<T>
+where
+    T: Copy + Default + Send + Sync + BitAnd<Output = T>,
+{
+    fn bitand_assign(&mut self, rhs: &Layer<T>) {
+        self.intersection_assign(rhs);
+    }
+}
+
+/// Layer negation.
+impl<T> Neg for &Layer<T>
+where
+    T: Copy + Default + Send + Sync + Neg<Output = T>,
+{
+    type Output = Layer<T>;
+
+    fn neg(self) -> Self::Output {
+        let mut output = Layer::new(self.len());
+
+        Layer::neg(self, &mut output);
+
+        output
+    }
+}
+
+/// Layer complement.
+impl<T> Not for &Layer<T>
+where
+    T: Copy + Default + Send + Sync + Not<Output = T>,
+{
+    type Output = Layer<T>;
+
+    fn not(self) -> Self::Output {
+        let mut output = Layer::new(self.len());
+
+        Layer::not(self, &mut output);
+
+        output
+    }
+}
+
+/// Layer ⋅ value operations can’t be generic over a value type, since they would overlap with layer ⋅ layer ones.
+macro_rules! impl_value_ops {
+    ($($value:ty),*) => {$(
+        /// Layer ⋅ value addition.
+        impl<T, W> Add<$value> for &Layer<T>
+        where
+            T: Copy + Default + Send + Sync + Add<$value, Output = W>,
+            W: Copy + Default + Send + Sync,
+        {
+            type Output = Layer<W>;
+
+            fn add(self, rhs: $value) -> Self::Output {
+                let mut output = Layer::new(self.len());
+
+                self.map1_with(rhs, &mut output, T::add);
+
+                output
+            }
+        }
+
+        /// Layer ⋅ value addition with assignment.
+        impl<T> AddAssign<$value> for Layer<T>
+        where
+            T: Copy + Default + Send + Sync + Add<$value, Output = T>,
+        {
+            fn add_assign(&mut self, rhs: $value) {
+                self.map1_with_assign(rhs, T::add);
+            }
+        }
+
+        /// Layer ⋅ value subtraction.
+        impl<T, W> Sub<$value> for &Layer<T>
+        where
+            T: Copy + Default + Send + Sync + Sub<$value, Output = W>,
+            W: Copy + Default + Send + Sync,
+        {
+            type Output = Layer<W>;
+
+            fn sub(self, rhs: $value) -> Self::Output {
+                let mut output = Layer::new(self.len());
+
+                self.map1_with(rhs, &mut output, T::sub);
+
+                output
+            }
+        }
+
+        /// Layer ⋅ value subtraction with assignment.
+        impl<T> SubAssign<$value> for Layer<T>
+        where
+            T: Copy + Default + Send + Sync + Sub<$value, Output = T>,
+        {
+            fn sub_assign(&mut self, rhs: $value) {
+                self.map1_with_assign(rhs, T::sub);
+            }
+        }
+
+        /// Layer ⋅ value multiplication.
+        impl<T, W> Mul<$value> for &Layer<T>
+        where
+            T: Copy + Default + Send + Sync + Mul<$value, Output = W>,
+            W: Copy + Default + Send + Sync,
+        {
+            type Output = Layer<W>;
+
+            fn mul(self, rhs: $value) -> Self::Output {
+                let mut output = Layer::new(self.len());
+
+                self.map1_with(rhs, &mut output, T::mul);
+
+                output
+            }
+        }
+
+        /// Layer ⋅ value multiplication with assignment.
+        impl<T> MulAssign<$value> for Layer<T>
+        where
+            T: Copy + Default + Send + Sync + Mul<$value, Output = T>,
+        {
+            fn mul_assign(&mut self, rhs: $value) {
+                self.map1_with_assign(rhs, T::mul);
+            }
+        }
+
+        /// Layer ⋅ value division.
+        impl<T, W> Div<$value> for &Layer<T>
+        where
+            T: Copy + Default + Send + Sync + Div<$value, Output = W>,
+            W: Copy + Default + Send + Sync,
+        {
+            type Output = Layer<W>;
+
+            fn div(self, rhs: $value) -> Self::Output {
+                let mut output = Layer::new(self.len());
+
+                self.map1_with(rhs, &mut output, T::div);
+
+                output
+            }
+        }
+
+        /// Layer ⋅ value division with assignment.
+        impl<T> DivAssign<$value> for Layer<T>
+        where
+            T: Copy + Default + Send + Sync + Div<$value, Output = T>,
+        {
+            fn div_assign(&mut self, rhs: $value) {
+                self.map1_with_assign(rhs, T::div);
+            }
+        }
+    )*};
+}
+
+impl_value_ops!(f32, f64, u8, u16, u32, i16, i32, V2, V3, V3d, V4);
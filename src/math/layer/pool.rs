@@ -0,0 +1,83 @@
+use crate::*;
+use std::any::*;
+use std::cell::*;
+use std::collections::*;
+use std::ops::*;
+
+/// A pool of scratch layers.
+///
+/// Hands out [`ScratchLayer`]s of a requested item type and length, and takes them back once they’re dropped, so operations
+/// needing temporary layers don’t allocate them on every call. Layers are reused between iterations as long as the pool is
+/// alive, so a simulation should keep a single pool for its whole run.
+///
+/// Scratch layers are returned as is, so their items are unspecified; every user must overwrite them before reading.
+#[derive(Debug, Default)]
+pub struct LayerPool {
+    /// Free layers, keyed by item type and length. Every value is a `Vec<Layer<T>>`, where `T` matches the key.
+    free: RefCell<HashMap<(TypeId, usize), Box<dyn Any>>>,
+}
+
+impl LayerPool {
+    /// Creates a new empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Takes a free layer of specified item type and length from the pool, or allocates a new one if there’s no such layer.
+    pub fn get<T: Copy + Default + Send + Sync + 'static>(&self, length: usize) -> ScratchLayer<'_, T> {
+        let layer = self
+            .free
+            .borrow_mut()
+            .get_mut(&(TypeId::of::<T>(), length))
+            .and_then(|free| free.downcast_mut::<Vec<Layer<T>>>())
+            .and_then(|free| free.pop())
+            .unwrap_or_else(|| Layer::new(length));
+
+        ScratchLayer { pool: self, layer }
+    }
+
+    /// Drops all free layers. Layers currently in use will still be returned to the pool.
+    pub fn clear(&self) {
+        self.free.borrow_mut().clear();
+    }
+
+    fn put<T: Copy + Default + Send + Sync + 'static>(&self, layer: Layer<T>) {
+        self.free
+            .borrow_mut()
+            .entry((TypeId::of::<T>(), layer.len()))
+            .or_insert_with(|| Box::new(Vec::<Layer<T>>::new()))
+            .downcast_mut::<Vec<Layer<T>>>()
+            .expect("layer pool entry type mismatch")
+            .push(layer);
+    }
+}
+
+/// A layer borrowed from a [`LayerPool`]; returns to the pool on drop.
+#[derive(Debug)]
+pub struct ScratchLayer<'a, T: Copy + Default + Send + Sync + 'static> {
+    pool: &'a LayerPool,
+    layer: Layer<T>,
+}
+
+impl<T: Copy + Default + Send + Sync + 'static> Deref for ScratchLayer<'_, T> {
+    type Target = Layer<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.layer
+    }
+}
+
+impl<T: Copy + Default + Send + Sync + 'static> DerefMut for ScratchLayer<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.layer
+    }
+}
+
+impl<T: Copy + Default + Send + Sync + 'static> Drop for ScratchLayer<'_, T> {
+    fn drop(&mut self) {
+        // An empty boxed slice doesn’t allocate.
+        let layer = std::mem::replace(&mut self.layer, Vec::new().into());
+
+        self.pool.put(layer);
+    }
+}
@@ -0,0 +1,43 @@
+use crate::*;
+
+/// # Rotation
+///
+/// Rotations of vector layers by unit quaternions; e.g., moving plate vertices. A quaternion is converted to a matrix once,
+/// so every vector costs a matrix multiplication.
+impl Layer<V3> {
+    // region rotate
+    /// $O_i = q S_i q^{-1}$
+    ///
+    /// Uses the [`vector_kernels::transform_m3_v3`] kernel.
+    pub fn rotate(&self, rotation: Quat, output: &mut Self) {
+        debug_assert_eq!(self.len(), output.len(), "output layer length mismatch");
+
+        vector_kernels::transform_m3_v3(self, rotation.to_m3(), output);
+    }
+
+    /// [`Layer::rotate`] with assignment.
+    pub fn rotate_assign(&mut self, rotation: Quat) {
+        vector_kernels::transform_m3_v3_assign(self, rotation.to_m3());
+    }
+
+    /// [`Layer::rotate`] by mask.
+    pub fn rotate_by_mask<M>(&self, rotation: Quat, mask: &M, output: &mut Self)
+    where
+        M: Mask,
+    {
+        let m = rotation.to_m3();
+
+        self.map_masked(mask, output, |s_i, m_i| if m_i { s_i * m } else { s_i });
+    }
+
+    /// [`Layer::rotate_by_mask`] with assignment.
+    pub fn rotate_by_mask_assign<M>(&mut self, rotation: Quat, mask: &M)
+    where
+        M: Mask,
+    {
+        let m = rotation.to_m3();
+
+        self.map_masked_assign(mask, |s_i, m_i| if m_i { s_i * m } else { s_i });
+    }
+    // endregion rotate
+}
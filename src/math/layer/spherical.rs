@@ -0,0 +1,100 @@
+use crate::*;
+
+/// # Spherical geometry
+///
+/// Batch versions of [`V3`] spherical helpers, for positions and tangent vectors on the unit sphere.
+impl Layer<V3> {
+    // region cross
+    /// $O_i = S_i \times v$
+    pub fn cross_value(&self, value: V3, output: &mut Self) {
+        self.map1(output, |s_i| s_i.cross(value));
+    }
+
+    /// [`Layer::cross_value`] with assignment.
+    pub fn cross_value_assign(&mut self, value: V3) {
+        self.map1_assign(|s_i| s_i.cross(value));
+    }
+
+    /// $O_i = S_i \times B_i$
+    pub fn cross_layer(&self, layer_b: &Self, output: &mut Self) {
+        self.map2(layer_b, output, V3::cross);
+    }
+
+    /// [`Layer::cross_layer`] with assignment.
+    pub fn cross_layer_assign(&mut self, layer_b: &Self) {
+        self.map2_assign(layer_b, V3::cross);
+    }
+    // endregion cross
+
+    // region angle
+    /// $O_i = \angle(S_i, v)$
+    pub fn angle_value(&self, value: V3, output: &mut Layer<f32>) {
+        self.map1(output, |s_i| s_i.angle(value));
+    }
+
+    /// $O_i = \angle(S_i, B_i)$
+    pub fn angle_layer(&self, layer_b: &Self, output: &mut Layer<f32>) {
+        self.map2(layer_b, output, V3::angle);
+    }
+    // endregion angle
+
+    // region great_circle_distance
+    /// $O_i$ — great-circle distance between $S_i$ and $v$ on the unit sphere.
+    pub fn great_circle_distance_value(&self, value: V3, output: &mut Layer<f32>) {
+        self.map1(output, |s_i| s_i.great_circle_distance(value));
+    }
+
+    /// $O_i$ — great-circle distance between $S_i$ and $B_i$ on the unit sphere.
+    pub fn great_circle_distance_layer(&self, layer_b: &Self, output: &mut Layer<f32>) {
+        self.map2(layer_b, output, V3::great_circle_distance);
+    }
+    // endregion great_circle_distance
+
+    // region slerp
+    /// $O_i = \operatorname{slerp}(S_i, v, t)$
+    pub fn slerp_value(&self, value: V3, t: f32, output: &mut Self) {
+        self.map1(output, |s_i| s_i.slerp(value, t));
+    }
+
+    /// [`Layer::slerp_value`] with assignment.
+    pub fn slerp_value_assign(&mut self, value: V3, t: f32) {
+        self.map1_assign(|s_i| s_i.slerp(value, t));
+    }
+
+    /// $O_i = \operatorname{slerp}(S_i, B_i, t)$
+    pub fn slerp_layer(&self, layer_b: &Self, t: f32, output: &mut Self) {
+        self.map2(layer_b, output, |s_i, b_i| s_i.slerp(b_i, t));
+    }
+
+    /// [`Layer::slerp_layer`] with assignment.
+    pub fn slerp_layer_assign(&mut self, layer_b: &Self, t: f32) {
+        self.map2_assign(layer_b, |s_i, b_i| s_i.slerp(b_i, t));
+    }
+    // endregion slerp
+
+    // region project_to_tangent
+    /// $O_i = S_i - \hat{P_i} (S_i \cdot \hat{P_i})$, where $\hat{P_i} = \frac{P_i}{|P_i|}$
+    ///
+    /// Projects vectors onto planes tangent to the sphere at points $P_i$; e.g., keeps velocities at vertices tangent.
+    pub fn project_to_tangent(&self, points: &Self, output: &mut Self) {
+        self.map2(points, output, V3::project_to_tangent);
+    }
+
+    /// [`Layer::project_to_tangent`] with assignment.
+    pub fn project_to_tangent_assign(&mut self, points: &Self) {
+        self.map2_assign(points, V3::project_to_tangent);
+    }
+    // endregion project_to_tangent
+
+    // region lat_lon
+    /// Converts directions to latitudes and longitudes in radians with [`V3::to_lat_lon`].
+    pub fn to_lat_lon(&self, output: &mut Layer<V2>) {
+        self.map1(output, V3::to_lat_lon);
+    }
+
+    /// Converts latitudes and longitudes in radians to unit vectors with [`V3::from_lat_lon`].
+    pub fn from_lat_lon(lat_lon: &Layer<V2>, output: &mut Self) {
+        lat_lon.map1(output, |l_i| V3::from_lat_lon(l_i.x, l_i.y));
+    }
+    // endregion lat_lon
+}
@@ -0,0 +1,392 @@
+use crate::*;
+
+/// Running weighted moments of a set of values: sum of weights, mean, and sum of squared deviations from the mean.
+#[derive(Clone, Copy, Default)]
+struct Moments {
+    weights_sum: f64,
+    mean: f64,
+    squares_sum: f64,
+}
+
+impl Moments {
+    /// Adds a value using West’s weighted update; zero weights are skipped.
+    fn push(&mut self, x: f64, w: f64) {
+        if w == 0.0 {
+            return;
+        }
+
+        self.weights_sum += w;
+
+        let delta = x - self.mean;
+
+        self.mean += delta * w / self.weights_sum;
+        self.squares_sum += w * delta * (x - self.mean);
+    }
+
+    /// Combines moments of two disjoint sets using Chan’s pairwise formula.
+    fn combine(a: Self, b: Self) -> Self {
+        if a.weights_sum == 0.0 {
+            return b;
+        }
+
+        if b.weights_sum == 0.0 {
+            return a;
+        }
+
+        let weights_sum = a.weights_sum + b.weights_sum;
+        let delta = b.mean - a.mean;
+
+        Self {
+            weights_sum,
+            mean: a.mean + delta * b.weights_sum / weights_sum,
+            squares_sum: a.squares_sum + b.squares_sum + delta * delta * a.weights_sum * b.weights_sum / weights_sum,
+        }
+    }
+
+    /// Population variance, or NaN for an empty set.
+    fn variance(self) -> f64 {
+        self.squares_sum / self.weights_sum
+    }
+}
+
+/// # Extended statistics
+///
+/// Statistics of numeric values of items ($S_i$ converted with [`ToNumeric::into_numeric`], e.g., magnitudes of vectors),
+/// evaluated in `f64`.
+///
+/// -   Variances are population ones; they’re NaN for empty layers and for zero sums of weights. Zero-weight items are
+///     skipped, but NaN items with non-zero weights propagate, so a single one makes a variance NaN; mask them out to skip
+///     them.
+/// -   Percentiles are in the $[0, 100]$ range, and are linearly interpolated between the closest ranks. They’re NaN for
+///     empty layers.
+/// -   Histograms count items in half-open bins $[e_k, e_{k + 1})$, except the last one, which also includes its upper edge.
+///     Items outside of the edges are skipped.
+/// -   Percentiles and histograms skip NaN items.
+/// -   `*_by_mask` versions only take items with set $M_i$ into account.
+///
+/// Like the [`Layer::average`], all of them are reduced chunk by chunk, so results don’t depend on the `parallel` feature.
+impl<T: Copy + Default + Send + Sync> Layer<T> {
+    // region variance
+    /// $\sigma^2 = \frac{1}{L_S} \sum_i (S_i - \bar{S})^2$
+    ///
+    /// NaN if any item is NaN.
+    pub fn variance<U>(&self) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+    {
+        self.moments(|_| 1.0).variance()
+    }
+
+    /// $\sigma^2 = \frac{\sum_i M_i (S_i - \bar{S})^2}{\sum_i M_i}$
+    pub fn variance_by_mask<U, M>(&self, mask: &M) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        self.moments(|i| if mask.is_set(i) { 1.0 } else { 0.0 }).variance()
+    }
+
+    /// $\sigma^2 = \frac{\sum_i W_i (S_i - \bar{S})^2}{\sum_i W_i}$, where $\bar{S}$ is the weighted average.
+    pub fn weighted_variance<U, W>(&self, weights: &Layer<W>) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        W: Copy + Send + Sync + Into<f64>,
+    {
+        debug_assert_eq!(self.len(), weights.len(), "weights layer length mismatch");
+
+        self.moments(|i| weights[i].into()).variance()
+    }
+
+    /// $\sigma^2 = \frac{\sum_i M_i W_i (S_i - \bar{S})^2}{\sum_i M_i W_i}$
+    pub fn weighted_variance_by_mask<U, W, M>(&self, weights: &Layer<W>, mask: &M) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        W: Copy + Send + Sync + Into<f64>,
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), weights.len(), "weights layer length mismatch");
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        self.moments(|i| if mask.is_set(i) { weights[i].into() } else { 0.0 }).variance()
+    }
+    // endregion variance
+
+    // region std_dev
+    /// $\sigma = \sqrt{\sigma^2}$
+    pub fn std_dev<U>(&self) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+    {
+        self.variance().sqrt()
+    }
+
+    /// [`Layer::std_dev`] by mask.
+    pub fn std_dev_by_mask<U, M>(&self, mask: &M) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        M: Mask,
+    {
+        self.variance_by_mask(mask).sqrt()
+    }
+
+    /// Square root of the [`Layer::weighted_variance`].
+    pub fn weighted_std_dev<U, W>(&self, weights: &Layer<W>) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        W: Copy + Send + Sync + Into<f64>,
+    {
+        self.weighted_variance(weights).sqrt()
+    }
+
+    /// Square root of the [`Layer::weighted_variance_by_mask`].
+    pub fn weighted_std_dev_by_mask<U, W, M>(&self, weights: &Layer<W>, mask: &M) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        W: Copy + Send + Sync + Into<f64>,
+        M: Mask,
+    {
+        self.weighted_variance_by_mask(weights, mask).sqrt()
+    }
+    // endregion std_dev
+
+    // region percentile
+    /// Returns the 50th percentile.
+    pub fn median<U>(&self) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+    {
+        self.percentile(50.0)
+    }
+
+    /// [`Layer::median`] by mask.
+    pub fn median_by_mask<U, M>(&self, mask: &M) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        M: Mask,
+    {
+        self.percentile_by_mask(50.0, mask)
+    }
+
+    /// Returns the $p$-th percentile.
+    pub fn percentile<U>(&self, p: f64) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+    {
+        percentile_of_sorted(&self.sorted_numerics(|_| true), p)
+    }
+
+    /// [`Layer::percentile`] by mask.
+    pub fn percentile_by_mask<U, M>(&self, p: f64, mask: &M) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        percentile_of_sorted(&self.sorted_numerics(|i| mask.is_set(i)), p)
+    }
+
+    /// Returns the $p_k$-th percentiles, sorting items only once.
+    pub fn percentiles<U>(&self, ps: &[f64]) -> Vec<f64>
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+    {
+        let sorted = self.sorted_numerics(|_| true);
+
+        ps.iter().map(|&p| percentile_of_sorted(&sorted, p)).collect()
+    }
+
+    /// [`Layer::percentiles`] by mask.
+    pub fn percentiles_by_mask<U, M>(&self, ps: &[f64], mask: &M) -> Vec<f64>
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        let sorted = self.sorted_numerics(|i| mask.is_set(i));
+
+        ps.iter().map(|&p| percentile_of_sorted(&sorted, p)).collect()
+    }
+    // endregion percentile
+
+    // region histogram
+    /// Counts items in `bins` bins of equal width in the $[l, u]$ range.
+    pub fn histogram<U>(&self, bins: usize, lower: f64, upper: f64) -> Vec<usize>
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+    {
+        self.count_bins(bins, |x| fixed_bin(x, bins, lower, upper), |_| true)
+    }
+
+    /// [`Layer::histogram`] by mask.
+    pub fn histogram_by_mask<U, M>(&self, bins: usize, lower: f64, upper: f64, mask: &M) -> Vec<usize>
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        self.count_bins(bins, |x| fixed_bin(x, bins, lower, upper), |i| mask.is_set(i))
+    }
+
+    /// Counts items in bins between ascending `edges`; returns $L_e - 1$ counts.
+    pub fn histogram_by_edges<U>(&self, edges: &[f64]) -> Vec<usize>
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+    {
+        self.count_bins(edges.len().saturating_sub(1), |x| edge_bin(x, edges), |_| true)
+    }
+
+    /// [`Layer::histogram_by_edges`] by mask.
+    pub fn histogram_by_edges_by_mask<U, M>(&self, edges: &[f64], mask: &M) -> Vec<usize>
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        M: Mask,
+    {
+        debug_assert_eq!(self.len(), mask.len(), "mask layer length mismatch");
+
+        self.count_bins(edges.len().saturating_sub(1), |x| edge_bin(x, edges), |i| mask.is_set(i))
+    }
+    // endregion histogram
+
+    /// $S_i$ converted to `f64`.
+    fn numeric_at<U>(&self, i: usize) -> f64
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+    {
+        self[i].into_numeric().into()
+    }
+
+    /// Reduces weighted moments of items, with `weight` returning $W_i$.
+    fn moments<U, F>(&self, weight: F) -> Moments
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        F: Fn(usize) -> f64 + Send + Sync,
+    {
+        kernels::reduce(
+            self.len(),
+            Moments::default(),
+            |range| {
+                let mut moments = Moments::default();
+
+                for i in range {
+                    moments.push(self.numeric_at(i), weight(i));
+                }
+
+                moments
+            },
+            Moments::combine,
+        )
+    }
+
+    /// Collects selected non-NaN numeric values in ascending order.
+    fn sorted_numerics<U, F>(&self, selected: F) -> Vec<f64>
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        F: Fn(usize) -> bool + Send + Sync,
+    {
+        let mut numerics = kernels::reduce(
+            self.len(),
+            Vec::new(),
+            |range| range.filter(|&i| selected(i)).map(|i| self.numeric_at(i)).filter(|x| !x.is_nan()).collect(),
+            |mut a, mut b| {
+                a.append(&mut b);
+                a
+            },
+        );
+
+        kernels::sort_unstable_by(&mut numerics, f64::total_cmp);
+
+        numerics
+    }
+
+    /// Counts selected items per bin, with `bin` returning a bin index of a numeric value.
+    fn count_bins<U, B, F>(&self, bins: usize, bin: B, selected: F) -> Vec<usize>
+    where
+        T: ToNumeric<U>,
+        U: Into<f64>,
+        B: Fn(f64) -> Option<usize> + Send + Sync,
+        F: Fn(usize) -> bool + Send + Sync,
+    {
+        kernels::reduce(
+            self.len(),
+            vec![0; bins],
+            |range| {
+                let mut counts = vec![0; bins];
+
+                for i in range.filter(|&i| selected(i)) {
+                    if let Some(k) = bin(self.numeric_at(i)) {
+                        counts[k] += 1;
+                    }
+                }
+
+                counts
+            },
+            |mut a, b| {
+                a.iter_mut().zip(b).for_each(|(a_k, b_k)| *a_k += b_k);
+                a
+            },
+        )
+    }
+}
+
+/// $p$-th percentile of sorted values, linearly interpolated between the closest ranks.
+fn percentile_of_sorted(sorted: &[f64], p: f64) -> f64 {
+    let Some(last) = sorted.len().checked_sub(1) else {
+        return f64::NAN;
+    };
+
+    let rank = interpolations::clamp(p / 100.0, 0.0, 1.0) * last as f64;
+    let lower = rank.floor() as usize;
+    let upper = usize::min(lower + 1, last);
+
+    interpolations::mix(rank - lower as f64, sorted[lower], sorted[upper])
+}
+
+/// Index of an equal-width bin containing $x$, if any.
+fn fixed_bin(x: f64, bins: usize, lower: f64, upper: f64) -> Option<usize> {
+    if bins == 0 || !(lower..=upper).contains(&x) {
+        return None;
+    }
+
+    let k = ((x - lower) / (upper - lower) * bins as f64) as usize;
+
+    Some(usize::min(k, bins - 1))
+}
+
+/// Index of a bin between ascending edges containing $x$, if any.
+fn edge_bin(x: f64, edges: &[f64]) -> Option<usize> {
+    let (&first, &last) = (edges.first()?, edges.last()?);
+
+    if edges.len() < 2 || !(first..=last).contains(&x) {
+        return None;
+    }
+
+    let k = edges.partition_point(|&e_k| e_k <= x);
+
+    Some(usize::min(k, edges.len() - 1) - 1)
+}
@@ -0,0 +1,428 @@
+//! Layer kernels for [`V3`] and [`V4`] layers.
+//!
+//! With the `simd` feature enabled, these kernels load 16-byte aligned items straight into 4-lane registers (see
+//! `simd::F32x4`) and store results the same way. Reductions of single items (dot products, magnitudes, and normalization)
+//! and matrix transforms process blocks of 4 items, transposed into registers of $x$, $y$, $z$, and $w$ components, so
+//! every lane performs a whole operation on its own item. Leftover items are processed one by one.
+//!
+//! Without the feature, kernels fall back to the scalar operations of vectors. Both paths perform exactly the same IEEE 754
+//! operations in the same order, so results are bit-identical. Like all other kernels, these ones are split into chunks
+//! with [`kernels::map_chunks1`] and its siblings, so they run in parallel with the `parallel` feature.
+
+#[cfg(feature = "simd")]
+use crate::simd::F32x4;
+use crate::*;
+use std::ops::*;
+
+// region V3
+/// $O_i = S_i + B_i$
+pub fn add_v3(source: &[V3], layer_b: &[V3], output: &mut [V3]) {
+    add(source, layer_b, output);
+}
+
+/// $O_i = S_i - B_i$
+pub fn sub_v3(source: &[V3], layer_b: &[V3], output: &mut [V3]) {
+    sub(source, layer_b, output);
+}
+
+/// $O_i = S_i v$
+pub fn mul_v3(source: &[V3], value: f32, output: &mut [V3]) {
+    mul(source, value, output);
+}
+
+/// $O_i = S_i \cdot B_i$
+pub fn dot_v3(source: &[V3], layer_b: &[V3], output: &mut [f32]) {
+    dot(source, layer_b, output);
+}
+
+/// $O_i = |S_i|$
+pub fn magnitude_v3(source: &[V3], output: &mut [f32]) {
+    magnitude(source, output);
+}
+
+/// $O_i = \frac{S_i}{|S_i|}$
+pub fn normalize_v3(source: &[V3], output: &mut [V3]) {
+    normalize(source, output);
+}
+
+/// $S_i \gets \frac{S_i}{|S_i|}$
+pub fn normalize_v3_assign(source: &mut [V3]) {
+    normalize_assign(source);
+}
+
+/// $O_i = M S_i$
+pub fn transform_m3_v3(source: &[V3], matrix: M3, output: &mut [V3]) {
+    transform(source, Transform::from(matrix), output);
+}
+
+/// $S_i \gets M S_i$
+pub fn transform_m3_v3_assign(source: &mut [V3], matrix: M3) {
+    transform_assign(source, Transform::from(matrix));
+}
+
+/// $O_i = M (S_i, 1)$
+pub fn transform_m4_v3(source: &[V3], matrix: M4, output: &mut [V3]) {
+    transform(source, Transform::from(matrix), output);
+}
+
+/// $S_i \gets M (S_i, 1)$
+pub fn transform_m4_v3_assign(source: &mut [V3], matrix: M4) {
+    transform_assign(source, Transform::from(matrix));
+}
+// endregion V3
+
+// region V4
+/// $O_i = S_i + B_i$
+pub fn add_v4(source: &[V4], layer_b: &[V4], output: &mut [V4]) {
+    add(source, layer_b, output);
+}
+
+/// $O_i = S_i - B_i$
+pub fn sub_v4(source: &[V4], layer_b: &[V4], output: &mut [V4]) {
+    sub(source, layer_b, output);
+}
+
+/// $O_i = S_i v$
+pub fn mul_v4(source: &[V4], value: f32, output: &mut [V4]) {
+    mul(source, value, output);
+}
+
+/// $O_i = S_i \cdot B_i$
+pub fn dot_v4(source: &[V4], layer_b: &[V4], output: &mut [f32]) {
+    dot(source, layer_b, output);
+}
+
+/// $O_i = |S_i|$
+pub fn magnitude_v4(source: &[V4], output: &mut [f32]) {
+    magnitude(source, output);
+}
+
+/// $O_i = \frac{S_i}{|S_i|}$
+pub fn normalize_v4(source: &[V4], output: &mut [V4]) {
+    normalize(source, output);
+}
+
+/// $S_i \gets \frac{S_i}{|S_i|}$
+pub fn normalize_v4_assign(source: &mut [V4]) {
+    normalize_assign(source);
+}
+
+/// $O_i = M S_i$
+pub fn transform_m4_v4(source: &[V4], matrix: M4, output: &mut [V4]) {
+    transform(source, Transform::from(matrix), output);
+}
+
+/// $S_i \gets M S_i$
+pub fn transform_m4_v4_assign(source: &mut [V4], matrix: M4) {
+    transform_assign(source, Transform::from(matrix));
+}
+// endregion V4
+
+/// A vector stored in 16 bytes, with its components in the first `LANES` lanes.
+trait Vector:
+    Copy + Send + Sync + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self> + Dot<Output = f32> + Magnitude<Output = f32> + Normalize
+{
+    #[cfg(feature = "simd")]
+    const LANES: usize;
+
+    /// Scalar matrix transform; the fallback for leftover items.
+    fn transform(self, transform: &Transform) -> Self;
+
+    #[cfg(feature = "simd")]
+    fn load(&self) -> F32x4;
+
+    #[cfg(feature = "simd")]
+    fn store(value: F32x4, output: &mut Self);
+}
+
+impl Vector for V3 {
+    #[cfg(feature = "simd")]
+    const LANES: usize = 3;
+
+    #[inline(always)]
+    fn transform(self, transform: &Transform) -> Self {
+        match *transform {
+            Transform::M3(m) => self * m,
+            Transform::M4(m) => self * m,
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    fn load(&self) -> F32x4 {
+        F32x4::load_v3(self)
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    fn store(value: F32x4, output: &mut Self) {
+        value.store_v3(output);
+    }
+}
+
+impl Vector for V4 {
+    #[cfg(feature = "simd")]
+    const LANES: usize = 4;
+
+    #[inline(always)]
+    fn transform(self, transform: &Transform) -> Self {
+        match *transform {
+            Transform::M3(_) => unreachable!("V4 transforms are 4×4"),
+            Transform::M4(m) => self * m,
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    fn load(&self) -> F32x4 {
+        F32x4::load_v4(self)
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    fn store(value: F32x4, output: &mut Self) {
+        value.store_v4(output);
+    }
+}
+
+/// A matrix transform: linear for matrices matching vector sizes, and affine for [`V3`] by [`M4`].
+#[derive(Clone, Copy)]
+enum Transform {
+    M3(M3),
+    M4(M4),
+}
+
+impl From<M3> for Transform {
+    fn from(matrix: M3) -> Self {
+        Self::M3(matrix)
+    }
+}
+
+impl From<M4> for Transform {
+    fn from(matrix: M4) -> Self {
+        Self::M4(matrix)
+    }
+}
+
+impl Transform {
+    /// Matrix columns, and the number of them; a [`V3`] by [`M4`] transform adds the last column as is.
+    #[cfg(feature = "simd")]
+    fn columns(&self) -> ([[f32; 4]; 4], usize) {
+        match *self {
+            Self::M3(M3 { a, b, c }) => ([a.extend(0.0).into(), b.extend(0.0).into(), c.extend(0.0).into(), [0.0; 4]], 3),
+            Self::M4(M4 { a, b, c, d }) => ([a.into(), b.into(), c.into(), d.into()], 4),
+        }
+    }
+}
+
+fn add<T: Vector>(source: &[T], layer_b: &[T], output: &mut [T]) {
+    #[cfg(feature = "simd")]
+    kernels::map_chunks2(source, layer_b, output, |source, layer_b, output| {
+        for ((o_i, s_i), b_i) in output.iter_mut().zip(source).zip(layer_b) {
+            T::store(s_i.load() + b_i.load(), o_i);
+        }
+    });
+
+    #[cfg(not(feature = "simd"))]
+    kernels::map2(source, layer_b, output, |s_i, b_i| s_i + b_i);
+}
+
+fn sub<T: Vector>(source: &[T], layer_b: &[T], output: &mut [T]) {
+    #[cfg(feature = "simd")]
+    kernels::map_chunks2(source, layer_b, output, |source, layer_b, output| {
+        for ((o_i, s_i), b_i) in output.iter_mut().zip(source).zip(layer_b) {
+            T::store(s_i.load() - b_i.load(), o_i);
+        }
+    });
+
+    #[cfg(not(feature = "simd"))]
+    kernels::map2(source, layer_b, output, |s_i, b_i| s_i - b_i);
+}
+
+fn mul<T: Vector>(source: &[T], value: f32, output: &mut [T]) {
+    #[cfg(feature = "simd")]
+    kernels::map_chunks1(source, output, |source, output| {
+        let value = F32x4::splat(value);
+
+        for (o_i, s_i) in output.iter_mut().zip(source) {
+            T::store(s_i.load() * value, o_i);
+        }
+    });
+
+    #[cfg(not(feature = "simd"))]
+    kernels::map1(source, output, |s_i| s_i * value);
+}
+
+fn dot<T: Vector>(source: &[T], layer_b: &[T], output: &mut [f32]) {
+    #[cfg(feature = "simd")]
+    kernels::map_chunks2(source, layer_b, output, |source, layer_b, output| {
+        let mut output = output.chunks_exact_mut(4);
+        let mut source = source.chunks_exact(4);
+        let mut layer_b = layer_b.chunks_exact(4);
+
+        for ((o, s), b) in (&mut output).zip(&mut source).zip(&mut layer_b) {
+            o.copy_from_slice(&dot_block::<T>(load_block(s), load_block(b)).to_array());
+        }
+
+        for ((o_i, &s_i), &b_i) in output.into_remainder().iter_mut().zip(source.remainder()).zip(layer_b.remainder()) {
+            *o_i = s_i.dot(b_i);
+        }
+    });
+
+    #[cfg(not(feature = "simd"))]
+    kernels::map2(source, layer_b, output, T::dot);
+}
+
+fn magnitude<T: Vector>(source: &[T], output: &mut [f32]) {
+    #[cfg(feature = "simd")]
+    kernels::map_chunks1(source, output, |source, output| {
+        let mut output = output.chunks_exact_mut(4);
+        let mut source = source.chunks_exact(4);
+
+        for (o, s) in (&mut output).zip(&mut source) {
+            let s = load_block(s);
+
+            o.copy_from_slice(&dot_block::<T>(s, s).sqrt().to_array());
+        }
+
+        for (o_i, &s_i) in output.into_remainder().iter_mut().zip(source.remainder()) {
+            *o_i = s_i.magnitude();
+        }
+    });
+
+    #[cfg(not(feature = "simd"))]
+    kernels::map1(source, output, T::magnitude);
+}
+
+fn normalize<T: Vector>(source: &[T], output: &mut [T]) {
+    #[cfg(feature = "simd")]
+    kernels::map_chunks1(source, output, |source, output| {
+        let mut output = output.chunks_exact_mut(4);
+        let mut source = source.chunks_exact(4);
+
+        for (o, s) in (&mut output).zip(&mut source) {
+            store_block(normalize_block::<T>(load_block(s)), o);
+        }
+
+        for (o_i, &s_i) in output.into_remainder().iter_mut().zip(source.remainder()) {
+            *o_i = s_i.normalize();
+        }
+    });
+
+    #[cfg(not(feature = "simd"))]
+    kernels::map1(source, output, T::normalize);
+}
+
+fn normalize_assign<T: Vector>(source: &mut [T]) {
+    #[cfg(feature = "simd")]
+    kernels::map_chunks1_assign(source, |source| {
+        let mut source = source.chunks_exact_mut(4);
+
+        for s in &mut source {
+            store_block(normalize_block::<T>(load_block(s)), s);
+        }
+
+        for s_i in source.into_remainder() {
+            *s_i = s_i.normalize();
+        }
+    });
+
+    #[cfg(not(feature = "simd"))]
+    kernels::map1_assign(source, T::normalize);
+}
+
+fn transform<T: Vector>(source: &[T], transform: Transform, output: &mut [T]) {
+    #[cfg(feature = "simd")]
+    kernels::map_chunks1(source, output, |source, output| {
+        let mut output = output.chunks_exact_mut(4);
+        let mut source = source.chunks_exact(4);
+
+        for (o, s) in (&mut output).zip(&mut source) {
+            store_block(transform_block::<T>(load_block(s), &transform), o);
+        }
+
+        for (o_i, &s_i) in output.into_remainder().iter_mut().zip(source.remainder()) {
+            *o_i = s_i.transform(&transform);
+        }
+    });
+
+    #[cfg(not(feature = "simd"))]
+    kernels::map1(source, output, |s_i| s_i.transform(&transform));
+}
+
+fn transform_assign<T: Vector>(source: &mut [T], transform: Transform) {
+    #[cfg(feature = "simd")]
+    kernels::map_chunks1_assign(source, |source| {
+        let mut source = source.chunks_exact_mut(4);
+
+        for s in &mut source {
+            store_block(transform_block::<T>(load_block(s), &transform), s);
+        }
+
+        for s_i in source.into_remainder() {
+            *s_i = s_i.transform(&transform);
+        }
+    });
+
+    #[cfg(not(feature = "simd"))]
+    kernels::map1_assign(source, |s_i| s_i.transform(&transform));
+}
+
+/// Loads 4 items, and transposes them into registers of their components.
+#[cfg(feature = "simd")]
+#[inline(always)]
+fn load_block<T: Vector>(block: &[T]) -> [F32x4; 4] {
+    F32x4::transpose([block[0].load(), block[1].load(), block[2].load(), block[3].load()])
+}
+
+/// Transposes registers of components back into 4 items, and stores them.
+#[cfg(feature = "simd")]
+#[inline(always)]
+fn store_block<T: Vector>(components: [F32x4; 4], block: &mut [T]) {
+    for (b_i, v_i) in block.iter_mut().zip(F32x4::transpose(components)) {
+        T::store(v_i, b_i);
+    }
+}
+
+/// Dot products of 4 pairs of items; components are summed from left to right, like the scalar [`Dot`] does.
+#[cfg(feature = "simd")]
+#[inline(always)]
+fn dot_block<T: Vector>(a: [F32x4; 4], b: [F32x4; 4]) -> F32x4 {
+    let sum = a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+
+    if T::LANES == 4 {
+        sum + a[3] * b[3]
+    } else {
+        sum
+    }
+}
+
+/// Normalizes 4 items like the scalar [`Normalize`] does: zero-length vectors are divided by 1.
+#[cfg(feature = "simd")]
+#[inline(always)]
+fn normalize_block<T: Vector>(s: [F32x4; 4]) -> [F32x4; 4] {
+    let inv = F32x4::splat(1.0) / dot_block::<T>(s, s).sqrt().replace_zeros(1.0);
+
+    [s[0] * inv, s[1] * inv, s[2] * inv, s[3] * inv]
+}
+
+/// Transforms 4 items; $O_k = \sum_j m_{jk} S_j$, summed from left to right like the scalar matrix multiplication does.
+#[cfg(feature = "simd")]
+#[inline(always)]
+fn transform_block<T: Vector>(s: [F32x4; 4], transform: &Transform) -> [F32x4; 4] {
+    let (columns, count) = transform.columns();
+    let affine = T::LANES == 3 && count == 4;
+    let mut result = [F32x4::splat(0.0); 4];
+
+    for (k, r_k) in result.iter_mut().enumerate().take(T::LANES) {
+        let mut sum = F32x4::splat(columns[0][k]) * s[0];
+
+        for j in 1..T::LANES {
+            sum = sum + F32x4::splat(columns[j][k]) * s[j];
+        }
+
+        *r_k = if affine { sum + F32x4::splat(columns[3][k]) } else { sum };
+    }
+
+    result
+}
@@ -0,0 +1,307 @@
+#![allow(dead_code)]
+
+use crate::*;
+
+/// A 2×2 matrix.
+///
+/// We will use column-major order, so vectors are columns.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(align(16))]
+pub struct M2 {
+    pub a: V2,
+    pub b: V2,
+}
+
+impl Default for M2 {
+    fn default() -> Self {
+        Self {
+            a: V2::new(1.0, 0.0),
+            b: V2::new(0.0, 1.0),
+        }
+    }
+}
+
+impl From<[[f32; 2]; 2]> for M2 {
+    fn from([a, b]: [[f32; 2]; 2]) -> Self {
+        Self { a: a.into(), b: b.into() }
+    }
+}
+
+impl From<M2> for [[f32; 2]; 2] {
+    fn from(value: M2) -> Self {
+        [value.a.into(), value.b.into()]
+    }
+}
+
+/// Matrix negation.
+impl std::ops::Neg for M2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { a: -self.a, b: -self.b }
+    }
+}
+
+/// Matrix addition.
+impl std::ops::Add for M2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            a: self.a + rhs.a,
+            b: self.b + rhs.b,
+        }
+    }
+}
+
+/// Matrix addition with assignment.
+impl std::ops::AddAssign for M2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+/// Matrix subtraction.
+impl std::ops::Sub for M2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            a: self.a - rhs.a,
+            b: self.b - rhs.b,
+        }
+    }
+}
+
+/// Matrix subtraction with assignment.
+impl std::ops::SubAssign for M2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+/// Matrix multiplication by scalar.
+impl std::ops::Mul<f32> for M2 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            a: self.a * rhs,
+            b: self.b * rhs,
+        }
+    }
+}
+
+/// Matrix multiplication by scalar with assignment.
+impl std::ops::MulAssign<f32> for M2 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs
+    }
+}
+
+/// Matrix multiplication.
+impl std::ops::Mul for M2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let x = V2::new(self.a.x, self.b.x);
+        let y = V2::new(self.a.y, self.b.y);
+
+        Self {
+            a: V2::new(rhs.a.dot(x), rhs.a.dot(y)),
+            b: V2::new(rhs.b.dot(x), rhs.b.dot(y)),
+        }
+    }
+}
+
+/// Matrix multiplication with assignment.
+impl std::ops::MulAssign for M2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+/// Matrix division by scalar.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl std::ops::Div<f32> for M2 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+/// Matrix division by scalar with assignment.
+impl std::ops::DivAssign<f32> for M2 {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs
+    }
+}
+
+/// Matrix transposition.
+impl Transpose for M2 {
+    fn transpose(&self) -> Self {
+        Self {
+            a: V2::new(self.a.x, self.b.x),
+            b: V2::new(self.a.y, self.b.y),
+        }
+    }
+}
+
+/// Matrix inversion.
+impl Inv for M2 {
+    fn inv(self) -> Self {
+        let Self {
+            a: V2 { x: a, y: b },
+            b: V2 { x: c, y: d },
+        } = self;
+
+        let det = a * d - b * c;
+
+        Self {
+            a: V2::new(d, -b),
+            b: V2::new(-c, a),
+        } / det
+    }
+}
+
+/// Entrywise matrix ⋅ scalar addition.
+impl EntrywiseAdd<f32> for M2 {
+    type Output = Self;
+
+    fn entrywise_add(self, rhs: f32) -> Self {
+        Self {
+            a: self.a.entrywise_add(rhs),
+            b: self.b.entrywise_add(rhs),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ scalar subtraction.
+impl EntrywiseSub<f32> for M2 {
+    type Output = Self;
+
+    fn entrywise_sub(self, rhs: f32) -> Self {
+        Self {
+            a: self.a.entrywise_sub(rhs),
+            b: self.b.entrywise_sub(rhs),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ matrix multiplication; i.e., Hadamard product.
+impl EntrywiseMul for M2 {
+    type Output = Self;
+
+    fn entrywise_mul(self, rhs: Self) -> Self {
+        Self {
+            a: self.a.entrywise_mul(rhs.a),
+            b: self.b.entrywise_mul(rhs.b),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ matrix division.
+impl EntrywiseDiv for M2 {
+    type Output = Self;
+
+    fn entrywise_div(self, rhs: Self) -> Self {
+        Self {
+            a: self.a.entrywise_div(rhs.a),
+            b: self.b.entrywise_div(rhs.b),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ matrix exponentiation.
+impl EntrywisePow for M2 {
+    type Output = Self;
+
+    fn entrywise_pow(self, rhs: Self) -> Self {
+        Self {
+            a: self.a.entrywise_pow(rhs.a),
+            b: self.b.entrywise_pow(rhs.b),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ scalar exponentiation.
+impl EntrywisePow<f32> for M2 {
+    type Output = Self;
+
+    fn entrywise_pow(self, rhs: f32) -> Self {
+        Self {
+            a: self.a.entrywise_pow(rhs),
+            b: self.b.entrywise_pow(rhs),
+        }
+    }
+}
+
+/// Entrywise matrix square root.
+impl EntrywiseSqrt for M2 {
+    type Output = Self;
+
+    fn entrywise_sqrt(self) -> Self {
+        Self {
+            a: self.a.entrywise_sqrt(),
+            b: self.b.entrywise_sqrt(),
+        }
+    }
+}
+
+/// Entrywise matrix inversion.
+impl EntrywiseInv for M2 {
+    type Output = Self;
+
+    fn entrywise_inv(self) -> Self {
+        Self {
+            a: self.a.entrywise_inv(),
+            b: self.b.entrywise_inv(),
+        }
+    }
+}
+
+/// Entrywise matrix exponentiation.
+impl EntrywiseEX for M2 {
+    type Output = Self;
+
+    fn entrywise_e_x(self) -> Self::Output {
+        Self {
+            a: self.a.entrywise_e_x(),
+            b: self.b.entrywise_e_x(),
+        }
+    }
+}
+
+impl M2 {
+    /// Returns the determinant.
+    pub fn determinant(self) -> f32 {
+        self.a.perp_dot(self.b)
+    }
+
+    /// Matrix inversion; `None` if a matrix is singular, or too close to singular to be inverted in `f32`.
+    ///
+    /// Uses the same scale-independent check as the [`M3::try_inv`].
+    pub fn try_inv(self) -> Option<Self> {
+        let det = self.determinant();
+
+        if det.is_finite() && det.abs() > f32::EPSILON * self.a.magnitude() * self.b.magnitude() {
+            Some(self.inv())
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new 2×2 matrix rotating vectors by an angle counterclockwise.
+    pub fn rotation(θ: f32) -> Self {
+        let (sθ, cθ) = θ.sin_cos();
+
+        #[rustfmt::skip]
+        let rotation = Self {
+            a: V2::new(cθ,  sθ),
+            b: V2::new(-sθ, cθ),
+        };
+
+        rotation
+    }
+}
@@ -35,9 +35,9 @@ impl From<[[f32; 3]; 3]> for M3 {
     }
 }
 
-impl Into<[[f32; 3]; 3]> for M3 {
-    fn into(self) -> [[f32; 3]; 3] {
-        [self.a.into(), self.b.into(), self.c.into()]
+impl From<M3> for [[f32; 3]; 3] {
+    fn from(value: M3) -> Self {
+        [value.a.into(), value.b.into(), value.c.into()]
     }
 }
 
@@ -139,6 +139,7 @@ impl std::ops::MulAssign for M3 {
 }
 
 /// Matrix division by scalar.
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl std::ops::Div<f32> for M3 {
     type Output = Self;
 
@@ -166,8 +167,197 @@ impl Transpose for M3 {
 }
 
 /// Matrix inversion.
+///
+/// Divides by zero for singular matrices; see [`M3::try_inv`].
 impl Inv for M3 {
     fn inv(self) -> Self {
+        let (adjugate, det) = self.adjugate_determinant();
+
+        adjugate / det
+    }
+}
+
+impl M3 {
+    /// Returns the determinant; i.e., the triple product $a \cdot (b \times c)$ of columns.
+    pub fn determinant(self) -> f32 {
+        self.a.dot(self.b * self.c)
+    }
+
+    /// Matrix inversion; `None` if a matrix is singular, or too close to singular to be inverted in `f32`.
+    ///
+    /// A matrix is treated as singular if $|\det M| \le \varepsilon |a| |b| |c|$, where $a$, $b$, and $c$ are columns. The
+    /// right side is the Hadamard bound of the determinant times the machine epsilon, so the check doesn’t depend on scale.
+    pub fn try_inv(self) -> Option<Self> {
+        let (adjugate, det) = self.adjugate_determinant();
+        let bound = self.a.magnitude() * self.b.magnitude() * self.c.magnitude();
+
+        if det.is_finite() && det.abs() > f32::EPSILON * bound {
+            Some(adjugate / det)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an orthonormal matrix built from columns with the modified Gram–Schmidt process.
+    ///
+    /// Keeps the direction of `a`, the plane of `a` and `b`, and the handedness; e.g., removes drift of a rotation matrix
+    /// composed many times. Columns which are linearly dependent on previous ones become zero vectors.
+    pub fn orthonormalize(self) -> Self {
+        let a = self.a.normalize();
+        let b = (self.b - a * a.dot(self.b)).normalize();
+        let c = self.c - a * a.dot(self.c);
+        let c = (c - b * b.dot(c)).normalize();
+
+        Self { a, b, c }
+    }
+
+    /// Polar decomposition $M = R S$, where $R$ is orthogonal, and $S$ is symmetric positive definite; `None` for singular
+    /// matrices.
+    ///
+    /// E.g., splits a deformation gradient into a rotation and a stretch. $R$ is a rotation if $\det M > 0$, and a rotation
+    /// with a reflection otherwise. Uses the scaled Newton iteration $R_{k+1} = \frac{1}{2} (\gamma R_k + \gamma^{-1} R_k^{-T})$.
+    ///
+    /// ```
+    /// use isecad::*;
+    ///
+    /// let distance = |m: M3, n: M3| [m.a - n.a, m.b - n.b, m.c - n.c].iter().map(|d| d.magnitude()).fold(0.0, f32::max);
+    ///
+    /// let rotation = V3::new(0.3, -0.5, 0.8).to_rotation_m3();
+    /// let stretch = M3::from([[2.0, 0.3, -0.1], [0.3, 1.5, 0.2], [-0.1, 0.2, 0.8]]);
+    /// let mirror = M3::from([[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    ///
+    /// for (m, det) in [(rotation * stretch, 1.0), (mirror * rotation * stretch, -1.0)] {
+    ///     let (r, s) = m.polar_decomposition().unwrap();
+    ///
+    ///     assert!(distance(r.transpose() * r, M3::default()) < 1e-6);
+    ///     assert!((r.determinant() - det).abs() < 1e-6);
+    ///     assert!(distance(s, s.transpose()) < 1e-6);
+    ///     assert!(distance(r * s, m) < 1e-5);
+    /// }
+    ///
+    /// assert!(M3::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 1.0]]).polar_decomposition().is_none());
+    /// ```
+    pub fn polar_decomposition(self) -> Option<(Self, Self)> {
+        let mut r = self;
+
+        for _ in 0..32 {
+            let r_inv_t = r.try_inv()?.transpose();
+            let scale = f32::sqrt(r_inv_t.frobenius_norm() / r.frobenius_norm());
+
+            let next = (r * scale + r_inv_t * scale.inv()) * 0.5;
+            let delta = (next - r).frobenius_norm();
+
+            r = next;
+
+            if delta <= 1e-6 * r.frobenius_norm() {
+                break;
+            }
+        }
+
+        let s = r.transpose() * self;
+
+        Some((r, (s + s.transpose()) * 0.5))
+    }
+
+    /// Eigen decomposition of a symmetric matrix $M = V \Lambda V^T$; e.g., principal stresses of a stress tensor and their
+    /// directions.
+    ///
+    /// Returns eigenvalues in ascending order, and a rotation matrix $V$ with unit eigenvectors as columns in the same order.
+    /// Only the symmetric part $\frac{1}{2} (M + M^T)$ of a matrix is used. Uses cyclic Jacobi rotations, which stay accurate
+    /// for close eigenvalues.
+    ///
+    /// ```
+    /// use isecad::*;
+    ///
+    /// let distance = |m: M3, n: M3| [m.a - n.a, m.b - n.b, m.c - n.c].iter().map(|d| d.magnitude()).fold(0.0, f32::max);
+    ///
+    /// let rotation = V3::new(0.3, -0.5, 0.8).to_rotation_m3();
+    /// let diagonal = |x: f32, y: f32, z: f32| M3::from([[x, 0.0, 0.0], [0.0, y, 0.0], [0.0, 0.0, z]]);
+    ///
+    /// for (m, expected) in [
+    ///     (M3::from([[4.0, 1.0, -2.0], [1.0, 3.0, 0.5], [-2.0, 0.5, -1.0]]), None),
+    ///     // A repeated eigenvalue, so any unit vector of a plane is an eigenvector.
+    ///     (rotation * diagonal(2.0, 2.0, 5.0) * rotation.transpose(), Some(V3::new(2.0, 2.0, 5.0))),
+    ///     (M3::default(), Some(V3::new(1.0, 1.0, 1.0))),
+    /// ] {
+    ///     let (values, vectors) = m.symmetric_eigen();
+    ///
+    ///     assert!(values.x <= values.y && values.y <= values.z);
+    ///     assert!(expected.map_or(true, |expected| (values - expected).magnitude() < 1e-5));
+    ///     assert!(distance(vectors.transpose() * vectors, M3::default()) < 1e-6);
+    ///
+    ///     for (value, vector) in [(values.x, vectors.a), (values.y, vectors.b), (values.z, vectors.c)] {
+    ///         assert!((vector * m - vector * value).magnitude() < 1e-6 * value.abs().max(1.0));
+    ///     }
+    /// }
+    /// ```
+    pub fn symmetric_eigen(self) -> (V3, Self) {
+        let s = (self + self.transpose()) * 0.5;
+
+        let mut m: [[f32; 3]; 3] = s.into();
+        let mut v: [[f32; 3]; 3] = Self::default().into();
+
+        let total = s.frobenius_norm() * s.frobenius_norm();
+
+        for _ in 0..16 {
+            let off = m[0][1] * m[0][1] + m[0][2] * m[0][2] + m[1][2] * m[1][2];
+
+            if off <= total * f32::EPSILON * f32::EPSILON {
+                break;
+            }
+
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if m[p][q] == 0.0 {
+                    continue;
+                }
+
+                let θ = (m[q][q] - m[p][p]) / (2.0 * m[p][q]);
+                let t = θ.signum() / (θ.abs() + f32::hypot(θ, 1.0));
+                let c = f32::hypot(t, 1.0).inv();
+                let s = t * c;
+
+                // $M' = J^T M J$ and $V' = V J$, where $J$ is a rotation in the $pq$ plane.
+                for m_k in &mut m {
+                    let (m_kp, m_kq) = (m_k[p], m_k[q]);
+
+                    m_k[p] = c * m_kp - s * m_kq;
+                    m_k[q] = s * m_kp + c * m_kq;
+                }
+
+                for n in [&mut m, &mut v] {
+                    let (n_p, n_q) = (n[p], n[q]);
+
+                    n[p] = [0, 1, 2].map(|k| c * n_p[k] - s * n_q[k]);
+                    n[q] = [0, 1, 2].map(|k| s * n_p[k] + c * n_q[k]);
+                }
+
+                m[p][q] = 0.0;
+                m[q][p] = 0.0;
+            }
+        }
+
+        let mut order = [0, 1, 2];
+
+        order.sort_by(|&i, &j| m[i][i].total_cmp(&m[j][j]));
+
+        let [i, j, k] = order;
+
+        let a = V3::from(v[i]);
+        let b = V3::from(v[j]);
+        let c = V3::from(v[k]);
+
+        // Jacobi rotations keep the handedness, but reordering may flip it.
+        let c = if a.dot(b * c) < 0.0 { -c } else { c };
+
+        (V3::new(m[i][i], m[j][j], m[k][k]), Self { a, b, c })
+    }
+
+    fn frobenius_norm(self) -> f32 {
+        f32::sqrt(self.a.magnitude_proportional() + self.b.magnitude_proportional() + self.c.magnitude_proportional())
+    }
+
+    /// Returns the adjugate matrix and the determinant, which share cofactors.
+    fn adjugate_determinant(self) -> (Self, f32) {
         let Self {
             a: V3 { x: a, y: b, z: c },
             b: V3 { x: d, y: e, z: f },
@@ -188,11 +378,13 @@ impl Inv for M3 {
 
         let det = a * m_a + b * m_b + c * m_c;
 
-        Self {
+        let adjugate = Self {
             a: V3::new(m_a, m_d, m_g),
             b: V3::new(m_b, m_e, m_h),
             c: V3::new(m_c, m_f, m_i),
-        } / det
+        };
+
+        (adjugate, det)
     }
 }
 
@@ -0,0 +1,366 @@
+#![allow(dead_code)]
+
+use crate::*;
+
+/// A 3×3 matrix of `f64` entries; a high-precision counterpart of the [`M3`].
+///
+/// We will use column-major order, so vectors are columns.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct M3d {
+    pub a: V3d,
+    pub b: V3d,
+    pub c: V3d,
+}
+
+impl Default for M3d {
+    fn default() -> Self {
+        Self {
+            a: V3d::new(1.0, 0.0, 0.0),
+            b: V3d::new(0.0, 1.0, 0.0),
+            c: V3d::new(0.0, 0.0, 1.0),
+        }
+    }
+}
+
+impl From<[[f64; 3]; 3]> for M3d {
+    fn from([a, b, c]: [[f64; 3]; 3]) -> Self {
+        Self {
+            a: a.into(),
+            b: b.into(),
+            c: c.into(),
+        }
+    }
+}
+
+impl From<M3d> for [[f64; 3]; 3] {
+    fn from(value: M3d) -> Self {
+        [value.a.into(), value.b.into(), value.c.into()]
+    }
+}
+
+/// Matrix negation.
+impl std::ops::Neg for M3d {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            a: -self.a,
+            b: -self.b,
+            c: -self.c,
+        }
+    }
+}
+
+/// Matrix addition.
+impl std::ops::Add for M3d {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            a: self.a + rhs.a,
+            b: self.b + rhs.b,
+            c: self.c + rhs.c,
+        }
+    }
+}
+
+/// Matrix addition with assignment.
+impl std::ops::AddAssign for M3d {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+/// Matrix subtraction.
+impl std::ops::Sub for M3d {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            a: self.a - rhs.a,
+            b: self.b - rhs.b,
+            c: self.c - rhs.c,
+        }
+    }
+}
+
+/// Matrix subtraction with assignment.
+impl std::ops::SubAssign for M3d {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+/// Matrix multiplication by scalar.
+impl std::ops::Mul<f64> for M3d {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            a: self.a * rhs,
+            b: self.b * rhs,
+            c: self.c * rhs,
+        }
+    }
+}
+
+/// Matrix multiplication by scalar with assignment.
+impl std::ops::MulAssign<f64> for M3d {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs
+    }
+}
+
+/// Matrix multiplication.
+impl std::ops::Mul for M3d {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let x = V3d::new(self.a.x, self.b.x, self.c.x);
+        let y = V3d::new(self.a.y, self.b.y, self.c.y);
+        let z = V3d::new(self.a.z, self.b.z, self.c.z);
+
+        Self {
+            a: V3d::new(rhs.a.dot(x), rhs.a.dot(y), rhs.a.dot(z)),
+            b: V3d::new(rhs.b.dot(x), rhs.b.dot(y), rhs.b.dot(z)),
+            c: V3d::new(rhs.c.dot(x), rhs.c.dot(y), rhs.c.dot(z)),
+        }
+    }
+}
+
+/// Matrix multiplication with assignment.
+impl std::ops::MulAssign for M3d {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+/// Matrix division by scalar.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl std::ops::Div<f64> for M3d {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+/// Matrix division by scalar with assignment.
+impl std::ops::DivAssign<f64> for M3d {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs
+    }
+}
+
+/// Matrix transposition.
+impl Transpose for M3d {
+    fn transpose(&self) -> Self {
+        Self {
+            a: V3d::new(self.a.x, self.b.x, self.c.x),
+            b: V3d::new(self.a.y, self.b.y, self.c.y),
+            c: V3d::new(self.a.z, self.b.z, self.c.z),
+        }
+    }
+}
+
+/// Matrix inversion.
+///
+/// Divides by zero for singular matrices; see [`M3d::try_inv`].
+impl Inv for M3d {
+    fn inv(self) -> Self {
+        let (adjugate, det) = self.adjugate_determinant();
+
+        adjugate / det
+    }
+}
+
+impl M3d {
+    /// Returns the determinant; i.e., the triple product $a \cdot (b \times c)$ of columns.
+    pub fn determinant(self) -> f64 {
+        self.a.dot(self.b * self.c)
+    }
+
+    /// Matrix inversion; `None` if a matrix is singular, or too close to singular to be inverted in `f64`.
+    ///
+    /// Uses the same scale-independent check as the [`M3::try_inv`].
+    pub fn try_inv(self) -> Option<Self> {
+        let (adjugate, det) = self.adjugate_determinant();
+        let bound = self.a.magnitude() * self.b.magnitude() * self.c.magnitude();
+
+        if det.is_finite() && det.abs() > f64::EPSILON * bound {
+            Some(adjugate / det)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the adjugate matrix and the determinant, which share cofactors.
+    fn adjugate_determinant(self) -> (Self, f64) {
+        let Self {
+            a: V3d { x: a, y: b, z: c },
+            b: V3d { x: d, y: e, z: f },
+            c: V3d { x: g, y: h, z: i },
+        } = self;
+
+        let m_a = e * i - h * f;
+        let m_b = f * g - d * i;
+        let m_c = d * h - e * g;
+
+        let m_d = c * h - b * i;
+        let m_e = a * i - c * g;
+        let m_f = b * g - a * h;
+
+        let m_g = b * f - c * e;
+        let m_h = c * d - a * f;
+        let m_i = a * e - b * d;
+
+        let det = a * m_a + b * m_b + c * m_c;
+
+        let adjugate = Self {
+            a: V3d::new(m_a, m_d, m_g),
+            b: V3d::new(m_b, m_e, m_h),
+            c: V3d::new(m_c, m_f, m_i),
+        };
+
+        (adjugate, det)
+    }
+}
+
+/// Entrywise matrix ⋅ scalar addition.
+impl EntrywiseAdd<f64> for M3d {
+    type Output = Self;
+
+    fn entrywise_add(self, rhs: f64) -> Self {
+        Self {
+            a: self.a.entrywise_add(rhs),
+            b: self.b.entrywise_add(rhs),
+            c: self.c.entrywise_add(rhs),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ scalar subtraction.
+impl EntrywiseSub<f64> for M3d {
+    type Output = Self;
+
+    fn entrywise_sub(self, rhs: f64) -> Self {
+        Self {
+            a: self.a.entrywise_sub(rhs),
+            b: self.b.entrywise_sub(rhs),
+            c: self.c.entrywise_sub(rhs),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ matrix multiplication; i.e., Hadamard product.
+impl EntrywiseMul for M3d {
+    type Output = Self;
+
+    fn entrywise_mul(self, rhs: Self) -> Self {
+        Self {
+            a: self.a.entrywise_mul(rhs.a),
+            b: self.b.entrywise_mul(rhs.b),
+            c: self.c.entrywise_mul(rhs.c),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ matrix division.
+impl EntrywiseDiv for M3d {
+    type Output = Self;
+
+    fn entrywise_div(self, rhs: Self) -> Self {
+        Self {
+            a: self.a.entrywise_div(rhs.a),
+            b: self.b.entrywise_div(rhs.b),
+            c: self.c.entrywise_div(rhs.c),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ matrix exponentiation.
+impl EntrywisePow for M3d {
+    type Output = Self;
+
+    fn entrywise_pow(self, rhs: Self) -> Self {
+        Self {
+            a: self.a.entrywise_pow(rhs.a),
+            b: self.b.entrywise_pow(rhs.b),
+            c: self.c.entrywise_pow(rhs.c),
+        }
+    }
+}
+
+/// Entrywise matrix ⋅ scalar exponentiation.
+impl EntrywisePow<f64> for M3d {
+    type Output = Self;
+
+    fn entrywise_pow(self, rhs: f64) -> Self {
+        Self {
+            a: self.a.entrywise_pow(rhs),
+            b: self.b.entrywise_pow(rhs),
+            c: self.c.entrywise_pow(rhs),
+        }
+    }
+}
+
+/// Entrywise matrix square root.
+impl EntrywiseSqrt for M3d {
+    type Output = Self;
+
+    fn entrywise_sqrt(self) -> Self {
+        Self {
+            a: self.a.entrywise_sqrt(),
+            b: self.b.entrywise_sqrt(),
+            c: self.c.entrywise_sqrt(),
+        }
+    }
+}
+
+/// Entrywise matrix inversion.
+impl EntrywiseInv for M3d {
+    type Output = Self;
+
+    fn entrywise_inv(self) -> Self {
+        Self {
+            a: self.a.entrywise_inv(),
+            b: self.b.entrywise_inv(),
+            c: self.c.entrywise_inv(),
+        }
+    }
+}
+
+/// Entrywise matrix exponentiation.
+impl EntrywiseEX for M3d {
+    type Output = Self;
+
+    fn entrywise_e_x(self) -> Self::Output {
+        Self {
+            a: self.a.entrywise_e_x(),
+            b: self.b.entrywise_e_x(),
+            c: self.c.entrywise_e_x(),
+        }
+    }
+}
+
+/// Lossless conversion from an [`M3`].
+impl From<M3> for M3d {
+    fn from(value: M3) -> Self {
+        Self {
+            a: value.a.into(),
+            b: value.b.into(),
+            c: value.c.into(),
+        }
+    }
+}
+
+impl M3d {
+    /// Converts a matrix to an [`M3`], rounding entries to `f32`.
+    pub fn to_m3(self) -> M3 {
+        M3 {
+            a: self.a.to_v3(),
+            b: self.b.to_v3(),
+            c: self.c.to_v3(),
+        }
+    }
+}
@@ -38,9 +38,9 @@ impl From<[[f32; 4]; 4]> for M4 {
     }
 }
 
-impl Into<[[f32; 4]; 4]> for M4 {
-    fn into(self) -> [[f32; 4]; 4] {
-        [self.a.into(), self.b.into(), self.c.into(), self.d.into()]
+impl From<M4> for [[f32; 4]; 4] {
+    fn from(value: M4) -> Self {
+        [value.a.into(), value.b.into(), value.c.into(), value.d.into()]
     }
 }
 
@@ -148,6 +148,7 @@ impl std::ops::MulAssign for M4 {
 }
 
 /// Matrix division by scalar.
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl std::ops::Div<f32> for M4 {
     type Output = Self;
 
@@ -176,8 +177,43 @@ impl Transpose for M4 {
 }
 
 /// Matrix inversion.
+///
+/// Divides by zero for singular matrices; see [`M4::try_inv`].
 impl Inv for M4 {
     fn inv(self) -> Self {
+        let (adjugate, det) = self.adjugate_determinant();
+
+        adjugate / det
+    }
+}
+
+impl M4 {
+    /// Converts a transform matrix to a new translation vector.
+    pub fn to_translation_v3(&self) -> V3 {
+        self.d.truncate()
+    }
+
+    /// Returns the determinant.
+    pub fn determinant(self) -> f32 {
+        self.adjugate_determinant().1
+    }
+
+    /// Matrix inversion; `None` if a matrix is singular, or too close to singular to be inverted in `f32`.
+    ///
+    /// Uses the same scale-independent check as the [`M3::try_inv`], with the Hadamard bound of four columns.
+    pub fn try_inv(self) -> Option<Self> {
+        let (adjugate, det) = self.adjugate_determinant();
+        let bound = self.a.magnitude() * self.b.magnitude() * self.c.magnitude() * self.d.magnitude();
+
+        if det.is_finite() && det.abs() > f32::EPSILON * bound {
+            Some(adjugate / det)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the adjugate matrix and the determinant, which share cofactors.
+    fn adjugate_determinant(self) -> (Self, f32) {
         let Self {
             a: V4 { x: a, y: b, z: c, w: d },
             b: V4 { x: e, y: f, z: g, w: h },
@@ -221,19 +257,14 @@ impl Inv for M4 {
 
         let det = a * m_a + b * m_b + c * m_c + d * m_d;
 
-        Self {
+        let adjugate = Self {
             a: V4::new(m_a, m_e, m_i, m_m),
             b: V4::new(m_b, m_f, m_j, m_n),
             c: V4::new(m_c, m_g, m_k, m_o),
             d: V4::new(m_d, m_h, m_l, m_p),
-        } / det
-    }
-}
+        };
 
-impl M4 {
-    /// Converts a transform matrix to a new translation vector.
-    pub fn to_translation_v3(&self) -> V3 {
-        self.d.truncate()
+        (adjugate, det)
     }
 }
 
@@ -22,14 +22,55 @@ impl Magnitude for f32 {
     }
 }
 
-impl Magnitude for u8 {
-    type Output = u8;
+impl Magnitude for f64 {
+    type Output = f64;
 
     fn magnitude_proportional(self) -> Self::Output {
-        self
+        self.abs()
     }
 
     fn magnitude(self) -> Self::Output {
-        self
+        self.abs()
     }
 }
+
+macro_rules! impl_magnitude_unsigned {
+    ($($integer:ty),*) => {
+        $(
+            impl Magnitude for $integer {
+                type Output = $integer;
+
+                fn magnitude_proportional(self) -> Self::Output {
+                    self
+                }
+
+                fn magnitude(self) -> Self::Output {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_magnitude_unsigned!(u8, u16, u32);
+
+macro_rules! impl_magnitude_signed {
+    ($($integer:ty => $unsigned:ty),*) => {
+        $(
+            /// Absolute value; unsigned, since the absolute value of the minimum doesn’t fit into a signed integer.
+            impl Magnitude for $integer {
+                type Output = $unsigned;
+
+                fn magnitude_proportional(self) -> Self::Output {
+                    self.unsigned_abs()
+                }
+
+                fn magnitude(self) -> Self::Output {
+                    self.unsigned_abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_magnitude_signed!(i16 => u16, i32 => u32);
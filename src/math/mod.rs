@@ -1,3 +1,4 @@
+pub mod average;
 pub mod bounded;
 pub mod div_usize;
 pub mod dot;
@@ -6,7 +7,9 @@ pub mod entrywise_ops;
 pub mod interpolations;
 pub mod inv;
 pub mod layer;
+pub mod m2;
 pub mod m3;
+pub mod m3d;
 pub mod m4;
 pub mod magnitude;
 pub mod normalize;
@@ -14,14 +17,20 @@ pub mod one;
 pub mod partial_eq;
 pub mod partial_ord;
 pub mod power;
+pub mod quat;
 pub mod random;
+#[cfg(feature = "simd")]
+pub mod simd;
 pub mod similarity;
 pub mod square_root;
 pub mod to_numeric;
 pub mod transpose;
+pub mod v2;
 pub mod v3;
+pub mod v3d;
 pub mod v4;
 
+pub use average::*;
 pub use bounded::*;
 pub use div_usize::*;
 pub use dot::*;
@@ -29,7 +38,9 @@ pub use e_x::*;
 pub use entrywise_ops::*;
 pub use inv::*;
 pub use layer::*;
+pub use m2::*;
 pub use m3::*;
+pub use m3d::*;
 pub use m4::*;
 pub use magnitude::*;
 pub use normalize::*;
@@ -37,10 +48,13 @@ pub use one::*;
 pub use partial_eq::*;
 pub use partial_ord::*;
 pub use power::*;
+pub use quat::*;
 pub use random::*;
 pub use similarity::*;
 pub use square_root::*;
 pub use to_numeric::*;
 pub use transpose::*;
+pub use v2::*;
 pub use v3::*;
+pub use v3d::*;
 pub use v4::*;
@@ -8,8 +8,22 @@ impl One for f32 {
     }
 }
 
-impl One for u8 {
+impl One for f64 {
     fn one() -> Self {
-        1
+        1.0
     }
 }
+
+macro_rules! impl_one_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl One for $integer {
+                fn one() -> Self {
+                    1
+                }
+            }
+        )*
+    };
+}
+
+impl_one_integer!(u8, u16, u32, i16, i32);
@@ -19,3 +19,36 @@ impl Power<i32> for f32 {
         self.powi(rhs)
     }
 }
+
+impl Power for f64 {
+    type Output = Self;
+
+    fn power(self, rhs: Self) -> Self::Output {
+        self.powf(rhs)
+    }
+}
+
+impl Power<i32> for f64 {
+    type Output = Self;
+
+    fn power(self, rhs: i32) -> Self::Output {
+        self.powi(rhs)
+    }
+}
+
+macro_rules! impl_power_integer {
+    ($($integer:ty),*) => {
+        $(
+            /// Integer exponentiation; overflows like multiplication does.
+            impl Power<u32> for $integer {
+                type Output = Self;
+
+                fn power(self, rhs: u32) -> Self::Output {
+                    self.pow(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_power_integer!(u8, u16, u32, i16, i32);
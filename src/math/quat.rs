@@ -0,0 +1,315 @@
+#![allow(dead_code)]
+
+use crate::*;
+
+/// A quaternion $w + xi + yj + zk$; unit quaternions represent rotations.
+///
+/// Unlike rotation matrices, unit quaternions may be composed many times with a cheap [`Normalize::normalize`] in between
+/// to stay a rotation, and may be interpolated with [`Quat::slerp`].
+///
+/// Rotations follow the [`V3::to_rotation_m3`] convention: a rotation by $\theta$ around a unit axis $u$ is
+/// $q = (u \sin \frac{\theta}{2}, \cos \frac{\theta}{2})$, and turns vectors counterclockwise when looking against $u$.
+///
+/// Aligned to 16 bytes to match the [`V4`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(align(16))]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+/// The identity rotation.
+impl Default for Quat {
+    fn default() -> Self {
+        Self::new(0.0, 0.0, 0.0, 1.0)
+    }
+}
+
+impl From<[f32; 4]> for Quat {
+    fn from([x, y, z, w]: [f32; 4]) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+impl From<Quat> for [f32; 4] {
+    fn from(value: Quat) -> Self {
+        [value.x, value.y, value.z, value.w]
+    }
+}
+
+/// Quaternion negation; represents the same rotation.
+impl std::ops::Neg for Quat {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+/// Quaternion addition.
+impl std::ops::Add for Quat {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z, self.w + rhs.w)
+    }
+}
+
+/// Quaternion subtraction.
+impl std::ops::Sub for Quat {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z, self.w - rhs.w)
+    }
+}
+
+/// Quaternion multiplication by scalar.
+impl std::ops::Mul<f32> for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+/// Hamilton product; `a * b` rotates by `b` first, then by `a`.
+impl std::ops::Mul for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let Self { x: x1, y: y1, z: z1, w: w1 } = self;
+        let Self { x: x2, y: y2, z: z2, w: w2 } = rhs;
+
+        Self {
+            x: w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+            y: w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+            z: w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+            w: w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+        }
+    }
+}
+
+/// Hamilton product with assignment.
+impl std::ops::MulAssign for Quat {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Vector rotation by a unit quaternion.
+impl std::ops::Mul<Quat> for V3 {
+    type Output = Self;
+
+    /// $v' = v + 2w (u \times v) + 2 u \times (u \times v)$, where $u = (x, y, z)$.
+    fn mul(self, rhs: Quat) -> Self::Output {
+        let u = V3::new(rhs.x, rhs.y, rhs.z);
+        let t = (u * self) * 2.0;
+
+        self + t * rhs.w + u * t
+    }
+}
+
+/// Vector rotation by a unit quaternion with assignment.
+impl std::ops::MulAssign<Quat> for V3 {
+    fn mul_assign(&mut self, rhs: Quat) {
+        *self = *self * rhs;
+    }
+}
+
+/// Quaternion magnitude.
+impl Magnitude for Quat {
+    type Output = f32;
+
+    fn magnitude_proportional(self) -> Self::Output {
+        self.dot(self)
+    }
+
+    fn magnitude(self) -> Self::Output {
+        f32::sqrt(self.magnitude_proportional())
+    }
+}
+
+/// Quaternion normalization; renormalizes a composed rotation to a unit quaternion.
+impl Normalize for Quat {
+    fn normalize(self) -> Self {
+        let mag = self.magnitude();
+
+        if mag == 0.0 {
+            Self::default()
+        } else {
+            self * mag.inv()
+        }
+    }
+}
+
+/// Dot product; cosine of a half of the angle between two unit quaternions.
+impl Dot for Quat {
+    type Output = f32;
+
+    fn dot(self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+}
+
+impl Quat {
+    /// Creates a new [`Quat`] using the provided values.
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a rotation by `angle` radians around an axis; the axis doesn’t have to be a unit vector.
+    pub fn from_axis_angle(axis: V3, angle: f32) -> Self {
+        let axis = axis.normalize();
+        let (s, c) = (angle * 0.5).sin_cos();
+
+        Self::new(axis.x * s, axis.y * s, axis.z * s, c)
+    }
+
+    /// Creates a rotation from a rotation vector; the same rotation as [`V3::to_rotation_m3`].
+    pub fn from_rotation_vector(vector: V3) -> Self {
+        Self::from_axis_angle(vector, vector.magnitude())
+    }
+
+    /// Creates a rotation from a rotation matrix.
+    ///
+    /// Uses Shepperd’s method, branching on the largest diagonal term, so it’s stable for any angle. The matrix should be
+    /// orthonormal; the result is normalized anyway.
+    ///
+    /// ```
+    /// use isecad::*;
+    /// use std::f32::consts::PI;
+    ///
+    /// // Near π, each axis hits a different largest diagonal term.
+    /// for axis in [V3::new(1.0, -2.0, 3.0), V3::new(3.0, 1.0, -2.0), V3::new(-2.0, 3.0, 1.0)] {
+    ///     let axis = axis.normalize();
+    ///
+    ///     for &angle in &[1e-3, 0.1, 1.0, PI / 2.0, 3.0, PI - 1e-3, PI] {
+    ///         let m = (axis * angle).to_rotation_m3();
+    ///         let q = Quat::from_m3(m);
+    ///
+    ///         // The same rotation, as `q` and `-q` are.
+    ///         assert!(q.dot(Quat::from_axis_angle(axis, angle)).abs() > 1.0 - 1e-6, "{:?} {}", axis, angle);
+    ///
+    ///         let n = q.to_m3();
+    ///
+    ///         for (m, n) in [(m.a, n.a), (m.b, n.b), (m.c, n.c)] {
+    ///             assert!((m - n).magnitude() < 1e-5, "{:?} {}", axis, angle);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn from_m3(m: M3) -> Self {
+        // Entries are named row-column; columns are `a`, `b`, and `c`.
+        let (m00, m10, m20) = (m.a.x, m.a.y, m.a.z);
+        let (m01, m11, m21) = (m.b.x, m.b.y, m.b.z);
+        let (m02, m12, m22) = (m.c.x, m.c.y, m.c.z);
+
+        let trace = m00 + m11 + m22;
+
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+
+            Self::new((m21 - m12) / s, (m02 - m20) / s, (m10 - m01) / s, s * 0.25)
+        } else if m00 > m11 && m00 > m22 {
+            let s = (1.0 + m00 - m11 - m22).sqrt() * 2.0;
+
+            Self::new(s * 0.25, (m01 + m10) / s, (m02 + m20) / s, (m21 - m12) / s)
+        } else if m11 > m22 {
+            let s = (1.0 + m11 - m00 - m22).sqrt() * 2.0;
+
+            Self::new((m01 + m10) / s, s * 0.25, (m12 + m21) / s, (m02 - m20) / s)
+        } else {
+            let s = (1.0 + m22 - m00 - m11).sqrt() * 2.0;
+
+            Self::new((m02 + m20) / s, (m12 + m21) / s, s * 0.25, (m10 - m01) / s)
+        };
+
+        q.normalize()
+    }
+
+    /// Returns the conjugate; the inverse rotation for unit quaternions.
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Rotates a vector; same as `vector * self`.
+    pub fn rotate(self, vector: V3) -> V3 {
+        vector * self
+    }
+
+    /// Spherical linear interpolation between unit quaternions, along the shortest path.
+    ///
+    /// Returns `self` if `t` is 0, and `other` or `-other` if `t` is 1. Falls back to normalized linear interpolation for
+    /// nearly equal rotations, where the spherical one is numerically unstable.
+    ///
+    /// ```
+    /// use isecad::*;
+    /// use std::f32::consts::PI;
+    ///
+    /// let axis = V3::new(0.0, 0.0, 1.0);
+    /// let (a, b) = (Quat::from_axis_angle(axis, 0.0), Quat::from_axis_angle(axis, PI / 2.0));
+    ///
+    /// assert!(a.slerp(b, 0.0).dot(a) > 1.0 - 1e-6);
+    /// assert!(a.slerp(b, 1.0).dot(b) > 1.0 - 1e-6);
+    ///
+    /// // Rotation angles are interpolated at a constant rate, and along the shortest path even if `other` is negated.
+    /// for &t in &[0.25, 0.5, 0.75] {
+    ///     let expected = Quat::from_axis_angle(axis, t * PI / 2.0);
+    ///
+    ///     assert!(a.slerp(b, t).dot(expected) > 1.0 - 1e-6);
+    ///     assert!(a.slerp(-b, t).dot(expected) > 1.0 - 1e-6);
+    ///     assert!((a.slerp(b, t).magnitude() - 1.0).abs() < 1e-6);
+    /// }
+    /// ```
+    pub fn slerp(self, other: Self, t: f32) -> Self {
+        let mut cos_ω = self.dot(other);
+        let mut other = other;
+
+        if cos_ω < 0.0 {
+            cos_ω = -cos_ω;
+            other = -other;
+        }
+
+        if cos_ω > 0.9995 {
+            return (self + (other - self) * t).normalize();
+        }
+
+        let ω = cos_ω.acos();
+        let sin_ω = ω.sin();
+
+        self * (((1.0 - t) * ω).sin() / sin_ω) + other * ((t * ω).sin() / sin_ω)
+    }
+
+    /// Converts a unit quaternion to a new 3×3 rotation matrix.
+    pub fn to_m3(self) -> M3 {
+        let Self { x, y, z, w } = self;
+
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+
+        #[rustfmt::skip]
+        let rotation = M3 {
+            a: V3::new(1.0 - 2.0 * (yy + zz), 2.0 * (xy + wz),       2.0 * (xz - wy)),
+            b: V3::new(2.0 * (xy - wz),       1.0 - 2.0 * (xx + zz), 2.0 * (yz + wx)),
+            c: V3::new(2.0 * (xz + wy),       2.0 * (yz - wx),       1.0 - 2.0 * (xx + yy)),
+        };
+
+        rotation
+    }
+
+    /// Converts a unit quaternion to a new 4×4 rotation matrix.
+    pub fn to_m4(self) -> M4 {
+        let M3 { a, b, c } = self.to_m3();
+
+        M4 {
+            a: a.extend(0.0),
+            b: b.extend(0.0),
+            c: c.extend(0.0),
+            d: V4::new(0.0, 0.0, 0.0, 1.0),
+        }
+    }
+}
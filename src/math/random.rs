@@ -10,7 +10,6 @@ pub const N: usize = 624;
 const NM1: usize = N - 1;
 const M: usize = 397;
 const MM1: usize = M - 1;
-const MMN: usize = ((M as isize) - (N as isize)) as usize;
 const NMM: usize = N - M;
 const U: Wrapping<u32> = Wrapping(2147483648);
 const L: Wrapping<u32> = Wrapping(U.0 - 1);
@@ -127,7 +126,7 @@ impl Random {
             for j in NMM..NM1 {
                 let a: Wrapping<u32> = (self.mt[j] & U) | (self.mt[j + 1] & L);
 
-                self.mt[j] = self.mt[j + MMN] ^ (a >> 1) ^ F[(a.0 & 1) as usize];
+                self.mt[j] = self.mt[j - NMM] ^ (a >> 1) ^ F[(a.0 & 1) as usize];
             }
 
             let a: Wrapping<u32> = (self.mt[NM1] & U) | (self.mt[0] & L);
@@ -0,0 +1,485 @@
+//! SIMD backend of vector types.
+//!
+//! With the `simd` feature enabled, hot [`V3`] and [`V4`] operations (addition, subtraction, multiplication by scalar, dot
+//! product, magnitude, normalization, and [`M3`]/[`M4`] transforms) are evaluated on 4-lane vectors, so generic layer
+//! operations like [`Layer::add_layer`] or [`Layer::to_magnitudes`] use SIMD item by item. Layer kernels in the
+//! [`vector_kernels`] module go further: they load aligned items straight into registers, and process blocks of 4 items at
+//! once. Both use:
+//!
+//! -   WASM SIMD128 (`core::arch::wasm32`) when compiling with the `simd128` target feature, e.g., with
+//!     `RUSTFLAGS="-C target-feature=+simd128"`;
+//! -   SSE (`core::arch::x86_64` or `core::arch::x86`) on native x86 targets;
+//! -   plain arrays elsewhere.
+//!
+//! Every lane performs exactly the same IEEE 754 operations in exactly the same order as the scalar implementations do, and
+//! horizontal sums are evaluated from left to right, so results are bit-identical with and without the feature.
+
+use crate::*;
+
+/// 4 lanes of `f32`.
+#[derive(Clone, Copy, Debug)]
+pub struct F32x4(imp::Repr);
+
+impl F32x4 {
+    /// Creates new vector from lane values.
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self(imp::new(x, y, z, w))
+    }
+
+    /// Creates new vector with all lanes equal to a value.
+    #[inline(always)]
+    pub fn splat(value: f32) -> Self {
+        Self(imp::splat(value))
+    }
+
+    /// Returns lane values.
+    #[inline(always)]
+    pub fn to_array(self) -> [f32; 4] {
+        imp::to_array(self.0)
+    }
+
+    /// Loads a vector from its place in memory; the last lane is zero.
+    ///
+    /// The padding of a [`V3`] is uninitialized, so only its first 12 bytes are loaded.
+    #[inline(always)]
+    pub fn load_v3(value: &V3) -> Self {
+        Self(imp::load_v3(value))
+    }
+
+    /// Loads a vector from its place in memory with a single aligned load.
+    #[inline(always)]
+    pub fn load_v4(value: &V4) -> Self {
+        Self(imp::load_v4(value))
+    }
+
+    /// Stores the first 3 lanes into a vector with a single aligned store; the last lane overwrites its padding.
+    #[inline(always)]
+    pub fn store_v3(self, output: &mut V3) {
+        imp::store_v3(self.0, output)
+    }
+
+    /// Stores lanes into a vector with a single aligned store.
+    #[inline(always)]
+    pub fn store_v4(self, output: &mut V4) {
+        imp::store_v4(self.0, output)
+    }
+
+    /// Lanewise square root.
+    #[inline(always)]
+    pub fn sqrt(self) -> Self {
+        Self(imp::sqrt(self.0))
+    }
+
+    /// Replaces lanes equal to zero with a value.
+    #[inline(always)]
+    pub fn replace_zeros(self, value: f32) -> Self {
+        Self(imp::replace_zeros(self.0, imp::splat(value)))
+    }
+
+    /// Transposes a 4×4 matrix of lanes; e.g., turns 4 vectors into their $x$, $y$, $z$, and $w$ components.
+    #[inline(always)]
+    pub fn transpose([a, b, c, d]: [Self; 4]) -> [Self; 4] {
+        let [a, b, c, d] = imp::transpose(a.0, b.0, c.0, d.0);
+
+        [Self(a), Self(b), Self(c), Self(d)]
+    }
+
+    /// $x + y + z$
+    ///
+    /// Sums the first 3 lanes from left to right.
+    #[inline(always)]
+    pub fn sum3(self) -> f32 {
+        let [x, y, z, _] = self.to_array();
+
+        x + y + z
+    }
+
+    /// $x + y + z + w$
+    ///
+    /// Sums all lanes from left to right.
+    #[inline(always)]
+    pub fn sum4(self) -> f32 {
+        let [x, y, z, w] = self.to_array();
+
+        x + y + z + w
+    }
+}
+
+/// Lanewise addition.
+impl std::ops::Add for F32x4 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(imp::add(self.0, rhs.0))
+    }
+}
+
+/// Lanewise subtraction.
+impl std::ops::Sub for F32x4 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(imp::sub(self.0, rhs.0))
+    }
+}
+
+/// Lanewise multiplication.
+impl std::ops::Mul for F32x4 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(imp::mul(self.0, rhs.0))
+    }
+}
+
+/// Lanewise division.
+impl std::ops::Div for F32x4 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(imp::div(self.0, rhs.0))
+    }
+}
+
+/// Lanewise multiplication by scalar.
+impl std::ops::Mul<f32> for F32x4 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * Self::splat(rhs)
+    }
+}
+
+/// The last lane is zero.
+impl From<V3> for F32x4 {
+    #[inline(always)]
+    fn from(value: V3) -> Self {
+        Self::new(value.x, value.y, value.z, 0.0)
+    }
+}
+
+/// The last lane is dropped.
+impl From<F32x4> for V3 {
+    #[inline(always)]
+    fn from(value: F32x4) -> Self {
+        let [x, y, z, _] = value.to_array();
+
+        V3::new(x, y, z)
+    }
+}
+
+impl From<V4> for F32x4 {
+    #[inline(always)]
+    fn from(value: V4) -> Self {
+        Self::new(value.x, value.y, value.z, value.w)
+    }
+}
+
+impl From<F32x4> for V4 {
+    #[inline(always)]
+    fn from(value: F32x4) -> Self {
+        let [x, y, z, w] = value.to_array();
+
+        V4::new(x, y, z, w)
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod imp {
+    use crate::{V3, V4};
+    use core::arch::wasm32::*;
+
+    pub type Repr = v128;
+
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Repr {
+        f32x4(x, y, z, w)
+    }
+
+    #[inline(always)]
+    pub fn splat(value: f32) -> Repr {
+        f32x4_splat(value)
+    }
+
+    #[inline(always)]
+    pub fn to_array(a: Repr) -> [f32; 4] {
+        [
+            f32x4_extract_lane::<0>(a),
+            f32x4_extract_lane::<1>(a),
+            f32x4_extract_lane::<2>(a),
+            f32x4_extract_lane::<3>(a),
+        ]
+    }
+
+    #[inline(always)]
+    pub fn add(a: Repr, b: Repr) -> Repr {
+        f32x4_add(a, b)
+    }
+
+    #[inline(always)]
+    pub fn sub(a: Repr, b: Repr) -> Repr {
+        f32x4_sub(a, b)
+    }
+
+    #[inline(always)]
+    pub fn mul(a: Repr, b: Repr) -> Repr {
+        f32x4_mul(a, b)
+    }
+
+    #[inline(always)]
+    pub fn div(a: Repr, b: Repr) -> Repr {
+        f32x4_div(a, b)
+    }
+
+    #[inline(always)]
+    pub fn sqrt(a: Repr) -> Repr {
+        f32x4_sqrt(a)
+    }
+
+    #[inline(always)]
+    pub fn replace_zeros(a: Repr, value: Repr) -> Repr {
+        v128_bitselect(value, a, f32x4_eq(a, f32x4_splat(0.0)))
+    }
+
+    #[inline(always)]
+    pub fn load_v3(value: &V3) -> Repr {
+        let pointer = value as *const V3 as *const f32;
+
+        // Both loads are within the first 12 bytes of a 16-byte aligned vector.
+        unsafe { v128_load32_lane::<2>(v128_load64_zero(pointer as *const u64), pointer.add(2) as *const u32) }
+    }
+
+    #[inline(always)]
+    pub fn load_v4(value: &V4) -> Repr {
+        // Vectors are 16 bytes long and aligned to 16 bytes.
+        unsafe { v128_load(value as *const V4 as *const v128) }
+    }
+
+    #[inline(always)]
+    pub fn store_v3(a: Repr, output: &mut V3) {
+        // Vectors are 16 bytes long including padding, and aligned to 16 bytes.
+        unsafe { v128_store(output as *mut V3 as *mut v128, a) }
+    }
+
+    #[inline(always)]
+    pub fn store_v4(a: Repr, output: &mut V4) {
+        unsafe { v128_store(output as *mut V4 as *mut v128, a) }
+    }
+
+    #[inline(always)]
+    pub fn transpose(a: Repr, b: Repr, c: Repr, d: Repr) -> [Repr; 4] {
+        let ab_xy = i32x4_shuffle::<0, 4, 1, 5>(a, b);
+        let cd_xy = i32x4_shuffle::<0, 4, 1, 5>(c, d);
+        let ab_zw = i32x4_shuffle::<2, 6, 3, 7>(a, b);
+        let cd_zw = i32x4_shuffle::<2, 6, 3, 7>(c, d);
+
+        [
+            i32x4_shuffle::<0, 1, 4, 5>(ab_xy, cd_xy),
+            i32x4_shuffle::<2, 3, 6, 7>(ab_xy, cd_xy),
+            i32x4_shuffle::<0, 1, 4, 5>(ab_zw, cd_zw),
+            i32x4_shuffle::<2, 3, 6, 7>(ab_zw, cd_zw),
+        ]
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse")))]
+mod imp {
+    //! SSE intrinsics are only unsafe because of their `#[target_feature]` attribute. SSE is a part of the x86_64 baseline,
+    //! and it’s checked at compile time on x86, so calling them is always safe here.
+
+    use crate::{V3, V4};
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    pub type Repr = __m128;
+
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Repr {
+        unsafe { _mm_set_ps(w, z, y, x) }
+    }
+
+    #[inline(always)]
+    pub fn splat(value: f32) -> Repr {
+        unsafe { _mm_set1_ps(value) }
+    }
+
+    #[inline(always)]
+    pub fn to_array(a: Repr) -> [f32; 4] {
+        let mut array = [0.0; 4];
+
+        // The array is exactly 16 bytes long, and the unaligned store doesn’t require any alignment.
+        unsafe { _mm_storeu_ps(array.as_mut_ptr(), a) };
+
+        array
+    }
+
+    #[inline(always)]
+    pub fn add(a: Repr, b: Repr) -> Repr {
+        unsafe { _mm_add_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn sub(a: Repr, b: Repr) -> Repr {
+        unsafe { _mm_sub_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn mul(a: Repr, b: Repr) -> Repr {
+        unsafe { _mm_mul_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn div(a: Repr, b: Repr) -> Repr {
+        unsafe { _mm_div_ps(a, b) }
+    }
+
+    #[inline(always)]
+    pub fn sqrt(a: Repr) -> Repr {
+        unsafe { _mm_sqrt_ps(a) }
+    }
+
+    #[inline(always)]
+    pub fn replace_zeros(a: Repr, value: Repr) -> Repr {
+        unsafe {
+            let zeros = _mm_cmpeq_ps(a, _mm_setzero_ps());
+
+            _mm_or_ps(_mm_and_ps(zeros, value), _mm_andnot_ps(zeros, a))
+        }
+    }
+
+    #[inline(always)]
+    pub fn load_v3(value: &V3) -> Repr {
+        let pointer = value as *const V3 as *const f32;
+
+        // Both loads are within the first 12 bytes of a 16-byte aligned vector.
+        unsafe { _mm_movelh_ps(_mm_castpd_ps(_mm_load_sd(pointer as *const f64)), _mm_load_ss(pointer.add(2))) }
+    }
+
+    #[inline(always)]
+    pub fn load_v4(value: &V4) -> Repr {
+        // Vectors are 16 bytes long and aligned to 16 bytes.
+        unsafe { _mm_load_ps(value as *const V4 as *const f32) }
+    }
+
+    #[inline(always)]
+    pub fn store_v3(a: Repr, output: &mut V3) {
+        // Vectors are 16 bytes long including padding, and aligned to 16 bytes.
+        unsafe { _mm_store_ps(output as *mut V3 as *mut f32, a) }
+    }
+
+    #[inline(always)]
+    pub fn store_v4(a: Repr, output: &mut V4) {
+        unsafe { _mm_store_ps(output as *mut V4 as *mut f32, a) }
+    }
+
+    #[inline(always)]
+    pub fn transpose(a: Repr, b: Repr, c: Repr, d: Repr) -> [Repr; 4] {
+        unsafe {
+            let ab_xy = _mm_unpacklo_ps(a, b);
+            let cd_xy = _mm_unpacklo_ps(c, d);
+            let ab_zw = _mm_unpackhi_ps(a, b);
+            let cd_zw = _mm_unpackhi_ps(c, d);
+
+            [
+                _mm_movelh_ps(ab_xy, cd_xy),
+                _mm_movehl_ps(cd_xy, ab_xy),
+                _mm_movelh_ps(ab_zw, cd_zw),
+                _mm_movehl_ps(cd_zw, ab_zw),
+            ]
+        }
+    }
+}
+
+#[cfg(not(any(
+    all(target_arch = "wasm32", target_feature = "simd128"),
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse"),
+)))]
+mod imp {
+    use crate::{V3, V4};
+
+    pub type Repr = [f32; 4];
+
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Repr {
+        [x, y, z, w]
+    }
+
+    #[inline(always)]
+    pub fn splat(value: f32) -> Repr {
+        [value; 4]
+    }
+
+    #[inline(always)]
+    pub fn to_array(a: Repr) -> [f32; 4] {
+        a
+    }
+
+    #[inline(always)]
+    pub fn add([a0, a1, a2, a3]: Repr, [b0, b1, b2, b3]: Repr) -> Repr {
+        [a0 + b0, a1 + b1, a2 + b2, a3 + b3]
+    }
+
+    #[inline(always)]
+    pub fn sub([a0, a1, a2, a3]: Repr, [b0, b1, b2, b3]: Repr) -> Repr {
+        [a0 - b0, a1 - b1, a2 - b2, a3 - b3]
+    }
+
+    #[inline(always)]
+    pub fn mul([a0, a1, a2, a3]: Repr, [b0, b1, b2, b3]: Repr) -> Repr {
+        [a0 * b0, a1 * b1, a2 * b2, a3 * b3]
+    }
+
+    #[inline(always)]
+    pub fn div([a0, a1, a2, a3]: Repr, [b0, b1, b2, b3]: Repr) -> Repr {
+        [a0 / b0, a1 / b1, a2 / b2, a3 / b3]
+    }
+
+    #[inline(always)]
+    pub fn sqrt(a: Repr) -> Repr {
+        a.map(f32::sqrt)
+    }
+
+    #[inline(always)]
+    pub fn replace_zeros(a: Repr, [value, _, _, _]: Repr) -> Repr {
+        a.map(|a| if a == 0.0 { value } else { a })
+    }
+
+    #[inline(always)]
+    pub fn load_v3(value: &V3) -> Repr {
+        [value.x, value.y, value.z, 0.0]
+    }
+
+    #[inline(always)]
+    pub fn load_v4(value: &V4) -> Repr {
+        [value.x, value.y, value.z, value.w]
+    }
+
+    #[inline(always)]
+    pub fn store_v3([x, y, z, _]: Repr, output: &mut V3) {
+        *output = V3::new(x, y, z);
+    }
+
+    #[inline(always)]
+    pub fn store_v4([x, y, z, w]: Repr, output: &mut V4) {
+        *output = V4::new(x, y, z, w);
+    }
+
+    #[inline(always)]
+    pub fn transpose(a: Repr, b: Repr, c: Repr, d: Repr) -> [Repr; 4] {
+        [
+            [a[0], b[0], c[0], d[0]],
+            [a[1], b[1], c[1], d[1]],
+            [a[2], b[2], c[2], d[2]],
+            [a[3], b[3], c[3], d[3]],
+        ]
+    }
+}
@@ -7,3 +7,9 @@ impl SquareRoot for f32 {
         self.sqrt()
     }
 }
+
+impl SquareRoot for f64 {
+    fn square_root(self) -> Self {
+        self.sqrt()
+    }
+}
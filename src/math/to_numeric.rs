@@ -3,22 +3,20 @@ pub trait ToNumeric<T> {
     fn into_numeric(self) -> T;
 }
 
-impl ToNumeric<Self> for f32 {
-    fn into_numeric_proportional(self) -> Self {
-        self
-    }
+macro_rules! impl_to_numeric_scalar {
+    ($($scalar:ty),*) => {
+        $(
+            impl ToNumeric<Self> for $scalar {
+                fn into_numeric_proportional(self) -> Self {
+                    self
+                }
 
-    fn into_numeric(self) -> Self {
-        self
-    }
+                fn into_numeric(self) -> Self {
+                    self
+                }
+            }
+        )*
+    };
 }
 
-impl ToNumeric<Self> for u8 {
-    fn into_numeric_proportional(self) -> Self {
-        self
-    }
-
-    fn into_numeric(self) -> Self {
-        self
-    }
-}
+impl_to_numeric_scalar!(f32, f64, u8, u16, u32, i16, i32);
@@ -0,0 +1,363 @@
+#![allow(dead_code)]
+
+use crate::*;
+
+/// A 2D vector; e.g., texture coordinates, a latitude and a longitude, or components in a tangent plane.
+///
+/// Aligned to 8 bytes, so a pair of components may be loaded at once.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[repr(align(8))]
+pub struct V2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl From<[f32; 2]> for V2 {
+    fn from([x, y]: [f32; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<V2> for [f32; 2] {
+    fn from(value: V2) -> Self {
+        [value.x, value.y]
+    }
+}
+
+impl Bounded for V2 {
+    const MIN_BOUND: V2 = V2::new(0.0, 0.0);
+    const MAX_BOUND: V2 = V2::new(f32::MAX_BOUND, f32::MAX_BOUND);
+}
+
+impl One for V2 {
+    fn one() -> Self {
+        Self {
+            x: 1.0 / f32::sqrt(2.0),
+            y: 1.0 / f32::sqrt(2.0),
+        }
+    }
+}
+
+/// Vector negation.
+impl std::ops::Neg for V2 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { x: -self.x, y: -self.y }
+    }
+}
+
+/// Vector addition.
+impl std::ops::Add for V2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+/// Vector addition with assignment.
+impl std::ops::AddAssign for V2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Vector ⋅ scalar addition.
+///
+/// Like the [`V3`] ⋅ scalar addition, changes vector magnitude by a scalar.
+///
+/// $(0, 0) + a = (\frac{a}{\sqrt 2}, \frac{a}{\sqrt 2})$
+///
+/// $(x, y) + a = (\frac{x (l + a)}{l}, \frac{y (l + a)}{l})$, where $l = |(x, y)|$.
+impl std::ops::Add<f32> for V2 {
+    type Output = Self;
+
+    fn add(self, rhs: f32) -> Self::Output {
+        let mag = self.magnitude();
+
+        if mag == 0.0 {
+            Self {
+                x: rhs * std::f32::consts::FRAC_1_SQRT_2,
+                y: rhs * std::f32::consts::FRAC_1_SQRT_2,
+            }
+        } else {
+            self * (mag + rhs) / mag
+        }
+    }
+}
+
+/// Vector ⋅ scalar addition with assignment.
+impl std::ops::AddAssign<f32> for V2 {
+    fn add_assign(&mut self, rhs: f32) {
+        *self = *self + rhs;
+    }
+}
+
+/// Vector subtraction.
+impl std::ops::Sub for V2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+/// Vector subtraction with assignment.
+impl std::ops::SubAssign for V2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Vector ⋅ scalar subtraction.
+impl std::ops::Sub<f32> for V2 {
+    type Output = Self;
+
+    fn sub(self, rhs: f32) -> Self::Output {
+        self + -rhs
+    }
+}
+
+/// Vector ⋅ scalar subtraction with assignment.
+impl std::ops::SubAssign<f32> for V2 {
+    fn sub_assign(&mut self, rhs: f32) {
+        *self = *self - rhs;
+    }
+}
+
+/// Vector multiplication by scalar.
+impl std::ops::Mul<f32> for V2 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+/// Vector multiplication by scalar with assignment.
+impl std::ops::MulAssign<f32> for V2 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+/// Vector multiplication by M2.
+impl std::ops::Mul<M2> for V2 {
+    type Output = Self;
+
+    fn mul(self, rhs: M2) -> Self::Output {
+        let Self { x, y } = self;
+
+        Self {
+            x: rhs.a.x * x + rhs.b.x * y,
+            y: rhs.a.y * x + rhs.b.y * y,
+        }
+    }
+}
+
+/// Vector multiplication by M2 with assignment.
+impl std::ops::MulAssign<M2> for V2 {
+    fn mul_assign(&mut self, rhs: M2) {
+        *self = *self * rhs;
+    }
+}
+
+/// Vector division by scalar.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl std::ops::Div<f32> for V2 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+/// Vector division by scalar with assignment.
+impl std::ops::DivAssign<f32> for V2 {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+/// Vector comparison by magnitude.
+impl std::cmp::PartialOrd for V2 {
+    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
+        self.magnitude_proportional().partial_cmp(&rhs.magnitude_proportional())
+    }
+}
+
+/// Vector magnitude.
+impl Magnitude for V2 {
+    type Output = f32;
+
+    fn magnitude_proportional(self) -> Self::Output {
+        self.x * self.x + self.y * self.y
+    }
+
+    fn magnitude(self) -> Self::Output {
+        f32::sqrt(self.magnitude_proportional())
+    }
+}
+
+/// Numeric value of vector.
+impl ToNumeric<f32> for V2 {
+    fn into_numeric_proportional(self) -> f32 {
+        self.magnitude_proportional()
+    }
+
+    fn into_numeric(self) -> f32 {
+        self.magnitude()
+    }
+}
+
+/// Vector normalization.
+impl Normalize for V2 {
+    fn normalize(self) -> Self {
+        let mag = self.magnitude();
+
+        self / if mag == 0.0 { 1.0 } else { mag }
+    }
+}
+
+/// Vector division by `usize`.
+impl DivUsize for V2 {
+    fn div_usize(self, rhs: usize) -> Self {
+        self / rhs as f32
+    }
+}
+
+/// Dot product.
+impl Dot for V2 {
+    type Output = f32;
+
+    fn dot(self, rhs: Self) -> f32 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+}
+
+/// Ochiai measure for two vectors.
+impl Similarity for V2 {
+    type Output = f32;
+
+    fn similarity(self, other: Self) -> Self::Output {
+        self.dot(other) / f32::sqrt(self.magnitude() * other.magnitude())
+    }
+}
+
+/// Entrywise vector ⋅ scalar addition.
+impl EntrywiseAdd<f32> for V2 {
+    type Output = Self;
+
+    fn entrywise_add(self, rhs: f32) -> Self {
+        Self::new(self.x + rhs, self.y + rhs)
+    }
+}
+
+/// Entrywise vector ⋅ scalar subtraction.
+impl EntrywiseSub<f32> for V2 {
+    type Output = Self;
+
+    fn entrywise_sub(self, rhs: f32) -> Self {
+        Self::new(self.x - rhs, self.y - rhs)
+    }
+}
+
+/// Entrywise vector ⋅ vector multiplication; i.e., Hadamard product.
+impl EntrywiseMul for V2 {
+    type Output = Self;
+
+    fn entrywise_mul(self, rhs: Self) -> Self {
+        Self::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+/// Entrywise vector ⋅ vector division.
+impl EntrywiseDiv for V2 {
+    type Output = Self;
+
+    fn entrywise_div(self, rhs: Self) -> Self {
+        Self::new(self.x / rhs.x, self.y / rhs.y)
+    }
+}
+
+/// Entrywise vector ⋅ vector exponentiation.
+impl EntrywisePow for V2 {
+    type Output = Self;
+
+    fn entrywise_pow(self, rhs: Self) -> Self {
+        Self::new(self.x.power(rhs.x), self.y.power(rhs.y))
+    }
+}
+
+/// Entrywise vector ⋅ scalar exponentiation.
+impl EntrywisePow<f32> for V2 {
+    type Output = Self;
+
+    fn entrywise_pow(self, rhs: f32) -> Self {
+        Self::new(self.x.power(rhs), self.y.power(rhs))
+    }
+}
+
+/// Entrywise vector square root.
+impl EntrywiseSqrt for V2 {
+    type Output = Self;
+
+    fn entrywise_sqrt(self) -> Self {
+        Self::new(self.x.sqrt(), self.y.sqrt())
+    }
+}
+
+/// Entrywise vector inversion.
+impl EntrywiseInv for V2 {
+    type Output = Self;
+
+    fn entrywise_inv(self) -> Self {
+        Self::new(self.x.inv(), self.y.inv())
+    }
+}
+
+/// Entrywise vector exponentiation.
+impl EntrywiseEX for V2 {
+    type Output = Self;
+
+    fn entrywise_e_x(self) -> Self::Output {
+        Self {
+            x: self.x.e_x(),
+            y: self.y.e_x(),
+        }
+    }
+}
+
+impl V2 {
+    /// Creates a new [`V2`] using the provided values.
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Create a [`V3`], using the `x` and `y` values from this vector, and the provided `z`.
+    pub fn extend(self, z: f32) -> V3 {
+        V3::new(self.x, self.y, z)
+    }
+
+    /// Perpendicular dot product; i.e., the $z$ component of a cross product of vectors extended with $z = 0$.
+    ///
+    /// Positive if `rhs` is counterclockwise from this vector.
+    pub fn perp_dot(self, rhs: Self) -> f32 {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// Returns this vector rotated by 90° counterclockwise.
+    pub fn perp(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+}
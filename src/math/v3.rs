@@ -19,9 +19,9 @@ impl From<[f32; 3]> for V3 {
     }
 }
 
-impl Into<[f32; 3]> for V3 {
-    fn into(self) -> [f32; 3] {
-        [self.x, self.y, self.z]
+impl From<V3> for [f32; 3] {
+    fn from(value: V3) -> Self {
+        [value.x, value.y, value.z]
     }
 }
 
@@ -57,6 +57,12 @@ impl std::ops::Neg for V3 {
 impl std::ops::Add for V3 {
     type Output = Self;
 
+    #[cfg(feature = "simd")]
+    fn add(self, rhs: Self) -> Self::Output {
+        (simd::F32x4::from(self) + simd::F32x4::from(rhs)).into()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn add(self, rhs: Self) -> Self::Output {
         Self {
             x: self.x + rhs.x,
@@ -119,6 +125,12 @@ impl std::ops::AddAssign<f32> for V3 {
 impl std::ops::Sub for V3 {
     type Output = Self;
 
+    #[cfg(feature = "simd")]
+    fn sub(self, rhs: Self) -> Self::Output {
+        (simd::F32x4::from(self) - simd::F32x4::from(rhs)).into()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn sub(self, rhs: Self) -> Self::Output {
         Self {
             x: self.x - rhs.x,
@@ -175,6 +187,12 @@ impl std::ops::MulAssign for V3 {
 impl std::ops::Mul<f32> for V3 {
     type Output = Self;
 
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: f32) -> Self::Output {
+        (simd::F32x4::from(self) * rhs).into()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn mul(self, rhs: f32) -> Self::Output {
         Self {
             x: self.x * rhs,
@@ -195,6 +213,12 @@ impl std::ops::MulAssign<f32> for V3 {
 impl std::ops::Mul<M3> for V3 {
     type Output = Self;
 
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: M3) -> Self::Output {
+        (simd::F32x4::from(rhs.a) * self.x + simd::F32x4::from(rhs.b) * self.y + simd::F32x4::from(rhs.c) * self.z).into()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn mul(self, rhs: M3) -> Self::Output {
         let Self { x, y, z } = self;
 
@@ -217,6 +241,12 @@ impl std::ops::MulAssign<M3> for V3 {
 impl std::ops::Mul<M4> for V3 {
     type Output = Self;
 
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: M4) -> Self::Output {
+        (simd::F32x4::from(rhs.a) * self.x + simd::F32x4::from(rhs.b) * self.y + simd::F32x4::from(rhs.c) * self.z + simd::F32x4::from(rhs.d)).into()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn mul(self, rhs: M4) -> Self::Output {
         let Self { x, y, z } = self;
 
@@ -236,6 +266,7 @@ impl std::ops::MulAssign<M4> for V3 {
 }
 
 /// Vector division by scalar.
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl std::ops::Div<f32> for V3 {
     type Output = Self;
 
@@ -262,6 +293,12 @@ impl std::cmp::PartialOrd for V3 {
 impl Magnitude for V3 {
     type Output = f32;
 
+    #[cfg(feature = "simd")]
+    fn magnitude_proportional(self) -> Self::Output {
+        (simd::F32x4::from(self) * simd::F32x4::from(self)).sum3()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn magnitude_proportional(self) -> Self::Output {
         self.x * self.x + self.y * self.y + self.z * self.z
     }
@@ -302,6 +339,12 @@ impl DivUsize for V3 {
 impl Dot for V3 {
     type Output = f32;
 
+    #[cfg(feature = "simd")]
+    fn dot(self, rhs: Self) -> f32 {
+        (simd::F32x4::from(self) * simd::F32x4::from(rhs)).sum3()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn dot(self, rhs: Self) -> f32 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
     }
@@ -412,6 +455,67 @@ impl V3 {
         V4::new(self.x, self.y, self.z, w)
     }
 
+    /// Cross product; same as `self * rhs`.
+    pub fn cross(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    /// Angle between vectors in radians, in the $[0, \pi]$ range; 0 if any vector is zero.
+    ///
+    /// Uses $\operatorname{atan2}(|a \times b|, a \cdot b)$, which stays accurate for nearly parallel vectors, unlike the arc
+    /// cosine of a dot product.
+    pub fn angle(self, rhs: Self) -> f32 {
+        f32::atan2(self.cross(rhs).magnitude(), self.dot(rhs))
+    }
+
+    /// Great-circle distance between points on the unit sphere; i.e., the [`V3::angle`] between their directions.
+    ///
+    /// Points don’t have to be normalized. Multiply by a radius for distances on other spheres.
+    pub fn great_circle_distance(self, rhs: Self) -> f32 {
+        self.angle(rhs)
+    }
+
+    /// Spherical linear interpolation; moves along the great circle through both vectors at a constant angular speed.
+    ///
+    /// Returns `self` if `t` is 0, and `rhs` if `t` is 1; unit vectors stay unit. Falls back to linear interpolation if
+    /// the sine of the angle between the vectors is below $10^{-4}$, i.e., for nearly parallel and nearly antipodal
+    /// vectors; between antipodal ones, the great circle isn’t unique, so the result passes through the origin instead.
+    pub fn slerp(self, rhs: Self, t: f32) -> Self {
+        let ω = self.angle(rhs);
+        let sin_ω = ω.sin();
+
+        if sin_ω < 1e-4 {
+            return self + (rhs - self) * t;
+        }
+
+        self * (((1.0 - t) * ω).sin() / sin_ω) + rhs * ((t * ω).sin() / sin_ω)
+    }
+
+    /// Projects a vector onto the plane tangent to a sphere at a point; i.e., removes its component along the normal.
+    ///
+    /// E.g., keeps wind or plate velocities tangent to the surface. The point doesn’t have to be normalized.
+    pub fn project_to_tangent(self, point: Self) -> Self {
+        let normal = point.normalize();
+
+        self - normal * self.dot(normal)
+    }
+
+    /// Converts a direction to a latitude and a longitude in radians, as the `x` and the `y` of a [`V2`].
+    ///
+    /// The $z$ axis points to the north pole, and the $x$ axis to the zero meridian; longitudes are in the $[-\pi, \pi]$
+    /// range. The vector doesn’t have to be normalized.
+    pub fn to_lat_lon(self) -> V2 {
+        V2::new(f32::atan2(self.z, f32::hypot(self.x, self.y)), f32::atan2(self.y, self.x))
+    }
+
+    /// Creates a unit vector from a latitude and a longitude in radians; the inverse of [`V3::to_lat_lon`].
+    pub fn from_lat_lon(latitude: f32, longitude: f32) -> Self {
+        let (sin_φ, cos_φ) = latitude.sin_cos();
+        let (sin_λ, cos_λ) = longitude.sin_cos();
+
+        Self::new(cos_φ * cos_λ, cos_φ * sin_λ, sin_φ)
+    }
+
     /// Converts a rotation vector to a new 3×3 transform matrix.
     pub fn to_rotation_m3(&self) -> M3 {
         let θ = self.magnitude();
@@ -432,11 +536,13 @@ impl V3 {
         let yzvθ = z * yvθ;
 
         #[rustfmt::skip]
-        M3 {
+        let rotation = M3 {
             a: V3::new(cθ + x * xvθ, xyvθ + zsθ,   xzvθ - ysθ),
             b: V3::new(xyvθ - zsθ,   cθ + y * yvθ, yzvθ + xsθ),
             c: V3::new(xzvθ + ysθ,   yzvθ - xsθ,   cθ + z * z * vθ),
-        }
+        };
+
+        rotation
     }
 
     /// Converts a rotation vector to a new 4×4 transform matrix.
@@ -459,12 +565,14 @@ impl V3 {
         let yzvθ = z * yvθ;
 
         #[rustfmt::skip]
-        M4 {
+        let rotation = M4 {
             a: V4::new(cθ + x * xvθ, xyvθ + zsθ,   xzvθ - ysθ,      0.0),
             b: V4::new(xyvθ - zsθ,   cθ + y * yvθ, yzvθ + xsθ,      0.0),
             c: V4::new(xzvθ + ysθ,   yzvθ - xsθ,   cθ + z * z * vθ, 0.0),
             d: V4::new(0.0,          0.0,          0.0,             1.0),
-        }
+        };
+
+        rotation
     }
 
     /// Converts a translation vector to a new 4×4 transform matrix.
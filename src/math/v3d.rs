@@ -0,0 +1,428 @@
+#![allow(dead_code)]
+
+use crate::*;
+
+/// A 3D vector of `f64` components; a high-precision counterpart of the [`V3`].
+///
+/// Useful to accumulate long chains of rotations, where `f32` rounding errors add up. Isn’t aligned for SIMD.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct V3d {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl From<[f64; 3]> for V3d {
+    fn from([x, y, z]: [f64; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<V3d> for [f64; 3] {
+    fn from(value: V3d) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl Bounded for V3d {
+    const MIN_BOUND: V3d = V3d::new(0.0, 0.0, 0.0);
+    const MAX_BOUND: V3d = V3d::new(f64::MAX_BOUND, f64::MAX_BOUND, f64::MAX_BOUND);
+}
+
+impl One for V3d {
+    fn one() -> Self {
+        Self {
+            x: 1.0 / f64::sqrt(3.0),
+            y: 1.0 / f64::sqrt(3.0),
+            z: 1.0 / f64::sqrt(3.0),
+        }
+    }
+}
+
+/// Vector negation.
+impl std::ops::Neg for V3d {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+/// Vector addition.
+impl std::ops::Add for V3d {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+/// Vector addition with assignment.
+impl std::ops::AddAssign for V3d {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Vector ⋅ scalar addition.
+///
+/// # $(0, 0, 0) + a$
+///
+/// To add a scalar to a zero vector, we’ll create new vector with equal components and with magnitude equal to given scalar.
+///
+/// If a scalar is negative, resulting vector components will be negative.
+///
+/// $(0, 0, 0) + a = (\frac{a}{\sqrt 3}, \frac{a}{\sqrt 3}, \frac{a}{\sqrt 3})$
+///
+/// # $(x, y, z) + a$
+///
+/// To add a scalar to a non-zero vector, we’ll change it to make its magnitude equal $|(x, y, z)| + a$.
+///
+/// When a scalar is negative and its absolute value is greater than vector magnitude, resulting vector will be antiparallel to an original one.
+///
+/// $(x, y, z) + a = (\frac{x (l + a)}{l}, \frac{y (l + a)}{l}, \frac{z (l + a)}{l})$, where $l = |(x, y, z)|$.
+impl std::ops::Add<f64> for V3d {
+    type Output = Self;
+
+    fn add(self, rhs: f64) -> Self::Output {
+        let mag = self.magnitude();
+
+        if mag == 0.0 {
+            Self {
+                x: rhs * 0.5773502691896258,
+                y: rhs * 0.5773502691896258,
+                z: rhs * 0.5773502691896258,
+            }
+        } else {
+            self * (mag + rhs) / mag
+        }
+    }
+}
+
+/// Vector ⋅ scalar addition with assignment.
+impl std::ops::AddAssign<f64> for V3d {
+    fn add_assign(&mut self, rhs: f64) {
+        *self = *self + rhs;
+    }
+}
+
+/// Vector subtraction.
+impl std::ops::Sub for V3d {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+/// Vector subtraction with assignment.
+impl std::ops::SubAssign for V3d {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Vector ⋅ scalar subtraction.
+impl std::ops::Sub<f64> for V3d {
+    type Output = Self;
+
+    fn sub(self, rhs: f64) -> Self::Output {
+        self + -rhs
+    }
+}
+
+/// Vector ⋅ scalar subtraction with assignment.
+impl std::ops::SubAssign<f64> for V3d {
+    fn sub_assign(&mut self, rhs: f64) {
+        *self = *self - rhs;
+    }
+}
+
+/// Cross product.
+impl std::ops::Mul for V3d {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
+
+/// Cross product with assignment.
+impl std::ops::MulAssign for V3d {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Vector multiplication by scalar.
+impl std::ops::Mul<f64> for V3d {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+/// Vector multiplication by scalar with assignment.
+impl std::ops::MulAssign<f64> for V3d {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+/// Vector multiplication by M3d.
+impl std::ops::Mul<M3d> for V3d {
+    type Output = Self;
+
+    fn mul(self, rhs: M3d) -> Self::Output {
+        let Self { x, y, z } = self;
+
+        Self {
+            x: rhs.a.x * x + rhs.b.x * y + rhs.c.x * z,
+            y: rhs.a.y * x + rhs.b.y * y + rhs.c.y * z,
+            z: rhs.a.z * x + rhs.b.z * y + rhs.c.z * z,
+        }
+    }
+}
+
+/// Vector multiplication by M3d with assignment.
+impl std::ops::MulAssign<M3d> for V3d {
+    fn mul_assign(&mut self, rhs: M3d) {
+        *self = *self * rhs;
+    }
+}
+
+/// Vector division by scalar.
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl std::ops::Div<f64> for V3d {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        self * rhs.inv()
+    }
+}
+
+/// Vector division by scalar with assignment.
+impl std::ops::DivAssign<f64> for V3d {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+/// Vector comparison by magnitude.
+impl std::cmp::PartialOrd for V3d {
+    fn partial_cmp(&self, rhs: &Self) -> Option<std::cmp::Ordering> {
+        self.magnitude_proportional().partial_cmp(&rhs.magnitude_proportional())
+    }
+}
+
+/// Vector magnitude.
+impl Magnitude for V3d {
+    type Output = f64;
+
+    fn magnitude_proportional(self) -> Self::Output {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    fn magnitude(self) -> Self::Output {
+        f64::sqrt(self.magnitude_proportional())
+    }
+}
+
+/// Numeric value of vector.
+impl ToNumeric<f64> for V3d {
+    fn into_numeric_proportional(self) -> f64 {
+        self.magnitude_proportional()
+    }
+
+    fn into_numeric(self) -> f64 {
+        self.magnitude()
+    }
+}
+
+/// Vector normalization.
+impl Normalize for V3d {
+    fn normalize(self) -> Self {
+        let mag = self.magnitude();
+
+        self / if mag == 0.0 { 1.0 } else { mag }
+    }
+}
+
+/// Vector division by `usize`.
+impl DivUsize for V3d {
+    fn div_usize(self, rhs: usize) -> Self {
+        self / rhs as f64
+    }
+}
+
+/// Dot product.
+impl Dot for V3d {
+    type Output = f64;
+
+    fn dot(self, rhs: Self) -> f64 {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+}
+
+/// Ochiai measure for two vectors.
+impl Similarity for V3d {
+    type Output = f64;
+
+    fn similarity(self, other: Self) -> Self::Output {
+        self.dot(other) / f64::sqrt(self.magnitude() * other.magnitude())
+    }
+}
+
+/// Entrywise vector ⋅ scalar addition.
+impl EntrywiseAdd<f64> for V3d {
+    type Output = Self;
+
+    fn entrywise_add(self, rhs: f64) -> Self {
+        Self::new(self.x + rhs, self.y + rhs, self.z + rhs)
+    }
+}
+
+/// Entrywise vector ⋅ scalar subtraction.
+impl EntrywiseSub<f64> for V3d {
+    type Output = Self;
+
+    fn entrywise_sub(self, rhs: f64) -> Self {
+        Self::new(self.x - rhs, self.y - rhs, self.z - rhs)
+    }
+}
+
+/// Entrywise vector ⋅ vector multiplication; i.e., Hadamard product.
+impl EntrywiseMul for V3d {
+    type Output = Self;
+
+    fn entrywise_mul(self, rhs: Self) -> Self {
+        Self::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+/// Entrywise vector ⋅ vector division.
+impl EntrywiseDiv for V3d {
+    type Output = Self;
+
+    fn entrywise_div(self, rhs: Self) -> Self {
+        Self::new(self.x / rhs.x, self.y / rhs.y, self.z / rhs.z)
+    }
+}
+
+/// Entrywise vector ⋅ vector exponentiation.
+impl EntrywisePow for V3d {
+    type Output = Self;
+
+    fn entrywise_pow(self, rhs: Self) -> Self {
+        Self::new(self.x.power(rhs.x), self.y.power(rhs.y), self.z.power(rhs.z))
+    }
+}
+
+/// Entrywise vector ⋅ scalar exponentiation.
+impl EntrywisePow<f64> for V3d {
+    type Output = Self;
+
+    fn entrywise_pow(self, rhs: f64) -> Self {
+        Self::new(self.x.power(rhs), self.y.power(rhs), self.z.power(rhs))
+    }
+}
+
+/// Entrywise vector square root.
+impl EntrywiseSqrt for V3d {
+    type Output = Self;
+
+    fn entrywise_sqrt(self) -> Self {
+        Self::new(self.x.sqrt(), self.y.sqrt(), self.z.sqrt())
+    }
+}
+
+/// Entrywise vector inversion.
+impl EntrywiseInv for V3d {
+    type Output = Self;
+
+    fn entrywise_inv(self) -> Self {
+        Self::new(self.x.inv(), self.y.inv(), self.z.inv())
+    }
+}
+
+/// Entrywise vector exponentiation.
+impl EntrywiseEX for V3d {
+    type Output = Self;
+
+    fn entrywise_e_x(self) -> Self::Output {
+        Self {
+            x: self.x.e_x(),
+            y: self.y.e_x(),
+            z: self.z.e_x(),
+        }
+    }
+}
+
+/// Lossless conversion from a [`V3`].
+impl From<V3> for V3d {
+    fn from(value: V3) -> Self {
+        Self::new(value.x as f64, value.y as f64, value.z as f64)
+    }
+}
+
+impl V3d {
+    /// Creates a new [`V3d`] using the provided values.
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts a vector to a [`V3`], rounding components to `f32`.
+    pub fn to_v3(self) -> V3 {
+        V3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+
+    /// Converts a rotation vector to a new 3×3 transform matrix.
+    pub fn to_rotation_m3d(&self) -> M3d {
+        let θ = self.magnitude();
+        let V3d { x, y, z } = *self / θ;
+
+        let (sθ, cθ) = θ.sin_cos();
+        let vθ = 1.0 - cθ;
+
+        let xvθ = x * vθ;
+        let yvθ = y * vθ;
+
+        let xsθ = x * sθ;
+        let ysθ = y * sθ;
+        let zsθ = z * sθ;
+
+        let xyvθ = y * xvθ;
+        let xzvθ = z * xvθ;
+        let yzvθ = z * yvθ;
+
+        #[rustfmt::skip]
+        let rotation = M3d {
+            a: V3d::new(cθ + x * xvθ, xyvθ + zsθ,   xzvθ - ysθ),
+            b: V3d::new(xyvθ - zsθ,   cθ + y * yvθ, yzvθ + xsθ),
+            c: V3d::new(xzvθ + ysθ,   yzvθ - xsθ,   cθ + z * z * vθ),
+        };
+
+        rotation
+    }
+}
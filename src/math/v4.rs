@@ -18,9 +18,9 @@ impl From<[f32; 4]> for V4 {
     }
 }
 
-impl Into<[f32; 4]> for V4 {
-    fn into(self) -> [f32; 4] {
-        [self.x, self.y, self.z, self.w]
+impl From<V4> for [f32; 4] {
+    fn from(value: V4) -> Self {
+        [value.x, value.y, value.z, value.w]
     }
 }
 
@@ -58,6 +58,12 @@ impl std::ops::Neg for V4 {
 impl std::ops::Add for V4 {
     type Output = Self;
 
+    #[cfg(feature = "simd")]
+    fn add(self, rhs: Self) -> Self::Output {
+        (simd::F32x4::from(self) + simd::F32x4::from(rhs)).into()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn add(self, rhs: Self) -> Self::Output {
         Self {
             x: self.x + rhs.x,
@@ -122,6 +128,12 @@ impl std::ops::AddAssign<f32> for V4 {
 impl std::ops::Sub for V4 {
     type Output = Self;
 
+    #[cfg(feature = "simd")]
+    fn sub(self, rhs: Self) -> Self::Output {
+        (simd::F32x4::from(self) - simd::F32x4::from(rhs)).into()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn sub(self, rhs: Self) -> Self::Output {
         Self {
             x: self.x - rhs.x,
@@ -159,6 +171,12 @@ impl std::ops::SubAssign<f32> for V4 {
 impl std::ops::Mul<f32> for V4 {
     type Output = Self;
 
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: f32) -> Self::Output {
+        (simd::F32x4::from(self) * rhs).into()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn mul(self, rhs: f32) -> Self::Output {
         Self {
             x: self.x * rhs,
@@ -180,6 +198,12 @@ impl std::ops::MulAssign<f32> for V4 {
 impl std::ops::Mul<M4> for V4 {
     type Output = Self;
 
+    #[cfg(feature = "simd")]
+    fn mul(self, rhs: M4) -> Self::Output {
+        (simd::F32x4::from(rhs.a) * self.x + simd::F32x4::from(rhs.b) * self.y + simd::F32x4::from(rhs.c) * self.z + simd::F32x4::from(rhs.d) * self.w).into()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn mul(self, rhs: M4) -> Self::Output {
         let Self { x, y, z, w } = self;
 
@@ -200,6 +224,7 @@ impl std::ops::MulAssign<M4> for V4 {
 }
 
 /// Vector division by scalar.
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl std::ops::Div<f32> for V4 {
     type Output = Self;
 
@@ -226,6 +251,12 @@ impl std::cmp::PartialOrd for V4 {
 impl Magnitude for V4 {
     type Output = f32;
 
+    #[cfg(feature = "simd")]
+    fn magnitude_proportional(self) -> Self::Output {
+        (simd::F32x4::from(self) * simd::F32x4::from(self)).sum4()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn magnitude_proportional(self) -> Self::Output {
         self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
     }
@@ -266,6 +297,12 @@ impl DivUsize for V4 {
 impl Dot for V4 {
     type Output = f32;
 
+    #[cfg(feature = "simd")]
+    fn dot(self, rhs: Self) -> f32 {
+        (simd::F32x4::from(self) * simd::F32x4::from(rhs)).sum4()
+    }
+
+    #[cfg(not(feature = "simd"))]
     fn dot(self, rhs: Self) -> f32 {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
     }
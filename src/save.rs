@@ -0,0 +1,562 @@
+//! Save files.
+//!
+//! A save file is a versioned little-endian binary container of named layers, a grid identifier, a [`Random`] state, and
+//! free-form metadata. It only works with byte buffers, so it’s the same in native and WASM builds:
+//!
+//! ```text
+//! magic          8 bytes    "ISECADSV"
+//! version        u32        1
+//! grid           u64        grid identifier
+//! random         u8         0 if there’s no random state, 1 if the state follows:
+//!   mt           624 × u32
+//!   i            u32
+//!   last_normal  f32
+//! metadata       u32        number of entries, each of them is:
+//!   key          string
+//!   value        string
+//! layers         u32        number of layers, each of them is:
+//!   name         string
+//!   type         u8         item type tag, see ItemType
+//!   length       u64        number of items
+//!   items        …          see ItemType
+//! checksum       u32        CRC-32 of all preceding bytes
+//! ```
+//!
+//! Strings are `u32` byte lengths followed by UTF-8 bytes. Metadata keys and layer names are unique, and are written in
+//! ascending order, so equal saves produce equal files.
+//!
+//! [`SaveFile::from_bytes`] validates every field before using it, and never allocates more than the input may contain, so
+//! truncated, corrupted, or foreign files are reported as [`SaveError`]s instead of panics.
+
+use crate::*;
+use std::collections::*;
+use std::convert::*;
+use std::fmt;
+use std::num::*;
+
+const MAGIC: [u8; 8] = *b"ISECADSV";
+const VERSION: u32 = 1;
+
+/// Item type of a saved layer.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ItemType {
+    /// Tag 1; every item is an `f32`.
+    F32,
+
+    /// Tag 2; every item is a byte.
+    U8,
+
+    /// Tag 3; items are packed into bytes, 8 items per byte starting from the lowest bit. Unused bits of the last byte are
+    /// zero.
+    Bool,
+
+    /// Tag 4; every item is $x$, $y$, $z$ `f32`s.
+    V3,
+
+    /// Tag 5; every item is $x$, $y$, $z$, $w$ `f32`s.
+    V4,
+
+    /// Tag 6; every item is $a$, $b$, $c$ columns of 3 `f32`s.
+    M3,
+}
+
+impl ItemType {
+    /// Returns tag of an item type.
+    pub fn tag(self) -> u8 {
+        match self {
+            ItemType::F32 => 1,
+            ItemType::U8 => 2,
+            ItemType::Bool => 3,
+            ItemType::V3 => 4,
+            ItemType::V4 => 5,
+            ItemType::M3 => 6,
+        }
+    }
+
+    /// Returns item type of a tag, if any.
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            1 => Some(ItemType::F32),
+            2 => Some(ItemType::U8),
+            3 => Some(ItemType::Bool),
+            4 => Some(ItemType::V3),
+            5 => Some(ItemType::V4),
+            6 => Some(ItemType::M3),
+            _ => None,
+        }
+    }
+
+    /// Returns number of bytes taken by `length` items, or `None` on overflow.
+    fn byte_length(self, length: usize) -> Option<usize> {
+        match self {
+            ItemType::F32 => length.checked_mul(4),
+            ItemType::U8 => Some(length),
+            ItemType::Bool => Some(length.div_ceil(8)),
+            ItemType::V3 => length.checked_mul(12),
+            ItemType::V4 => length.checked_mul(16),
+            ItemType::M3 => length.checked_mul(36),
+        }
+    }
+}
+
+impl fmt::Display for ItemType {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ItemType::F32 => "f32",
+            ItemType::U8 => "u8",
+            ItemType::Bool => "bool",
+            ItemType::V3 => "V3",
+            ItemType::V4 => "V4",
+            ItemType::M3 => "M3",
+        };
+
+        fmt.write_str(name)
+    }
+}
+
+/// An error of reading a save file, or of looking up its contents.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SaveError {
+    /// A file doesn’t start with the save file magic bytes.
+    InvalidMagic,
+
+    /// A file has a format version this build can’t read.
+    UnsupportedVersion { version: u32 },
+
+    /// A file ends in the middle of a field starting at an offset.
+    UnexpectedEnd { offset: usize },
+
+    /// The checksum doesn’t match file contents.
+    ChecksumMismatch { expected: u32, actual: u32 },
+
+    /// A layer has an unknown item type tag.
+    UnknownItemType { tag: u8, offset: usize },
+
+    /// A string isn’t valid UTF-8.
+    InvalidUtf8 { offset: usize },
+
+    /// A field has a value out of its range.
+    InvalidValue { offset: usize },
+
+    /// A metadata key or a layer name occurs twice.
+    DuplicateName { name: String },
+
+    /// There are extra bytes after the last layer.
+    TrailingBytes { offset: usize },
+
+    /// There’s no layer with a name.
+    MissingLayer { name: String },
+
+    /// A layer has a different item type than requested.
+    ItemTypeMismatch { name: String, expected: ItemType, actual: ItemType },
+
+    /// A file was saved for a different grid.
+    GridMismatch { expected: u64, actual: u64 },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::InvalidMagic => write!(fmt, "not a save file"),
+            SaveError::UnsupportedVersion { version } => write!(fmt, "unsupported save file version {}", version),
+            SaveError::UnexpectedEnd { offset } => write!(fmt, "unexpected end of save file at {}", offset),
+            SaveError::ChecksumMismatch { expected, actual } => write!(fmt, "checksum mismatch: expected {:08x}, got {:08x}", expected, actual),
+            SaveError::UnknownItemType { tag, offset } => write!(fmt, "unknown item type {} at {}", tag, offset),
+            SaveError::InvalidUtf8 { offset } => write!(fmt, "invalid UTF-8 string at {}", offset),
+            SaveError::InvalidValue { offset } => write!(fmt, "invalid value at {}", offset),
+            SaveError::DuplicateName { name } => write!(fmt, "duplicate name `{}`", name),
+            SaveError::TrailingBytes { offset } => write!(fmt, "trailing bytes at {}", offset),
+            SaveError::MissingLayer { name } => write!(fmt, "missing layer `{}`", name),
+            SaveError::ItemTypeMismatch { name, expected, actual } => write!(fmt, "layer `{}` has {} items, expected {}", name, actual, expected),
+            SaveError::GridMismatch { expected, actual } => write!(fmt, "grid mismatch: expected {}, got {}", expected, actual),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+/// A layer of any savable item type.
+#[derive(Clone, Debug)]
+pub enum SavedLayer {
+    F32(Layer<f32>),
+    U8(Layer<u8>),
+    Bool(Layer<bool>),
+    V3(Layer<V3>),
+    V4(Layer<V4>),
+    M3(Layer<M3>),
+}
+
+impl SavedLayer {
+    /// Returns item type of a layer.
+    pub fn item_type(&self) -> ItemType {
+        match self {
+            SavedLayer::F32(_) => ItemType::F32,
+            SavedLayer::U8(_) => ItemType::U8,
+            SavedLayer::Bool(_) => ItemType::Bool,
+            SavedLayer::V3(_) => ItemType::V3,
+            SavedLayer::V4(_) => ItemType::V4,
+            SavedLayer::M3(_) => ItemType::M3,
+        }
+    }
+
+    /// Returns number of items in a layer.
+    pub fn len(&self) -> usize {
+        match self {
+            SavedLayer::F32(layer) => layer.len(),
+            SavedLayer::U8(layer) => layer.len(),
+            SavedLayer::Bool(layer) => layer.len(),
+            SavedLayer::V3(layer) => layer.len(),
+            SavedLayer::V4(layer) => layer.len(),
+            SavedLayer::M3(layer) => layer.len(),
+        }
+    }
+
+    /// Returns `true` if a layer has no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn write_items(&self, bytes: &mut Vec<u8>) {
+        match self {
+            SavedLayer::F32(layer) => write_floats(bytes, layer.iter().copied()),
+            SavedLayer::U8(layer) => bytes.extend_from_slice(layer),
+            SavedLayer::Bool(layer) => {
+                for chunk in layer.chunks(8) {
+                    bytes.push(chunk.iter().enumerate().fold(0, |byte, (j, &l_j)| byte | (l_j as u8) << j));
+                }
+            }
+            SavedLayer::V3(layer) => write_floats(bytes, layer.iter().flat_map(|&l_i| <[f32; 3]>::from(l_i))),
+            SavedLayer::V4(layer) => write_floats(bytes, layer.iter().flat_map(|&l_i| <[f32; 4]>::from(l_i))),
+            SavedLayer::M3(layer) => write_floats(bytes, layer.iter().flat_map(|&l_i| <[[f32; 3]; 3]>::from(l_i)).flatten()),
+        }
+    }
+
+    fn read_items(item_type: ItemType, length: usize, items: &[u8], offset: usize) -> Result<Self, SaveError> {
+        Ok(match item_type {
+            ItemType::F32 => SavedLayer::F32(items.chunks_exact(4).map(read_f32).collect()),
+            ItemType::U8 => SavedLayer::U8(items.to_vec().into()),
+            ItemType::Bool => {
+                if !length.is_multiple_of(8) && items[items.len() - 1] >> (length % 8) != 0 {
+                    return Err(SaveError::InvalidValue {
+                        offset: offset + items.len() - 1,
+                    });
+                }
+
+                SavedLayer::Bool((0..length).map(|i| items[i / 8] >> (i % 8) & 1 != 0).collect())
+            }
+            ItemType::V3 => SavedLayer::V3(items.chunks_exact(12).map(|item| V3::from(read_floats::<3>(item))).collect()),
+            ItemType::V4 => SavedLayer::V4(items.chunks_exact(16).map(|item| V4::from(read_floats::<4>(item))).collect()),
+            ItemType::M3 => SavedLayer::M3(
+                items
+                    .chunks_exact(36)
+                    .map(|item| M3::from([0, 12, 24].map(|k| read_floats::<3>(&item[k..k + 12]))))
+                    .collect(),
+            ),
+        })
+    }
+}
+
+/// An item type that layers of can be saved.
+pub trait SaveItem: Copy + Default + Send + Sync + Sized {
+    /// Item type of saved layers.
+    const ITEM_TYPE: ItemType;
+
+    /// Wraps a layer.
+    fn wrap(layer: Layer<Self>) -> SavedLayer;
+
+    /// Unwraps a layer of this item type.
+    fn unwrap(layer: &SavedLayer) -> Option<&Layer<Self>>;
+}
+
+macro_rules! impl_save_item {
+    ($($item:ty => $variant:ident),*) => {$(
+        impl SaveItem for $item {
+            const ITEM_TYPE: ItemType = ItemType::$variant;
+
+            fn wrap(layer: Layer<Self>) -> SavedLayer {
+                SavedLayer::$variant(layer)
+            }
+
+            fn unwrap(layer: &SavedLayer) -> Option<&Layer<Self>> {
+                match layer {
+                    SavedLayer::$variant(layer) => Some(layer),
+                    _ => None,
+                }
+            }
+        }
+
+        impl From<Layer<$item>> for SavedLayer {
+            fn from(layer: Layer<$item>) -> Self {
+                SavedLayer::$variant(layer)
+            }
+        }
+    )*};
+}
+
+impl_save_item!(f32 => F32, u8 => U8, bool => Bool, V3 => V3, V4 => V4, M3 => M3);
+
+/// Contents of a save file.
+#[derive(Clone, Debug, Default)]
+pub struct SaveFile {
+    /// Identifier of the grid layers are defined on.
+    pub grid: u64,
+
+    /// State of the random number generator, if any.
+    pub random: Option<Random>,
+
+    /// Free-form metadata.
+    pub metadata: BTreeMap<String, String>,
+
+    /// Named layers.
+    pub layers: BTreeMap<String, SavedLayer>,
+}
+
+impl SaveFile {
+    /// Creates new empty save of specified grid.
+    pub fn new(grid: u64) -> Self {
+        Self { grid, ..Self::default() }
+    }
+
+    /// Adds a layer, replacing a layer with the same name.
+    pub fn insert_layer<T: SaveItem>(&mut self, name: impl Into<String>, layer: Layer<T>) {
+        self.layers.insert(name.into(), T::wrap(layer));
+    }
+
+    /// Returns a layer of specified item type.
+    pub fn layer<T: SaveItem>(&self, name: &str) -> Result<&Layer<T>, SaveError> {
+        let layer = self.layers.get(name).ok_or_else(|| SaveError::MissingLayer { name: name.to_string() })?;
+
+        T::unwrap(layer).ok_or_else(|| SaveError::ItemTypeMismatch {
+            name: name.to_string(),
+            expected: T::ITEM_TYPE,
+            actual: layer.item_type(),
+        })
+    }
+
+    /// Checks that a save belongs to specified grid.
+    pub fn check_grid(&self, grid: u64) -> Result<(), SaveError> {
+        if self.grid == grid {
+            Ok(())
+        } else {
+            Err(SaveError::GridMismatch {
+                expected: grid,
+                actual: self.grid,
+            })
+        }
+    }
+
+    /// Serializes a save.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.grid.to_le_bytes());
+
+        match &self.random {
+            None => bytes.push(0),
+            Some(random) => {
+                bytes.push(1);
+
+                for mt_k in random.mt.iter() {
+                    bytes.extend_from_slice(&mt_k.0.to_le_bytes());
+                }
+
+                bytes.extend_from_slice(&(random.i as u32).to_le_bytes());
+                bytes.extend_from_slice(&random.last_normal.to_le_bytes());
+            }
+        }
+
+        bytes.extend_from_slice(&(self.metadata.len() as u32).to_le_bytes());
+
+        for (key, value) in &self.metadata {
+            write_string(&mut bytes, key);
+            write_string(&mut bytes, value);
+        }
+
+        bytes.extend_from_slice(&(self.layers.len() as u32).to_le_bytes());
+
+        for (name, layer) in &self.layers {
+            write_string(&mut bytes, name);
+
+            bytes.push(layer.item_type().tag());
+            bytes.extend_from_slice(&(layer.len() as u64).to_le_bytes());
+
+            layer.write_items(&mut bytes);
+        }
+
+        let checksum = checksum::crc32(&bytes);
+
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+
+        bytes
+    }
+
+    /// Deserializes a save, validating all of its fields.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SaveError> {
+        if bytes.len() < MAGIC.len() || bytes[..MAGIC.len()] != MAGIC {
+            return Err(SaveError::InvalidMagic);
+        }
+
+        let mut reader = Reader { bytes, offset: MAGIC.len() };
+
+        let version = reader.u32()?;
+
+        if version != VERSION {
+            return Err(SaveError::UnsupportedVersion { version });
+        }
+
+        let body_length = bytes.len().checked_sub(4).ok_or(SaveError::UnexpectedEnd { offset: bytes.len() })?;
+        let expected = read_u32(&bytes[body_length..]);
+        let actual = checksum::crc32(&bytes[..body_length]);
+
+        if expected != actual {
+            return Err(SaveError::ChecksumMismatch { expected, actual });
+        }
+
+        let mut reader = Reader {
+            bytes: &bytes[..body_length],
+            offset: reader.offset,
+        };
+
+        let mut save = Self::new(reader.u64()?);
+
+        save.random = match reader.u8()? {
+            0 => None,
+            1 => Some(reader.random()?),
+            _ => return Err(SaveError::InvalidValue { offset: reader.offset - 1 }),
+        };
+
+        for _ in 0..reader.u32()? {
+            let key = reader.string()?;
+            let value = reader.string()?;
+
+            if save.metadata.contains_key(&key) {
+                return Err(SaveError::DuplicateName { name: key });
+            }
+
+            save.metadata.insert(key, value);
+        }
+
+        for _ in 0..reader.u32()? {
+            let name = reader.string()?;
+
+            let tag_offset = reader.offset;
+            let tag = reader.u8()?;
+            let item_type = ItemType::from_tag(tag).ok_or(SaveError::UnknownItemType { tag, offset: tag_offset })?;
+
+            let length_offset = reader.offset;
+            let length = usize::try_from(reader.u64()?).map_err(|_| SaveError::InvalidValue { offset: length_offset })?;
+            let byte_length = item_type.byte_length(length).ok_or(SaveError::InvalidValue { offset: length_offset })?;
+
+            let items_offset = reader.offset;
+            let items = reader.take(byte_length)?;
+            let layer = SavedLayer::read_items(item_type, length, items, items_offset)?;
+
+            if save.layers.contains_key(&name) {
+                return Err(SaveError::DuplicateName { name });
+            }
+
+            save.layers.insert(name, layer);
+        }
+
+        if reader.offset != body_length {
+            return Err(SaveError::TrailingBytes { offset: reader.offset });
+        }
+
+        Ok(save)
+    }
+}
+
+/// A bounds-checked cursor over save file bytes.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], SaveError> {
+        let end = self
+            .offset
+            .checked_add(length)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(SaveError::UnexpectedEnd { offset: self.offset })?;
+
+        let bytes = &self.bytes[self.offset..end];
+
+        self.offset = end;
+
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, SaveError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, SaveError> {
+        self.take(4).map(read_u32)
+    }
+
+    fn u64(&mut self) -> Result<u64, SaveError> {
+        let bytes = self.take(8)?;
+
+        Ok(u64::from_le_bytes(bytes.try_into().expect("8 bytes")))
+    }
+
+    fn string(&mut self) -> Result<String, SaveError> {
+        let length = self.u32()? as usize;
+        let offset = self.offset;
+        let bytes = self.take(length)?;
+
+        String::from_utf8(bytes.to_vec()).map_err(|_| SaveError::InvalidUtf8 { offset })
+    }
+
+    fn random(&mut self) -> Result<Random, SaveError> {
+        let mut mt = [Wrapping(0); random::N];
+
+        for mt_k in mt.iter_mut() {
+            *mt_k = Wrapping(self.u32()?);
+        }
+
+        let i_offset = self.offset;
+        let i = self.u32()? as usize;
+
+        if i > random::N {
+            return Err(SaveError::InvalidValue { offset: i_offset });
+        }
+
+        let last_normal = read_f32(self.take(4)?);
+
+        Ok(Random { mt, i, last_normal })
+    }
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes.try_into().expect("4 bytes"))
+}
+
+fn read_f32(bytes: &[u8]) -> f32 {
+    f32::from_le_bytes(bytes.try_into().expect("4 bytes"))
+}
+
+/// Reads `N` consecutive `f32`s from an item of `4 N` bytes, without allocating.
+fn read_floats<const N: usize>(item: &[u8]) -> [f32; N] {
+    let mut floats = [0.0; N];
+
+    for (float, bytes) in floats.iter_mut().zip(item.chunks_exact(4)) {
+        *float = read_f32(bytes);
+    }
+
+    floats
+}
+
+fn write_floats(bytes: &mut Vec<u8>, floats: impl Iterator<Item = f32>) {
+    for float in floats {
+        bytes.extend_from_slice(&float.to_le_bytes());
+    }
+}
+
+fn write_string(bytes: &mut Vec<u8>, string: &str) {
+    bytes.extend_from_slice(&(string.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(string.as_bytes());
+}
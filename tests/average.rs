@@ -0,0 +1,43 @@
+//! Averages of integer layers must be summed in a wide accumulator, so they don’t overflow, and be truncated toward zero.
+
+use isecad::*;
+
+#[test]
+fn averages_many_narrow_items() {
+    assert_eq!(Layer::from(vec![1u8; 300]).average(), 1);
+    assert_eq!(Layer::from(vec![1i16; 300]).average(), 1);
+    assert_eq!(Layer::from(vec![u8::MAX; 300]).average(), u8::MAX);
+}
+
+#[test]
+fn averages_items_with_overflowing_sums() {
+    assert_eq!(Layer::from(vec![200u8, 200]).average(), 200);
+    assert_eq!(Layer::from(vec![255u8, 254]).average(), 254);
+    assert_eq!(Layer::from(vec![u16::MAX, u16::MAX - 2]).average(), u16::MAX - 1);
+    assert_eq!(Layer::from(vec![u32::MAX; 3]).average(), u32::MAX);
+    assert_eq!(Layer::from(vec![i16::MIN, i16::MIN]).average(), i16::MIN);
+    assert_eq!(Layer::from(vec![i32::MAX, i32::MAX, i32::MIN]).average(), i32::MAX / 3);
+}
+
+#[test]
+fn averages_items_across_chunks() {
+    let length = kernels::CHUNK_LENGTH * 3 + 5;
+    let layer = Layer::from_fn(length, |i| (i % 256) as u8);
+    let sum: u64 = layer.iter().map(|&l_i| l_i as u64).sum();
+
+    assert_eq!(layer.average() as u64, sum / length as u64);
+    assert_eq!(layer.average_as::<f64>(), sum as f64 / length as f64);
+}
+
+#[test]
+fn truncates_averages_toward_zero() {
+    assert_eq!(Layer::from(vec![1u8, 2]).average(), 1);
+    assert_eq!(Layer::from(vec![-1i32, -2]).average(), -1);
+    assert_eq!(Layer::from(vec![-3i16, 1]).average(), -1);
+}
+
+#[test]
+fn empty_layers_have_no_average() {
+    assert_eq!(Layer::<u8>::new(0).try_average(), None);
+    assert_eq!(Layer::from(vec![7u32]).try_average(), Some(7));
+}
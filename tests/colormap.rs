@@ -0,0 +1,125 @@
+//! Colormaps must hit their stops exactly, interpolate between them, and clamp beyond them; palettes must wrap around.
+
+use isecad::*;
+
+const BLACK: Rgba8 = [0, 0, 0, 255];
+const ORANGE: Rgba8 = [255, 100, 10, 0];
+
+#[test]
+fn colormap_interpolates_between_stops() {
+    let colormap = Colormap::new(&[(0.0, BLACK), (1.0, ORANGE)]);
+
+    assert_eq!(colormap.color(0.0), BLACK);
+    assert_eq!(colormap.color(1.0), ORANGE);
+
+    // Channels are rounded half away from zero.
+    assert_eq!(colormap.color(0.5), [128, 50, 5, 128]);
+    assert_eq!(colormap.color(0.25), [64, 25, 3, 191]);
+}
+
+#[test]
+fn colormap_clamps_beyond_stops() {
+    let colormap = Colormap::new(&[(0.0, BLACK), (1.0, ORANGE)]);
+
+    for value in [-0.001, -1.0, -1e30, f32::NEG_INFINITY] {
+        assert_eq!(colormap.color(value), BLACK, "{}", value);
+    }
+
+    for value in [1.001, 2.0, 1e30, f32::INFINITY] {
+        assert_eq!(colormap.color(value), ORANGE, "{}", value);
+    }
+
+    let single = Colormap::new(&[(0.5, ORANGE)]);
+
+    assert_eq!(single.color(0.0), ORANGE);
+    assert_eq!(single.color(0.5), ORANGE);
+    assert_eq!(single.color(1.0), ORANGE);
+}
+
+#[test]
+fn colormap_makes_nan_transparent() {
+    let colormap = Colormap::viridis(0.0, 1.0);
+    let layer = Layer::from(vec![0.0, f32::NAN, 1.0]);
+    let mut output = Layer::new(3);
+
+    assert_eq!(colormap.color(f32::NAN), TRANSPARENT);
+
+    colormap.map_layer(&layer, &mut output);
+
+    assert_eq!(&output[..], &[[0x44, 0x01, 0x54, 0xff], TRANSPARENT, [0xfd, 0xe7, 0x25, 0xff]]);
+}
+
+#[test]
+fn builtin_colormaps_hit_endpoints_and_midpoints() {
+    let viridis = Colormap::viridis(-10.0, 10.0);
+
+    assert_eq!(viridis.color(-10.0), [0x44, 0x01, 0x54, 0xff]);
+    assert_eq!(viridis.color(0.0), [0x21, 0x91, 0x8c, 0xff]);
+    assert_eq!(viridis.color(10.0), [0xfd, 0xe7, 0x25, 0xff]);
+
+    // The center doesn’t have to be in the middle of the range.
+    let diverging = Colormap::diverging(-30.0, 0.0, 10.0);
+
+    assert_eq!(diverging.color(-30.0), [0x05, 0x30, 0x61, 0xff]);
+    assert_eq!(diverging.color(0.0), [0xf7, 0xf7, 0xf7, 0xff]);
+    assert_eq!(diverging.color(10.0), [0x67, 0x00, 0x1f, 0xff]);
+    assert_eq!(diverging.color(-18.0), [0x43, 0x93, 0xc3, 0xff]);
+    assert_eq!(diverging.color(4.0), [0xf4, 0xa5, 0x82, 0xff]);
+}
+
+#[test]
+fn hypsometric_colormap_has_sharp_coastline() {
+    let hypsometric = Colormap::hypsometric(-8000.0, 0.0, 6000.0);
+
+    assert_eq!(hypsometric.color(-8000.0), [0x08, 0x1d, 0x58, 0xff]);
+    assert_eq!(hypsometric.color(-0.001), [0x8c, 0xc6, 0xe8, 0xff]);
+    assert_eq!(hypsometric.color(0.0), [0x2f, 0x7d, 0x3c, 0xff]);
+    assert_eq!(hypsometric.color(6000.0), [0xfa, 0xfa, 0xfa, 0xff]);
+}
+
+#[test]
+#[should_panic(expected = "colormap without stops")]
+fn colormap_requires_stops() {
+    Colormap::new(&[]);
+}
+
+#[test]
+fn palette_wraps_ids_around() {
+    let palette = Palette::categorical();
+    let colors = palette.colors();
+
+    assert_eq!(colors.len(), 10);
+    assert_eq!(palette.color(0), colors[0]);
+    assert_eq!(palette.color(9), colors[9]);
+    assert_eq!(palette.color(10), colors[0]);
+    assert_eq!(palette.color(u8::MAX), colors[5]);
+
+    let layer = Layer::from(vec![3, 13, 255]);
+    let mut output = Layer::new(3);
+
+    palette.map_layer(&layer, &mut output);
+
+    assert_eq!(&output[..], &[colors[3], colors[3], colors[5]]);
+}
+
+#[test]
+fn palette_grows_with_repeated_colors() {
+    let mut palette = Palette::new(vec![BLACK, ORANGE]);
+
+    palette.set_color(1, TRANSPARENT);
+    assert_eq!(palette.colors(), &[BLACK, TRANSPARENT]);
+
+    palette.set_color(4, ORANGE);
+    assert_eq!(palette.colors(), &[BLACK, TRANSPARENT, BLACK, TRANSPARENT, ORANGE]);
+    assert_eq!(palette.color(5), BLACK);
+
+    palette.set_color(u8::MAX, BLACK);
+    assert_eq!(palette.colors().len(), 256);
+    assert_eq!(palette.color(u8::MAX), BLACK);
+}
+
+#[test]
+#[should_panic(expected = "palette without colors")]
+fn palette_requires_colors() {
+    Palette::new(Vec::new());
+}
@@ -0,0 +1,45 @@
+//! Reductions must be bit-identical with and without the `parallel` feature, and for any number of threads.
+//!
+//! Expected values are exact bit patterns, so running these tests with and without `--features parallel` compares both modes.
+
+use isecad::*;
+
+/// Longer than a few chunks, with a shorter last chunk.
+const LENGTH: usize = kernels::CHUNK_LENGTH * 5 + 123;
+
+/// Values spanning several orders of magnitude, so the summation order matters.
+fn values() -> Layer<f32> {
+    Layer::from_fn(LENGTH, |i| ((i * 7919 % 10007) as f32 - 5003.0) * if i % 3 == 0 { 1e3 } else { 1e-3 })
+}
+
+fn weights() -> Layer<f32> {
+    Layer::from_fn(LENGTH, |i| (i * 104_729 % 101) as f32 / 100.0)
+}
+
+fn results() -> (u32, u32, (u32, u32)) {
+    let (values, weights) = (values(), weights());
+    let (min, max) = values.min_max::<f32>();
+
+    (
+        values.average().to_bits(),
+        values.weighted_average(&weights).to_bits(),
+        (min.to_bits(), max.to_bits()),
+    )
+}
+
+#[test]
+fn reductions_are_deterministic() {
+    assert_eq!(results(), (0x436d_ecfd, 0x4501_6fc3, (0xca98_adf0, 0x4a98_a620)));
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn reductions_are_deterministic_across_thread_counts() {
+    let expected = results();
+
+    for threads in [1, 2, 3, 8] {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+
+        assert_eq!(pool.install(results), expected, "{} threads", threads);
+    }
+}
@@ -0,0 +1,247 @@
+//! Rendered maps must sample the nearest vertices and triangles, and encoded files must follow the PNG, zlib, and PGM formats.
+
+use isecad::*;
+use std::convert::*;
+
+fn octahedron() -> (Layer<V3>, Vec<[usize; 3]>) {
+    let positions = Layer::from(vec![
+        V3::new(1.0, 0.0, 0.0),
+        V3::new(0.0, 1.0, 0.0),
+        V3::new(0.0, 0.0, 1.0),
+        V3::new(-1.0, 0.0, 0.0),
+        V3::new(0.0, -1.0, 0.0),
+        V3::new(0.0, 0.0, -1.0),
+    ]);
+    let triangles = vec![[0, 1, 2], [1, 3, 2], [3, 4, 2], [4, 0, 2], [1, 0, 5], [3, 1, 5], [4, 3, 5], [0, 4, 5]];
+
+    (positions, triangles)
+}
+
+/// Points scattered over the sphere along a golden-angle spiral.
+fn spiral(length: usize) -> Layer<V3> {
+    Layer::from_fn(length, |i| {
+        let latitude = (1.0 - (2 * i + 1) as f32 / length as f32).asin();
+        let longitude = i as f32 * 2.399_963;
+
+        V3::from_lat_lon(latitude, longitude)
+    })
+}
+
+/// Splits PNG bytes into chunk kinds and data, checking lengths and CRCs.
+fn png_chunks(png: &[u8]) -> Vec<([u8; 4], Vec<u8>)> {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+    let mut chunks = Vec::new();
+    let mut rest = &png[8..];
+
+    while !rest.is_empty() {
+        let length = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let kind: [u8; 4] = rest[4..8].try_into().unwrap();
+        let data = rest[8..8 + length].to_vec();
+        let crc = u32::from_be_bytes(rest[8 + length..12 + length].try_into().unwrap());
+
+        assert_eq!(crc, checksum::crc32(&rest[4..8 + length]), "CRC of {:?}", kind);
+
+        chunks.push((kind, data));
+        rest = &rest[12 + length..];
+    }
+
+    chunks
+}
+
+/// Unwraps a zlib stream of stored deflate blocks, checking its header, block lengths, and Adler-32 checksum.
+fn inflate_stored(zlib: &[u8]) -> Vec<u8> {
+    assert_eq!(zlib[0] & 0x0f, 8, "deflate compression method");
+    assert_eq!(u16::from_be_bytes([zlib[0], zlib[1]]) % 31, 0, "header check bits");
+
+    let mut data = Vec::new();
+    let mut rest = &zlib[2..];
+
+    loop {
+        let header = rest[0];
+        let length = u16::from_le_bytes([rest[1], rest[2]]);
+        let length_complement = u16::from_le_bytes([rest[3], rest[4]]);
+
+        assert_eq!(header & 0b110, 0, "stored block type");
+        assert_eq!(length_complement, !length);
+
+        data.extend_from_slice(&rest[5..5 + length as usize]);
+        rest = &rest[5 + length as usize..];
+
+        if header & 1 == 1 {
+            break;
+        }
+
+        assert_eq!(length, u16::MAX, "only the last block may be shorter");
+    }
+
+    assert_eq!(rest, checksum::adler32(&data).to_be_bytes());
+
+    data
+}
+
+#[test]
+fn checksums_match_reference_values() {
+    assert_eq!(checksum::crc32(b"123456789"), 0xcbf4_3926);
+    assert_eq!(checksum::crc32(b"IEND"), 0xae42_6082);
+    assert_eq!(checksum::adler32(b"Wikipedia"), 0x11e6_0398);
+    assert_eq!(checksum::adler32(b""), 1);
+}
+
+#[test]
+fn encodes_png() {
+    for (bit_depth, bits, pixels, raw) in [
+        (BitDepth::Eight, 8, vec![0, 127, 255, 64, 2, 0], vec![0, 0, 127, 255, 0, 64, 2, 0]),
+        (
+            BitDepth::Sixteen,
+            16,
+            vec![0, 0x7f00, 0xff01, 64, 0x0201, 0],
+            vec![0, 0, 0, 0x7f, 0, 0xff, 1, 0, 0, 64, 2, 1, 0, 0],
+        ),
+    ] {
+        let image = GrayImage {
+            width: 3,
+            height: 2,
+            bit_depth,
+            pixels,
+        };
+
+        let chunks = png_chunks(&image.to_png());
+        let kinds: Vec<_> = chunks.iter().map(|(kind, _)| kind).collect();
+
+        assert_eq!(kinds, [b"IHDR", b"IDAT", b"IEND"]);
+        assert_eq!(chunks[0].1, [0, 0, 0, 3, 0, 0, 0, 2, bits, 0, 0, 0, 0]);
+        assert!(chunks[2].1.is_empty());
+
+        // Each row starts with the filter type byte.
+        assert_eq!(inflate_stored(&chunks[1].1), raw);
+    }
+}
+
+#[test]
+fn splits_large_png_data_into_stored_blocks() {
+    let (width, height) = (300, 250);
+    let image = GrayImage {
+        width,
+        height,
+        bit_depth: BitDepth::Sixteen,
+        pixels: (0..width * height).map(|i| (i * 7919) as u16).collect(),
+    };
+
+    let chunks = png_chunks(&image.to_png());
+    let raw = inflate_stored(&chunks[1].1);
+
+    assert!(raw.len() > 2 * u16::MAX as usize);
+
+    for (y, row) in raw.chunks(width * 2 + 1).enumerate() {
+        assert_eq!(row[0], 0);
+
+        for (x, pixel) in row[1..].chunks(2).enumerate() {
+            assert_eq!(u16::from_be_bytes([pixel[0], pixel[1]]), image.pixels[y * width + x]);
+        }
+    }
+}
+
+#[test]
+fn encodes_empty_png() {
+    let image = GrayImage {
+        width: 0,
+        height: 0,
+        bit_depth: BitDepth::Eight,
+        pixels: Vec::new(),
+    };
+
+    let chunks = png_chunks(&image.to_png());
+
+    assert!(inflate_stored(&chunks[1].1).is_empty());
+}
+
+#[test]
+fn encodes_pgm() {
+    let image = GrayImage {
+        width: 2,
+        height: 2,
+        bit_depth: BitDepth::Eight,
+        pixels: vec![0, 255, 17, 128],
+    };
+
+    assert_eq!(image.to_pgm(), b"P5\n2 2\n255\n\x00\xff\x11\x80");
+
+    let image = GrayImage {
+        bit_depth: BitDepth::Sixteen,
+        pixels: vec![0, 0xffff, 0x1234, 0x8000],
+        ..image
+    };
+
+    assert_eq!(image.to_pgm(), b"P5\n2 2\n65535\n\x00\x00\xff\xff\x12\x34\x80\x00");
+}
+
+#[test]
+fn renders_constant_layers_black() {
+    let (positions, triangles) = octahedron();
+    let sampler = ImageSampler::new(&positions, &triangles, Projection::Equirectangular, Sampling::Barycentric, 16, 8).unwrap();
+    let image = sampler.render(&Layer::from(vec![2.5f32; 6]), BitDepth::Eight);
+
+    assert_eq!((image.width, image.height), (16, 8));
+    assert_eq!(image.pixels, vec![0; 16 * 8]);
+}
+
+#[test]
+fn renders_gradients_monotonically() {
+    let (positions, triangles) = octahedron();
+    let (width, height) = (32, 16);
+    let sampler = ImageSampler::new(&positions, &triangles, Projection::Equirectangular, Sampling::Barycentric, width, height).unwrap();
+
+    // Heights grow to the north, so pixels get darker from top to bottom in every column.
+    let heights = Layer::from_fn(6, |i| positions[i].z);
+    let image = sampler.render(&heights, BitDepth::Sixteen);
+
+    for x in 0..width {
+        let column: Vec<u16> = (0..height).map(|y| image.pixels[y * width + x]).collect();
+
+        assert!(column.windows(2).all(|pair| pair[0] > pair[1]), "column {}: {:?}", x, column);
+        assert!(column[0] > 60000 && column[height - 1] < 5000, "column {}: {:?}", x, column);
+    }
+}
+
+#[test]
+fn samples_nearest_vertices() {
+    let positions = spiral(500);
+    let (width, height) = (64, 32);
+    let sampler = ImageSampler::new(&positions, &[], Projection::Equirectangular, Sampling::Nearest, width, height).unwrap();
+
+    // Vertex indices are rendered as distinct 16-bit values.
+    let indices = Layer::from_fn(positions.len(), |i| i as f32);
+    let image = sampler.render(&indices, BitDepth::Sixteen);
+
+    for pixel in 0..width * height {
+        let u = ((pixel % width) as f32 + 0.5) / width as f32;
+        let v = ((pixel / width) as f32 + 0.5) / height as f32;
+        let point = Projection::Equirectangular.inverse(u, v).unwrap();
+
+        let nearest = (0..positions.len())
+            .min_by(|&i, &j| {
+                let (d_i, d_j) = ((positions[i] - point).magnitude(), (positions[j] - point).magnitude());
+
+                d_i.total_cmp(&d_j)
+            })
+            .unwrap();
+        let expected = (nearest as f32 / (positions.len() - 1) as f32 * u16::MAX as f32).round() as u16;
+
+        assert_eq!(image.pixels[pixel], expected, "pixel {}", pixel);
+    }
+}
+
+#[test]
+fn rejects_out_of_range_triangle_vertices() {
+    let (positions, mut triangles) = octahedron();
+
+    triangles[3] = [4, 6, 2];
+
+    for sampling in [Sampling::Nearest, Sampling::Barycentric] {
+        assert_eq!(
+            ImageSampler::new(&positions, &triangles, Projection::Mollweide, sampling, 8, 4).map(|sampler| sampler.width()),
+            Err(LayerError::OutOfBounds { index: 6, length: 6 })
+        );
+    }
+}
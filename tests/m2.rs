@@ -0,0 +1,79 @@
+//! 2×2 matrices must be column-major like the [`M3`], and rotate vectors counterclockwise.
+
+use isecad::*;
+use std::f32::consts::*;
+
+fn assert_close(actual: M2, expected: M2) {
+    let difference = actual - expected;
+
+    assert!(difference.a.magnitude() + difference.b.magnitude() < 1e-6, "{:?} != {:?}", actual, expected);
+}
+
+#[test]
+fn converts_arrays_of_columns() {
+    let m = M2::from([[1.0, 2.0], [3.0, 4.0]]);
+
+    assert_eq!((m.a, m.b), (V2::new(1.0, 2.0), V2::new(3.0, 4.0)));
+    assert_eq!(<[[f32; 2]; 2]>::from(m), [[1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(M2::default(), M2::from([[1.0, 0.0], [0.0, 1.0]]));
+}
+
+#[test]
+fn multiplies_matrices() {
+    let (m, n) = (M2::from([[1.0, 2.0], [3.0, 4.0]]), M2::from([[0.0, 1.0], [-1.0, 2.0]]));
+    let v = V2::new(5.0, -6.0);
+
+    // Columns of a product are the first matrix applied to columns of the second one.
+    assert_eq!(m * n, M2::from([[3.0, 4.0], [5.0, 6.0]]));
+    assert_eq!(v * (m * n), (v * n) * m);
+    assert_eq!(m * M2::default(), m);
+    assert_eq!(M2::default() * m, m);
+
+    let mut product = m;
+
+    product *= n;
+
+    assert_eq!(product, m * n);
+    assert_eq!(m * 2.0 / 4.0, M2::from([[0.5, 1.0], [1.5, 2.0]]));
+    assert_eq!(-m + m, M2::from([[0.0, 0.0], [0.0, 0.0]]));
+    assert_eq!(m.transpose(), M2::from([[1.0, 3.0], [2.0, 4.0]]));
+}
+
+#[test]
+fn inverts_regular_matrices() {
+    let m = M2::from([[4.0, 2.0], [7.0, 6.0]]);
+
+    assert_eq!(m.determinant(), 10.0);
+    assert_close(m.inv(), M2::from([[0.6, -0.2], [-0.7, 0.4]]));
+    assert_close(m * m.try_inv().unwrap(), M2::default());
+
+    // Inversion doesn't depend on scale.
+    assert!((m * 1e-15).try_inv().is_some());
+    assert!((m * 1e15).try_inv().is_some());
+}
+
+#[test]
+fn rejects_singular_matrices() {
+    assert_eq!(M2::from([[1.0, 2.0], [2.0, 4.0]]).try_inv(), None);
+    assert_eq!(M2::from([[0.0, 0.0], [0.0, 0.0]]).try_inv(), None);
+    assert_eq!(M2::from([[1.0, 0.0], [0.0, f32::NAN]]).try_inv(), None);
+    assert_eq!(M2::from([[1e30, 0.0], [0.0, 1e30]]).try_inv(), None);
+}
+
+#[test]
+fn rotates_counterclockwise() {
+    let x = V2::new(1.0, 0.0);
+
+    assert!((x * M2::rotation(FRAC_PI_2) - x.perp()).magnitude() < 1e-6);
+    assert!((x * M2::rotation(PI) + x).magnitude() < 1e-6);
+    assert!((x * M2::rotation(FRAC_PI_3)).perp_dot(x) < 0.0);
+
+    for angle in [-2.0, 0.0, 0.3, 1.0, 3.0] {
+        let rotation = M2::rotation(angle);
+
+        assert!((rotation.determinant() - 1.0).abs() < 1e-6);
+        assert_close(rotation.transpose(), rotation.inv());
+        assert_close(rotation * M2::rotation(0.5), M2::rotation(angle + 0.5));
+        assert!(((V2::new(3.0, 4.0) * rotation).magnitude() - 5.0).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,168 @@
+//! Bit-packed masks must behave like regular boolean layers, including in their partially used last words.
+
+use isecad::*;
+
+/// Lengths around word boundaries, and a length spanning several chunks of words.
+const LENGTHS: [usize; 7] = [1, 63, 64, 65, 130, 1000, kernels::CHUNK_LENGTH * 64 * 2 + 37];
+
+fn bools(length: usize, seed: usize) -> Layer<bool> {
+    Layer::from_fn(length, |i| (i * 7919 + seed * 104_729) % 13 < 5 + seed % 3)
+}
+
+fn assert_mask(actual: &MaskLayer, expected: impl Fn(usize) -> bool) {
+    for i in 0..actual.len() {
+        assert_eq!(actual.get(i), expected(i), "item {} of {}", i, actual.len());
+    }
+
+    assert_tail_cleared(actual);
+}
+
+fn assert_tail_cleared(mask: &MaskLayer) {
+    if let Some(&last) = mask.words().last() {
+        let used = mask.len() - (mask.words().len() - 1) * 64;
+
+        assert_eq!(last.checked_shr(used as u32).unwrap_or(0), 0, "tail bits of {} items", mask.len());
+    }
+}
+
+#[test]
+fn packs_and_unpacks_layers() {
+    for length in LENGTHS {
+        let layer = bools(length, 0);
+        let mask = MaskLayer::from(&layer);
+
+        assert_eq!(mask.len(), length);
+        assert_eq!(mask.words().len(), length.div_ceil(64));
+        assert_mask(&mask, |i| layer[i]);
+        assert_eq!(&Layer::from(&mask)[..], &layer[..]);
+        assert_eq!(mask.iter().collect::<MaskLayer>(), mask);
+    }
+}
+
+#[test]
+fn counts_set_items() {
+    for length in LENGTHS {
+        let layer = bools(length, 0);
+        let mask = MaskLayer::from(&layer);
+
+        assert_eq!(mask.count_ones(), layer.iter().filter(|&&l_i| l_i).count());
+        assert!(mask.any());
+        assert!(!mask.all() || length == 1);
+
+        let mut full = MaskLayer::new(length);
+
+        assert!(!full.any());
+        assert!(!full.all());
+        assert_eq!(full.count_ones(), 0);
+
+        full.fill(true);
+
+        assert!(full.any());
+        assert!(full.all());
+        assert_eq!(full.count_ones(), length);
+        assert_tail_cleared(&full);
+    }
+}
+
+#[test]
+fn empty_masks_are_vacuously_full() {
+    let mask = MaskLayer::new(0);
+
+    assert!(mask.is_empty());
+    assert_eq!(mask.count_ones(), 0);
+    assert!(!mask.any());
+    assert!(mask.all());
+}
+
+#[test]
+fn not_keeps_tail_cleared() {
+    for length in LENGTHS {
+        let layer = bools(length, 0);
+        let mask = MaskLayer::from(&layer);
+        let mut output = MaskLayer::new(length);
+
+        mask.not(&mut output);
+        assert_mask(&output, |i| !layer[i]);
+        assert_eq!(output.count_ones(), length - mask.count_ones());
+
+        let mut assigned = mask.clone();
+
+        assigned.not_assign();
+        assert_eq!(assigned, output);
+
+        assigned.not_assign();
+        assert_eq!(assigned, mask);
+    }
+}
+
+#[test]
+fn combines_masks() {
+    for length in LENGTHS {
+        let (a, b) = (bools(length, 0), bools(length, 1));
+        let (mask_a, mask_b) = (MaskLayer::from(&a), MaskLayer::from(&b));
+        let mut output = MaskLayer::new(length);
+
+        type Op = fn(&MaskLayer, &MaskLayer, &mut MaskLayer);
+        type OpAssign = fn(&mut MaskLayer, &MaskLayer);
+        type Expected = fn(bool, bool) -> bool;
+
+        let cases: [(Op, OpAssign, Expected); 4] = [
+            (MaskLayer::union, MaskLayer::union_assign, |a, b| a || b),
+            (MaskLayer::intersection, MaskLayer::intersection_assign, |a, b| a && b),
+            (MaskLayer::difference, MaskLayer::difference_assign, |a, b| a && !b),
+            (MaskLayer::xor, MaskLayer::xor_assign, |a, b| a != b),
+        ];
+
+        for (op, op_assign, expected) in cases {
+            op(&mask_a, &mask_b, &mut output);
+            assert_mask(&output, |i| expected(a[i], b[i]));
+
+            let mut assigned = mask_a.clone();
+
+            op_assign(&mut assigned, &mask_b);
+            assert_eq!(assigned, output);
+        }
+    }
+}
+
+#[test]
+fn by_mask_operations_match_boolean_layers() {
+    let length = LENGTHS[6];
+    let layer = bools(length, 2);
+    let mask = MaskLayer::from(&layer);
+
+    let values = Layer::from_fn(length, |i| (i % 101) as f32 - 50.0);
+    let others = Layer::from_fn(length, |i| (i % 7) as f32 * 0.5);
+
+    let mut expected = Layer::new(length);
+    let mut actual = Layer::new(length);
+
+    values.add_value_by_mask(2.5, &layer, &mut expected);
+    values.add_value_by_mask(2.5, &mask, &mut actual);
+    assert_eq!(&actual[..], &expected[..]);
+
+    values.sub_layer_by_mask(&others, &layer, &mut expected);
+    values.sub_layer_by_mask(&others, &mask, &mut actual);
+    assert_eq!(&actual[..], &expected[..]);
+
+    values.copy_into_selection(&others, &layer, &mut expected);
+    values.copy_into_selection(&others, &mask, &mut actual);
+    assert_eq!(&actual[..], &expected[..]);
+
+    let mut expected = values.clone();
+    let mut actual = values.clone();
+
+    expected.add_layer_by_mask_assign(&others, &layer);
+    actual.add_layer_by_mask_assign(&others, &mask);
+    assert_eq!(&actual[..], &expected[..]);
+
+    expected.fill_into_selection_assign(-1.0, &layer);
+    actual.fill_into_selection_assign(-1.0, &mask);
+    assert_eq!(&actual[..], &expected[..]);
+
+    assert_eq!(values.variance_by_mask(&mask).to_bits(), values.variance_by_mask(&layer).to_bits());
+    assert_eq!(
+        values.histogram_by_mask(10, -50.0, 50.0, &mask),
+        values.histogram_by_mask(10, -50.0, 50.0, &layer)
+    );
+}
@@ -0,0 +1,110 @@
+//! Histogram matching must fail, and leave an output as is, when there are no fractions to match, or no valid curve to
+//! match them to.
+
+use isecad::*;
+
+fn source() -> Layer<f32> {
+    Layer::from(vec![3.0, 1.0, f32::NAN, 2.0])
+}
+
+#[test]
+fn matches_ranks_to_curve() {
+    let pool = LayerPool::new();
+    let mut output = Layer::from(vec![0.0; 4]);
+
+    assert_eq!(source().match_histogram_to_curve(&[0.0, 1.0], &[10.0, 20.0], &mut output, &pool), Ok(()));
+    assert_eq!(&output[..2], &[10.0 + 10.0 * 5.0 / 6.0, 10.0 + 10.0 / 6.0]);
+    assert!(output[2].is_nan());
+    assert_eq!(output[3], 15.0);
+}
+
+#[test]
+fn fails_if_all_items_are_masked_out() {
+    let pool = LayerPool::new();
+    let mask = Layer::from(vec![false, false, true, false]);
+    let mut output = Layer::from(vec![-1.0; 4]);
+
+    assert_eq!(
+        source().match_histogram_to_curve_by_mask(&[0.0, 1.0], &[10.0, 20.0], &mask, &mut output, &pool),
+        Err(LayerError::InvalidWeights)
+    );
+    assert_eq!(
+        source().match_histogram_to_layer_by_mask(&source(), &mask, &mut output, &pool),
+        Err(LayerError::InvalidWeights)
+    );
+    assert_eq!(&output[..], &[-1.0; 4]);
+}
+
+#[test]
+fn fails_if_weights_sum_to_zero() {
+    let pool = LayerPool::new();
+    let weights = Layer::from(vec![0.0, 0.0, 1.0, 0.0]);
+    let mut output = Layer::from(vec![-1.0; 4]);
+
+    assert_eq!(
+        source().match_histogram_to_curve_weighted(&[0.0, 1.0], &[10.0, 20.0], &weights, &mut output, &pool),
+        Err(LayerError::InvalidWeights)
+    );
+    assert_eq!(
+        source().match_histogram_to_layer_weighted(&source(), &weights, &mut output, &pool),
+        Err(LayerError::InvalidWeights)
+    );
+    assert_eq!(&output[..], &[-1.0; 4]);
+}
+
+#[test]
+fn fails_if_target_layer_has_no_items() {
+    let pool = LayerPool::new();
+    let mut output = Layer::from(vec![-1.0; 4]);
+
+    let empty = Layer::from(vec![]);
+    let nans = Layer::from(vec![f32::NAN; 2]);
+
+    assert_eq!(
+        source().match_histogram_to_layer(&empty, &mut output, &pool),
+        Err(LayerError::OutOfBounds { index: 0, length: 0 })
+    );
+    assert_eq!(
+        source().match_histogram_to_layer(&nans, &mut output, &pool),
+        Err(LayerError::OutOfBounds { index: 0, length: 0 })
+    );
+    assert_eq!(&output[..], &[-1.0; 4]);
+}
+
+#[test]
+fn fails_if_curve_has_no_control_points() {
+    let pool = LayerPool::new();
+    let mut output = Layer::from(vec![-1.0; 4]);
+
+    assert_eq!(
+        source().match_histogram_to_curve(&[], &[], &mut output, &pool),
+        Err(LayerError::OutOfBounds { index: 0, length: 0 })
+    );
+    assert_eq!(&output[..], &[-1.0; 4]);
+}
+
+#[test]
+fn fails_if_control_points_have_different_lengths() {
+    let pool = LayerPool::new();
+    let weights = Layer::from(vec![1.0; 4]);
+    let mask = Layer::from(vec![true; 4]);
+    let mut output = Layer::from(vec![-1.0; 4]);
+
+    assert_eq!(
+        source().match_histogram_to_curve(&[0.0, 0.5, 1.0], &[10.0, 20.0], &mut output, &pool),
+        Err(LayerError::LengthMismatch { expected: 3, actual: 2 })
+    );
+    assert_eq!(
+        source().match_histogram_to_curve_weighted(&[0.0, 1.0], &[10.0, 15.0, 20.0], &weights, &mut output, &pool),
+        Err(LayerError::LengthMismatch { expected: 2, actual: 3 })
+    );
+    assert_eq!(
+        source().match_histogram_to_curve_by_mask(&[0.0], &[], &mask, &mut output, &pool),
+        Err(LayerError::LengthMismatch { expected: 1, actual: 0 })
+    );
+    assert_eq!(
+        source().match_histogram_to_curve_weighted_by_mask(&[], &[10.0], &weights, &mask, &mut output, &pool),
+        Err(LayerError::LengthMismatch { expected: 0, actual: 1 })
+    );
+    assert_eq!(&output[..], &[-1.0; 4]);
+}
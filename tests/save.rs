@@ -0,0 +1,125 @@
+//! Save files must round-trip exactly, and corrupted or truncated files must be reported as errors instead of panics.
+
+use isecad::*;
+
+/// A save with layers of every item type, including an empty layer and a bool layer with a partial last byte.
+fn sample() -> SaveFile {
+    let mut save = SaveFile::new(0x0123_4567_89ab_cdef);
+
+    save.metadata.insert("name".to_string(), "Isecad".to_string());
+    save.metadata.insert("seed".to_string(), "42".to_string());
+
+    save.insert_layer("elevation", Layer::from(vec![-1.5, 0.0, f32::MAX, f32::NAN]));
+    save.insert_layer("empty", Layer::<f32>::from(vec![]));
+    save.insert_layer("plates", Layer::from(vec![0u8, 7, 255]));
+    save.insert_layer("land", Layer::from_fn(11, |i| i % 3 == 0));
+    save.insert_layer("positions", Layer::from(vec![V3::new(1.0, 2.0, 3.0), V3::new(-4.0, 5.0, -6.0)]));
+    save.insert_layer("colors", Layer::from(vec![V4::new(0.1, 0.2, 0.3, 1.0)]));
+    save.insert_layer("frames", Layer::from(vec![M3::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])]));
+
+    save
+}
+
+fn sample_with_random() -> SaveFile {
+    let mut random = Random::new(42);
+
+    random.f32();
+    random.normal(0.0, 1.0);
+
+    SaveFile {
+        random: Some(random),
+        ..sample()
+    }
+}
+
+/// Replaces a trailing checksum of bytes, so a parser gets past the checksum check.
+fn reseal(bytes: &mut [u8]) {
+    let body_length = bytes.len() - 4;
+    let checksum = checksum::crc32(&bytes[..body_length]);
+
+    bytes[body_length..].copy_from_slice(&checksum.to_le_bytes());
+}
+
+#[test]
+fn round_trips() {
+    for save in [sample(), sample_with_random()] {
+        let bytes = save.to_bytes();
+        let loaded = SaveFile::from_bytes(&bytes).unwrap();
+
+        assert_eq!(loaded.to_bytes(), bytes);
+        assert_eq!(loaded.grid, save.grid);
+        assert_eq!(loaded.metadata, save.metadata);
+        assert_eq!(loaded.random.as_ref().map(|random| random.i), save.random.as_ref().map(|random| random.i));
+
+        assert_eq!(&loaded.layer::<bool>("land").unwrap()[..], &save.layer::<bool>("land").unwrap()[..]);
+        assert_eq!(&loaded.layer::<V3>("positions").unwrap()[..], &save.layer::<V3>("positions").unwrap()[..]);
+        assert_eq!(&loaded.layer::<V4>("colors").unwrap()[..], &save.layer::<V4>("colors").unwrap()[..]);
+        assert_eq!(loaded.layer::<M3>("frames").unwrap()[0], save.layer::<M3>("frames").unwrap()[0]);
+        assert!(loaded.layer::<f32>("empty").unwrap().is_empty());
+    }
+}
+
+#[test]
+fn rejects_every_single_byte_mutation() {
+    let bytes = sample().to_bytes();
+
+    for offset in 0..bytes.len() {
+        for value in 0..=u8::MAX {
+            if value == bytes[offset] {
+                continue;
+            }
+
+            let mut mutated = bytes.clone();
+
+            mutated[offset] = value;
+
+            assert!(SaveFile::from_bytes(&mutated).is_err(), "byte {} set to {}", offset, value);
+        }
+    }
+}
+
+#[test]
+fn rejects_every_single_byte_mutation_of_random_state() {
+    let bytes = sample_with_random().to_bytes();
+
+    for offset in 0..bytes.len() {
+        let mut mutated = bytes.clone();
+
+        mutated[offset] ^= 0xff;
+
+        assert!(SaveFile::from_bytes(&mutated).is_err(), "byte {} inverted", offset);
+    }
+}
+
+#[test]
+fn never_panics_on_resealed_mutations() {
+    let bytes = sample().to_bytes();
+
+    for offset in 0..bytes.len() - 4 {
+        for value in 0..=u8::MAX {
+            let mut mutated = bytes.clone();
+
+            mutated[offset] = value;
+            reseal(&mut mutated);
+
+            let _ = SaveFile::from_bytes(&mutated);
+        }
+    }
+}
+
+#[test]
+fn rejects_every_truncation() {
+    for bytes in [sample().to_bytes(), sample_with_random().to_bytes()] {
+        for length in 0..bytes.len() {
+            assert!(SaveFile::from_bytes(&bytes[..length]).is_err(), "truncated to {} bytes", length);
+
+            let mut resealed = bytes[..length].to_vec();
+
+            if resealed.len() >= 4 {
+                reseal(&mut resealed);
+            }
+
+            assert!(SaveFile::from_bytes(&resealed).is_err(), "truncated to {} bytes and resealed", length);
+        }
+    }
+}
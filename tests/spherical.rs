@@ -0,0 +1,173 @@
+//! Spherical helpers must agree with known distances and coordinates, and their layer versions with the [`V3`] ones.
+
+use isecad::*;
+use std::f32::consts::*;
+
+fn assert_close(actual: V3, expected: V3) {
+    assert!((actual - expected).magnitude() < 1e-5, "{:?} != {:?}", actual, expected);
+}
+
+fn directions(length: usize) -> Layer<V3> {
+    Layer::from_fn(length, |i| V3::new((i as f32 * 0.7).sin(), (i as f32 * 1.3).cos(), i as f32 * 0.01 - 0.3))
+}
+
+#[test]
+fn measures_known_distances() {
+    let (x, y) = (V3::new(1.0, 0.0, 0.0), V3::new(0.0, 1.0, 0.0));
+
+    assert_eq!(x.great_circle_distance(x), 0.0);
+    assert_eq!(x.great_circle_distance(y), FRAC_PI_2);
+    assert_eq!(x.great_circle_distance(-x), PI);
+    assert_eq!((x * 3.0).great_circle_distance(y * 0.5), FRAC_PI_2);
+    assert!((x.great_circle_distance(x + y) - FRAC_PI_4).abs() < 1e-6);
+
+    // From the equator at the zero meridian to 60° N at 90° E.
+    let north_east = V3::from_lat_lon(FRAC_PI_3, FRAC_PI_2);
+
+    assert!((x.great_circle_distance(north_east) - FRAC_PI_2).abs() < 1e-6);
+}
+
+#[test]
+fn slerp_hits_endpoints_and_midpoints() {
+    let (x, y) = (V3::new(1.0, 0.0, 0.0), V3::new(0.0, 1.0, 0.0));
+
+    assert_close(x.slerp(y, 0.0), x);
+    assert_close(x.slerp(y, 1.0), y);
+    assert_close(x.slerp(y, 0.5), (x + y).normalize());
+
+    for t in [0.1, 0.25, 0.6, 0.9] {
+        let point = x.slerp(y, t);
+
+        assert!((point.magnitude() - 1.0).abs() < 1e-6);
+        assert!((x.great_circle_distance(point) - t * FRAC_PI_2).abs() < 1e-5, "{}", t);
+    }
+}
+
+#[test]
+fn slerp_falls_back_to_lerp() {
+    let x = V3::new(1.0, 0.0, 0.0);
+    let nearby = V3::new(1.0, 1e-5, 0.0);
+
+    assert_eq!(x.slerp(x, 0.3), x);
+    assert_eq!(x.slerp(nearby, 0.5), x + (nearby - x) * 0.5);
+
+    // Antipodal vectors don’t define a single great circle, so the path goes through the origin.
+    assert_eq!(x.slerp(-x, 0.0), x);
+    assert_eq!(x.slerp(-x, 0.25), x * 0.5);
+    assert_eq!(x.slerp(-x, 0.5), V3::new(0.0, 0.0, 0.0));
+    assert_eq!(x.slerp(-x, 1.0), -x);
+}
+
+#[test]
+fn converts_lat_lon() {
+    assert_close(V3::from_lat_lon(0.0, 0.0), V3::new(1.0, 0.0, 0.0));
+    assert_close(V3::from_lat_lon(0.0, FRAC_PI_2), V3::new(0.0, 1.0, 0.0));
+    assert_close(V3::from_lat_lon(FRAC_PI_2, 1.0), V3::new(0.0, 0.0, 1.0));
+    assert_close(V3::from_lat_lon(-FRAC_PI_2, -2.0), V3::new(0.0, 0.0, -1.0));
+
+    assert_eq!(V3::new(0.0, 0.0, 5.0).to_lat_lon(), V2::new(FRAC_PI_2, 0.0));
+    assert_eq!(V3::new(0.0, 0.0, -1.0).to_lat_lon().x, -FRAC_PI_2);
+    assert_eq!(V3::new(-2.0, 0.0, 0.0).to_lat_lon(), V2::new(0.0, PI));
+    assert_eq!(V3::new(0.0, -1.0, 0.0).to_lat_lon(), V2::new(0.0, -FRAC_PI_2));
+}
+
+#[test]
+fn lat_lon_round_trips() {
+    for latitude in [-1.5, -0.7, 0.0, 0.3, 1.2] {
+        for longitude in [-3.0, -1.0, 0.0, 0.5, 3.1] {
+            let lat_lon = V3::from_lat_lon(latitude, longitude).to_lat_lon();
+
+            assert!((lat_lon - V2::new(latitude, longitude)).magnitude() < 1e-5, "{} {}", latitude, longitude);
+        }
+    }
+
+    // Longitudes are arbitrary at the poles, but the points must stay there.
+    for latitude in [FRAC_PI_2, -FRAC_PI_2] {
+        let pole = V3::from_lat_lon(latitude, 1.0);
+
+        assert_close(V3::from_lat_lon(pole.to_lat_lon().x, pole.to_lat_lon().y), pole);
+    }
+
+    let direction = V3::new(1.0, -2.0, 3.0);
+    let lat_lon = direction.to_lat_lon();
+
+    assert_close(V3::from_lat_lon(lat_lon.x, lat_lon.y), direction.normalize());
+}
+
+#[test]
+fn projects_to_tangent_planes() {
+    let point = V3::new(0.0, 0.0, 2.0);
+
+    assert_eq!(V3::new(1.0, 2.0, 3.0).project_to_tangent(point), V3::new(1.0, 2.0, 0.0));
+    assert_eq!(point.project_to_tangent(point), V3::new(0.0, 0.0, 0.0));
+
+    let point = V3::new(1.0, 1.0, 1.0);
+    let tangent = V3::new(3.0, -1.0, 0.5).project_to_tangent(point);
+
+    assert!(tangent.dot(point).abs() < 1e-6);
+    assert_close(tangent.project_to_tangent(point), tangent);
+}
+
+#[test]
+fn layers_match_vector_helpers() {
+    let length = kernels::CHUNK_LENGTH + 3;
+    let (layer_a, layer_b) = (directions(length), directions(length + 5));
+    let layer_b = Layer::from_fn(length, |i| layer_b[i + 5]);
+    let value = V3::new(0.2, -0.5, 0.8);
+
+    let mut distances = Layer::new(length);
+    let mut vectors = Layer::new(length);
+
+    layer_a.great_circle_distance_value(value, &mut distances);
+    assert!((0..length).all(|i| distances[i] == layer_a[i].great_circle_distance(value)));
+
+    layer_a.great_circle_distance_layer(&layer_b, &mut distances);
+    assert!((0..length).all(|i| distances[i] == layer_a[i].great_circle_distance(layer_b[i])));
+
+    layer_a.angle_value(value, &mut distances);
+    assert!((0..length).all(|i| distances[i] == layer_a[i].angle(value)));
+
+    layer_a.cross_layer(&layer_b, &mut vectors);
+    assert!((0..length).all(|i| vectors[i] == layer_a[i].cross(layer_b[i])));
+
+    layer_a.slerp_value(value, 0.3, &mut vectors);
+    assert!((0..length).all(|i| vectors[i] == layer_a[i].slerp(value, 0.3)));
+
+    layer_a.slerp_layer(&layer_b, 0.7, &mut vectors);
+    assert!((0..length).all(|i| vectors[i] == layer_a[i].slerp(layer_b[i], 0.7)));
+
+    let mut assigned = layer_a.clone();
+
+    assigned.slerp_layer_assign(&layer_b, 0.7);
+    assert_eq!(&assigned[..], &vectors[..]);
+
+    layer_a.project_to_tangent(&layer_b, &mut vectors);
+    assert!((0..length).all(|i| vectors[i] == layer_a[i].project_to_tangent(layer_b[i])));
+
+    let mut assigned = layer_a.clone();
+
+    assigned.project_to_tangent_assign(&layer_b);
+    assert_eq!(&assigned[..], &vectors[..]);
+}
+
+#[test]
+fn layers_convert_lat_lon() {
+    let directions = directions(100);
+    let mut lat_lon = Layer::new(100);
+    let mut round_trip = Layer::new(100);
+
+    directions.to_lat_lon(&mut lat_lon);
+    Layer::from_lat_lon(&lat_lon, &mut round_trip);
+
+    for i in 0..100 {
+        assert_eq!(lat_lon[i], directions[i].to_lat_lon());
+        assert_close(round_trip[i], directions[i].normalize());
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "length")]
+fn layers_require_equal_lengths() {
+    directions(3).project_to_tangent(&directions(4), &mut Layer::new(3));
+}
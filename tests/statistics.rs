@@ -0,0 +1,154 @@
+//! Extended statistics must match their textbook definitions, and not depend on how a layer is split into chunks.
+
+use isecad::*;
+
+/// Longer than a few chunks, with a shorter last chunk.
+const LENGTH: usize = kernels::CHUNK_LENGTH * 3 + 17;
+
+/// Values with a large offset, so naive single-pass variances would lose most of their precision.
+fn values() -> Layer<f32> {
+    Layer::from_fn(LENGTH, |i| 1e4 + (i * 7919 % 1013) as f32 * 0.25)
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() <= 1e-9 * expected.abs().max(1.0), "{} != {}", actual, expected);
+}
+
+#[test]
+fn variance_of_constant_layer_is_zero() {
+    let layer = Layer::from(vec![3.7f32; LENGTH]);
+
+    assert_eq!(layer.variance(), 0.0);
+    assert_eq!(layer.std_dev(), 0.0);
+}
+
+#[test]
+fn variance_of_known_values() {
+    let layer = Layer::from(vec![2.0f32, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+
+    assert_eq!(layer.variance(), 4.0);
+    assert_eq!(layer.std_dev(), 2.0);
+
+    let weights = Layer::from(vec![1.0f32, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 3.0]);
+
+    // Weighted mean is 7.25, so the variance is (1 × 5.25² + 3 × 1.75²) / 4.
+    assert_eq!(layer.weighted_variance(&weights), 9.1875);
+
+    let mask = Layer::from(vec![true, false, false, false, true, false, false, true]);
+
+    // Values 2, 5, and 9 have the mean of 16 / 3.
+    assert_close(layer.variance_by_mask(&mask), 74.0 / 9.0);
+}
+
+#[test]
+fn variance_is_nan_without_items() {
+    let layer = Layer::from(vec![1.0f32, 2.0]);
+
+    assert!(Layer::<f32>::new(0).variance().is_nan());
+    assert!(layer.variance_by_mask(&Layer::from(vec![false, false])).is_nan());
+    assert!(layer.weighted_variance(&Layer::from(vec![0.0f32, 0.0])).is_nan());
+}
+
+#[test]
+fn variance_propagates_nan_items() {
+    let layer = Layer::from(vec![1.0f32, f32::NAN, 3.0]);
+
+    assert!(layer.variance().is_nan());
+    assert!(layer.std_dev().is_nan());
+    assert_eq!(layer.variance_by_mask(&Layer::from(vec![true, false, true])), 1.0);
+    assert_eq!(layer.weighted_variance(&Layer::from(vec![1.0f32, 0.0, 1.0])), 1.0);
+}
+
+#[test]
+fn chunk_moments_combine_to_two_pass_variance() {
+    let layer = values();
+    let xs: Vec<f64> = layer.iter().map(|&x| x as f64).collect();
+
+    let mean = xs.iter().sum::<f64>() / LENGTH as f64;
+    let variance = xs.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / LENGTH as f64;
+
+    assert_close(layer.variance(), variance);
+
+    let mask = Layer::from_fn(LENGTH, |i| i % 3 != 0);
+    let selected: Vec<f64> = xs.iter().enumerate().filter(|&(i, _)| mask[i]).map(|(_, &x)| x).collect();
+
+    let mean = selected.iter().sum::<f64>() / selected.len() as f64;
+    let variance = selected.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / selected.len() as f64;
+
+    assert_close(layer.variance_by_mask(&mask), variance);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn variance_is_deterministic_across_thread_counts() {
+    let layer = values();
+    let expected = layer.variance().to_bits();
+
+    for threads in [1, 2, 3, 8] {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+
+        assert_eq!(pool.install(|| layer.variance().to_bits()), expected, "{} threads", threads);
+    }
+}
+
+#[test]
+fn percentiles_interpolate_between_ranks() {
+    let layer = Layer::from(vec![4.0f32, 1.0, f32::NAN, 5.0, 3.0, 2.0]);
+
+    assert_eq!(layer.percentiles(&[0.0, 25.0, 50.0, 100.0]), vec![1.0, 2.0, 3.0, 5.0]);
+    assert_eq!(layer.percentile(-10.0), 1.0);
+    assert_eq!(layer.percentile(110.0), 5.0);
+    assert_eq!(layer.median(), 3.0);
+
+    let even = Layer::from(vec![4.0f32, 1.0, 3.0, 2.0]);
+
+    assert_eq!(even.median(), 2.5);
+    assert_eq!(even.percentile(10.0), 1.3);
+    assert_eq!(even.median_by_mask(&Layer::from(vec![true, true, false, false])), 2.5);
+}
+
+#[test]
+fn percentiles_are_nan_without_items() {
+    let empty = Layer::<f32>::new(0);
+    let nans = Layer::from(vec![f32::NAN; 3]);
+
+    for layer in [empty, nans] {
+        assert!(layer.percentile(0.0).is_nan());
+        assert!(layer.median().is_nan());
+        assert!(layer.percentile(100.0).is_nan());
+        assert!(layer.percentiles(&[0.0, 50.0]).iter().all(|p| p.is_nan()));
+    }
+}
+
+#[test]
+fn histogram_puts_upper_edge_into_last_bin() {
+    let layer = Layer::from(vec![0.0f32, 0.25, 0.5, 0.75, 0.999, 1.0, -0.001, 1.001, f32::NAN]);
+
+    assert_eq!(layer.histogram(4, 0.0, 1.0), vec![1, 1, 1, 3]);
+    assert_eq!(layer.histogram(0, 0.0, 1.0), Vec::<usize>::new());
+
+    let mask = Layer::from(vec![true, true, false, false, false, true, true, true, true]);
+
+    assert_eq!(layer.histogram_by_mask(4, 0.0, 1.0, &mask), vec![1, 1, 0, 1]);
+}
+
+#[test]
+fn histogram_by_edges_counts_half_open_bins() {
+    let layer = Layer::from(vec![0.0f32, 1.0, 2.0, 3.9, 4.0, 5.0, -1.0, 0.5]);
+
+    assert_eq!(layer.histogram_by_edges(&[0.0, 1.0, 2.0, 4.0]), vec![2, 1, 3]);
+    assert_eq!(layer.histogram_by_edges(&[0.0]), Vec::<usize>::new());
+    assert_eq!(layer.histogram_by_edges(&[]), Vec::<usize>::new());
+}
+
+#[test]
+fn histogram_counts_every_chunk() {
+    let layer = values();
+    let counts = layer.histogram(7, 1e4, 1e4 + 1012.0 * 0.25);
+
+    assert_eq!(counts.iter().sum::<usize>(), LENGTH);
+    assert_eq!(
+        counts,
+        layer.histogram_by_edges(&(0..=7).map(|k| 1e4 + k as f64 * 1012.0 * 0.25 / 7.0).collect::<Vec<_>>())
+    );
+}
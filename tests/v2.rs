@@ -0,0 +1,115 @@
+//! 2D vectors must follow the [`V3`] conventions: magnitude-changing scalar addition, column vectors, and counterclockwise
+//! perpendiculars.
+
+use isecad::*;
+
+fn assert_close(actual: V2, expected: V2) {
+    assert!((actual - expected).magnitude() < 1e-6, "{:?} != {:?}", actual, expected);
+}
+
+#[test]
+fn converts_arrays() {
+    let v = V2::new(1.5, -2.0);
+
+    assert_eq!(V2::from([1.5, -2.0]), v);
+    assert_eq!(<[f32; 2]>::from(v), [1.5, -2.0]);
+    assert_eq!(v.extend(3.0), V3::new(1.5, -2.0, 3.0));
+    assert_eq!(std::mem::align_of::<V2>(), 8);
+}
+
+#[test]
+fn adds_and_multiplies() {
+    let (a, b) = (V2::new(1.0, 2.0), V2::new(-3.0, 0.5));
+
+    assert_eq!(a + b, V2::new(-2.0, 2.5));
+    assert_eq!(a - b, V2::new(4.0, 1.5));
+    assert_eq!(-a, V2::new(-1.0, -2.0));
+    assert_eq!(a * 2.0, V2::new(2.0, 4.0));
+    assert_eq!(a / 2.0, V2::new(0.5, 1.0));
+    assert_eq!(a.div_usize(4), V2::new(0.25, 0.5));
+
+    let mut c = a;
+
+    c += b;
+    c -= a;
+    c *= 2.0;
+    c /= 4.0;
+
+    assert_eq!(c, b * 0.5);
+}
+
+#[test]
+fn scalar_addition_changes_magnitude() {
+    assert_eq!(V2::new(3.0, 4.0) + 5.0, V2::new(6.0, 8.0));
+    assert_eq!(V2::new(3.0, 4.0) - 2.5, V2::new(1.5, 2.0));
+    assert_close(V2::new(0.0, 0.0) + 2.0, V2::new(2.0_f32.sqrt(), 2.0_f32.sqrt()));
+    assert!(((V2::new(0.0, 0.0) + 2.0).magnitude() - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn measures_vectors() {
+    let v = V2::new(3.0, -4.0);
+
+    assert_eq!(v.magnitude(), 5.0);
+    assert_eq!(v.magnitude_proportional(), 25.0);
+    assert_eq!(v.into_numeric(), 5.0);
+    assert_close(v.normalize(), V2::new(0.6, -0.8));
+    assert_eq!(V2::new(0.0, 0.0).normalize(), V2::new(0.0, 0.0));
+    assert!(V2::new(1.0, 1.0) < V2::new(0.0, -2.0));
+    assert!((V2::one().magnitude() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn dot_products_follow_orientation() {
+    let (x, y) = (V2::new(1.0, 0.0), V2::new(0.0, 1.0));
+
+    assert_eq!(x.dot(y), 0.0);
+    assert_eq!(V2::new(1.0, 2.0).dot(V2::new(3.0, -4.0)), -5.0);
+
+    // Counterclockwise from x is y, so the perpendicular dot product is positive.
+    assert_eq!(x.perp_dot(y), 1.0);
+    assert_eq!(y.perp_dot(x), -1.0);
+    assert_eq!(x.perp(), y);
+    assert_eq!(y.perp(), -x);
+    assert_eq!(V2::new(2.0, 3.0).perp().dot(V2::new(2.0, 3.0)), 0.0);
+
+    assert_eq!(x.similarity(x * 4.0), 2.0);
+    assert_eq!(x.similarity(y), 0.0);
+}
+
+#[test]
+fn entrywise_operations() {
+    let v = V2::new(4.0, 9.0);
+
+    assert_eq!(v.entrywise_add(1.0), V2::new(5.0, 10.0));
+    assert_eq!(v.entrywise_sub(1.0), V2::new(3.0, 8.0));
+    assert_eq!(v.entrywise_mul(V2::new(0.5, 2.0)), V2::new(2.0, 18.0));
+    assert_eq!(v.entrywise_div(V2::new(2.0, 3.0)), V2::new(2.0, 3.0));
+    assert_eq!(v.entrywise_sqrt(), V2::new(2.0, 3.0));
+    assert_eq!(v.entrywise_inv(), V2::new(0.25, 1.0 / 9.0));
+    assert_eq!(v.entrywise_pow(0.5), V2::new(2.0, 3.0));
+    assert_eq!(V2::new(0.0, 0.0).entrywise_e_x(), V2::new(1.0, 1.0));
+}
+
+#[test]
+fn transforms_column_vectors() {
+    let m = M2::from([[1.0, 2.0], [3.0, 4.0]]);
+
+    // Columns are (1, 2) and (3, 4), so (1, 0) maps to the first one.
+    assert_eq!(V2::new(1.0, 0.0) * m, V2::new(1.0, 2.0));
+    assert_eq!(V2::new(1.0, 1.0) * m, V2::new(4.0, 6.0));
+
+    let mut v = V2::new(0.0, 1.0);
+
+    v *= m;
+
+    assert_eq!(v, V2::new(3.0, 4.0));
+}
+
+#[test]
+fn averages_layers() {
+    let layer = Layer::from(vec![V2::new(1.0, 2.0), V2::new(3.0, -2.0)]);
+
+    assert_eq!(layer.average(), V2::new(2.0, 0.0));
+    assert_eq!(layer.sum(), V2::new(4.0, 0.0));
+}
@@ -0,0 +1,147 @@
+//! Vector layer kernels must be bit-identical to per-item vector operations, with and without the `simd` feature.
+
+use isecad::*;
+
+/// Longer than a chunk, and not a multiple of 4, so leftover items are covered too.
+const LENGTH: usize = kernels::CHUNK_LENGTH * 2 + 7;
+
+/// Regular values mixed with zeros, negative zeros, subnormals, and NaNs.
+fn value(i: usize) -> f32 {
+    match i % 11 {
+        0 => 0.0,
+        1 => -0.0,
+        2 => 1e-40,
+        3 => f32::NAN,
+        _ => ((i * 7919 % 1013) as f32 - 500.0) * 0.37,
+    }
+}
+
+fn v3s(offset: usize) -> Layer<V3> {
+    Layer::from_fn(LENGTH, |i| V3::new(value(i + offset), value(i * 5 + offset + 1), value(i * 3 + offset + 2)))
+}
+
+fn v4s(offset: usize) -> Layer<V4> {
+    Layer::from_fn(LENGTH, |i| {
+        V4::new(
+            value(i + offset),
+            value(i * 5 + offset + 1),
+            value(i * 3 + offset + 2),
+            value(i * 7 + offset + 3),
+        )
+    })
+}
+
+fn bits(values: &[f32]) -> Vec<u32> {
+    values.iter().map(|v| if v.is_nan() { u32::MAX } else { v.to_bits() }).collect()
+}
+
+fn assert_v3s(actual: &[V3], expected: impl Fn(usize) -> V3) {
+    for (i, &a_i) in actual.iter().enumerate() {
+        let e_i = expected(i);
+
+        assert_eq!(bits(&<[f32; 3]>::from(a_i)), bits(&<[f32; 3]>::from(e_i)), "item {}", i);
+    }
+}
+
+fn assert_v4s(actual: &[V4], expected: impl Fn(usize) -> V4) {
+    for (i, &a_i) in actual.iter().enumerate() {
+        let e_i = expected(i);
+
+        assert_eq!(bits(&<[f32; 4]>::from(a_i)), bits(&<[f32; 4]>::from(e_i)), "item {}", i);
+    }
+}
+
+fn assert_f32s(actual: &[f32], expected: impl Fn(usize) -> f32) {
+    assert_eq!(bits(actual), bits(&(0..actual.len()).map(expected).collect::<Vec<_>>()));
+}
+
+fn m4() -> M4 {
+    M4 {
+        a: V4::new(0.3, -1.2, 2.0, 0.1),
+        b: V4::new(1.1, 0.5, -0.7, 0.2),
+        c: V4::new(-0.2, 0.9, 1.3, -0.5),
+        d: V4::new(5.0, -3.0, 0.25, 1.0),
+    }
+}
+
+#[test]
+fn v3_kernels_match_vector_operations() {
+    let (a, b) = (v3s(0), v3s(17));
+    let m3 = (V3::new(1.0, 2.0, 3.0).normalize() * 0.5).to_rotation_m3();
+    let m4 = m4();
+    let mut output = Layer::<V3>::new(LENGTH);
+    let mut scalars = Layer::<f32>::new(LENGTH);
+
+    vector_kernels::add_v3(&a, &b, &mut output);
+    assert_v3s(&output, |i| a[i] + b[i]);
+
+    vector_kernels::sub_v3(&a, &b, &mut output);
+    assert_v3s(&output, |i| a[i] - b[i]);
+
+    vector_kernels::mul_v3(&a, 1.7, &mut output);
+    assert_v3s(&output, |i| a[i] * 1.7);
+
+    vector_kernels::dot_v3(&a, &b, &mut scalars);
+    assert_f32s(&scalars, |i| a[i].dot(b[i]));
+
+    vector_kernels::magnitude_v3(&a, &mut scalars);
+    assert_f32s(&scalars, |i| a[i].magnitude());
+
+    vector_kernels::normalize_v3(&a, &mut output);
+    assert_v3s(&output, |i| a[i].normalize());
+
+    vector_kernels::transform_m3_v3(&a, m3, &mut output);
+    assert_v3s(&output, |i| a[i] * m3);
+
+    vector_kernels::transform_m4_v3(&a, m4, &mut output);
+    assert_v3s(&output, |i| a[i] * m4);
+
+    output.copy_from_slice(&a);
+    vector_kernels::normalize_v3_assign(&mut output);
+    assert_v3s(&output, |i| a[i].normalize());
+
+    output.copy_from_slice(&a);
+    vector_kernels::transform_m3_v3_assign(&mut output, m3);
+    assert_v3s(&output, |i| a[i] * m3);
+
+    output.copy_from_slice(&a);
+    vector_kernels::transform_m4_v3_assign(&mut output, m4);
+    assert_v3s(&output, |i| a[i] * m4);
+}
+
+#[test]
+fn v4_kernels_match_vector_operations() {
+    let (a, b) = (v4s(0), v4s(17));
+    let m4 = m4();
+    let mut output = Layer::<V4>::new(LENGTH);
+    let mut scalars = Layer::<f32>::new(LENGTH);
+
+    vector_kernels::add_v4(&a, &b, &mut output);
+    assert_v4s(&output, |i| a[i] + b[i]);
+
+    vector_kernels::sub_v4(&a, &b, &mut output);
+    assert_v4s(&output, |i| a[i] - b[i]);
+
+    vector_kernels::mul_v4(&a, -2.3, &mut output);
+    assert_v4s(&output, |i| a[i] * -2.3);
+
+    vector_kernels::dot_v4(&a, &b, &mut scalars);
+    assert_f32s(&scalars, |i| a[i].dot(b[i]));
+
+    vector_kernels::magnitude_v4(&a, &mut scalars);
+    assert_f32s(&scalars, |i| a[i].magnitude());
+
+    vector_kernels::normalize_v4(&a, &mut output);
+    assert_v4s(&output, |i| a[i].normalize());
+
+    vector_kernels::transform_m4_v4(&a, m4, &mut output);
+    assert_v4s(&output, |i| a[i] * m4);
+
+    output.copy_from_slice(&a);
+    vector_kernels::normalize_v4_assign(&mut output);
+    assert_v4s(&output, |i| a[i].normalize());
+
+    output.copy_from_slice(&a);
+    vector_kernels::transform_m4_v4_assign(&mut output, m4);
+    assert_v4s(&output, |i| a[i] * m4);
+}
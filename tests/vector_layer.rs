@@ -0,0 +1,147 @@
+//! Struct-of-arrays vector layers must round-trip through array-of-structs layers, and give bit-identical results.
+
+use isecad::*;
+
+/// Longer than a chunk, with a shorter last chunk.
+const LENGTH: usize = kernels::CHUNK_LENGTH + 13;
+
+/// Regular values mixed with zeros, negative zeros, and NaNs.
+fn value(i: usize) -> f32 {
+    match i % 11 {
+        0 => 0.0,
+        1 => -0.0,
+        2 => f32::NAN,
+        _ => ((i * 7919 % 1013) as f32 - 500.0) * 0.37,
+    }
+}
+
+/// Includes zero vectors, so normalization of zero vectors is covered too.
+fn v3s(offset: usize) -> Layer<V3> {
+    Layer::from_fn(LENGTH, |i| {
+        if i % 17 == 0 {
+            V3::new(0.0, 0.0, 0.0)
+        } else {
+            V3::new(value(i + offset), value(i * 5 + offset + 1), value(i * 3 + offset + 2))
+        }
+    })
+}
+
+fn bits(value: V3) -> [u32; 3] {
+    <[f32; 3]>::from(value).map(|v| if v.is_nan() { u32::MAX } else { v.to_bits() })
+}
+
+fn assert_f32s(actual: &[f32], expected: impl Fn(usize) -> f32) {
+    for (i, &a_i) in actual.iter().enumerate() {
+        let e_i = expected(i);
+
+        assert!(a_i.to_bits() == e_i.to_bits() || a_i.is_nan() && e_i.is_nan(), "item {}: {} != {}", i, a_i, e_i);
+    }
+}
+
+#[test]
+fn round_trips_through_array_of_structs() {
+    let layer = v3s(0);
+    let vectors = VectorLayer3::from(&layer);
+
+    assert_eq!(vectors.len(), LENGTH);
+    assert_eq!(&vectors.y[..3], &[layer[0].y, layer[1].y, layer[2].y]);
+
+    let round_trip = Layer::from(&vectors);
+
+    for i in 0..LENGTH {
+        assert_eq!(bits(vectors.get(i)), bits(layer[i]), "item {}", i);
+        assert_eq!(bits(round_trip[i]), bits(layer[i]), "item {}", i);
+    }
+
+    let mut copy = VectorLayer3::new(LENGTH);
+    let mut output = Layer::new(LENGTH);
+
+    copy.copy_from_layer(&layer);
+    copy.copy_into_layer(&mut output);
+
+    for i in 0..LENGTH {
+        assert_eq!(bits(output[i]), bits(layer[i]), "item {}", i);
+    }
+}
+
+#[test]
+fn round_trips_empty_layers() {
+    let vectors = VectorLayer3::from(&Layer::<V3>::new(0));
+
+    assert!(vectors.is_empty());
+    assert!(Layer::from(&vectors).is_empty());
+}
+
+#[test]
+fn dot_matches_vectors() {
+    let (a, b) = (v3s(0), v3s(7));
+    let (vectors_a, vectors_b) = (VectorLayer3::from(&a), VectorLayer3::from(&b));
+    let value = V3::new(0.3, -1.2, 2.0);
+    let mut output = Layer::new(LENGTH);
+
+    vectors_a.dot_layer(&vectors_b, &mut output);
+    assert_f32s(&output, |i| a[i].dot(b[i]));
+
+    vectors_a.dot_value(value, &mut output);
+    assert_f32s(&output, |i| a[i].dot(value));
+}
+
+#[test]
+fn similarity_matches_vectors() {
+    let (a, b) = (v3s(0), v3s(7));
+    let (vectors_a, vectors_b) = (VectorLayer3::from(&a), VectorLayer3::from(&b));
+    let value = V3::new(0.3, -1.2, 2.0);
+    let mut output = Layer::new(LENGTH);
+
+    vectors_a.similarity_layer(&vectors_b, &mut output);
+    assert_f32s(&output, |i| a[i].similarity(b[i]));
+
+    vectors_a.similarity_value(value, &mut output);
+    assert_f32s(&output, |i| a[i].similarity(value));
+}
+
+#[test]
+fn to_magnitudes_matches_layer() {
+    let layer = v3s(0);
+    let mut expected = Layer::new(LENGTH);
+    let mut output = Layer::new(LENGTH);
+
+    layer.to_magnitudes(&mut expected);
+    VectorLayer3::from(&layer).to_magnitudes(&mut output);
+
+    assert_f32s(&output, |i| expected[i]);
+}
+
+#[test]
+fn normalize_each_matches_layer() {
+    let layer = v3s(0);
+    let mut expected = Layer::new(LENGTH);
+    let mut output = VectorLayer3::new(LENGTH);
+
+    layer.normalize_each(&mut expected);
+    VectorLayer3::from(&layer).normalize_each(&mut output);
+
+    for i in 0..LENGTH {
+        assert_eq!(bits(output.get(i)), bits(expected[i]), "item {}", i);
+    }
+
+    assert_eq!(bits(output.get(0)), [0; 3]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "second operand layer length mismatch")]
+fn rejects_operands_of_other_lengths() {
+    let mut output = Layer::new(LENGTH);
+
+    VectorLayer3::new(LENGTH).dot_layer(&VectorLayer3::new(LENGTH - 1), &mut output);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "output layer length mismatch")]
+fn rejects_outputs_of_other_lengths() {
+    let mut output = Layer::new(LENGTH + 1);
+
+    VectorLayer3::new(LENGTH).to_magnitudes(&mut output);
+}